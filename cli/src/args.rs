@@ -1,7 +1,12 @@
 //! Command line argument parsing.
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+#[cfg(feature = "python")]
+use typeshare_core::language::PythonStyle;
+use typeshare_core::language::{ScalaDialect, SupportedLanguage};
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum AvailableLanguage {
     Kotlin,
@@ -14,6 +19,31 @@ pub enum AvailableLanguage {
     Python,
 }
 
+/// How parse errors are reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// Errors with the source lines they point at
+    #[default]
+    Human,
+    /// One JSON object per error on stdout
+    Json,
+}
+
+impl From<AvailableLanguage> for SupportedLanguage {
+    fn from(language: AvailableLanguage) -> Self {
+        match language {
+            AvailableLanguage::Kotlin => SupportedLanguage::Kotlin,
+            AvailableLanguage::Scala => SupportedLanguage::Scala,
+            AvailableLanguage::Swift => SupportedLanguage::Swift,
+            AvailableLanguage::Typescript => SupportedLanguage::TypeScript,
+            #[cfg(feature = "go")]
+            AvailableLanguage::Go => SupportedLanguage::Go,
+            #[cfg(feature = "python")]
+            AvailableLanguage::Python => SupportedLanguage::Python,
+        }
+    }
+}
+
 #[derive(clap::Parser)]
 #[command(
     version,
@@ -21,14 +51,26 @@ pub enum AvailableLanguage {
     subcommand_negates_reqs = true,
     name = "typeshare"
 )]
-pub struct Args {
+pub struct Cli {
     #[command(subcommand)]
     pub subcommand: Option<Command>,
 
-    /// Language of generated types
-    #[arg(short, long = "lang", required_unless_present = "generate_config")]
+    #[command(flatten)]
+    pub args: Args,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Language of generated types. Required unless the configuration file
+    /// defines `[[targets]]`
+    #[arg(short, long = "lang")]
     pub language: Option<AvailableLanguage>,
 
+    /// Generate types with the external `typeshare-gen-<PLUGIN>` executable
+    /// instead of a built in language
+    #[arg(long, conflicts_with = "language")]
+    pub plugin: Option<String>,
+
     /// Prefix for generated Swift types
     #[arg(short, long)]
     pub swift_prefix: Option<String>,
@@ -53,6 +95,15 @@ pub struct Args {
     #[arg(long)]
     pub scala_module_name: Option<String>,
 
+    /// Scala language version to generate code for
+    #[arg(long, value_enum)]
+    pub scala_dialect: Option<ScalaDialect>,
+
+    #[cfg(feature = "python")]
+    /// Kind of Python classes to generate
+    #[arg(long, value_enum)]
+    pub python_style: Option<PythonStyle>,
+
     #[cfg(feature = "go")]
     /// Go package name
     #[arg(long)]
@@ -70,25 +121,55 @@ pub struct Args {
     pub follow_links: bool,
 
     /// Directories within which to recursively find and process rust files
-    #[arg(required=true, num_args = 1..)]
+    #[arg(required_unless_present = "from_ir", num_args = 1..)]
     pub directories: Vec<PathBuf>,
 
+    /// Write the parsed types to this file as JSON, to generate code from
+    /// later with --from-ir
+    #[arg(long)]
+    pub emit_ir: Option<PathBuf>,
+
+    /// Generate code from the types in a file written with --emit-ir instead
+    /// of parsing Rust sources
+    #[arg(long, conflicts_with_all = ["emit_ir", "directories"])]
+    pub from_ir: Option<PathBuf>,
+
+    /// Check that the generated files are up to date instead of writing them.
+    /// Prints a diff for every out of date file and fails if there are any.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Keep files in the output folder that were generated by an earlier run
+    /// but aren't generated anymore, instead of deleting them.
+    #[arg(long)]
+    pub no_prune: bool,
+
+    /// Write a file per Rust module instead of per crate to the output folder
+    #[arg(long, requires = "folder")]
+    pub per_module: bool,
+
+    /// How to report parse errors
+    #[arg(long, value_enum, default_value_t)]
+    pub message_format: MessageFormat,
+
     /// Optional restrict to target_os
     #[arg(short, long, num_args = 1..)]
     pub target_os: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Copy, clap::Subcommand)]
+#[derive(Debug, clap::Subcommand)]
 pub enum Command {
     /// Generate shell completions
     Completions {
         /// The shell to generate the completions for
         shell: clap_complete::Shell,
     },
+    /// Generate types, then regenerate them whenever the Rust sources change
+    Watch(Box<Args>),
 }
 
 #[derive(clap::Args, Debug)]
-#[group(multiple = false, required = true)]
+#[group(multiple = false)]
 pub struct Output {
     /// File to write output to. mtime will be preserved if the file contents
     /// don't change
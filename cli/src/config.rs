@@ -1,3 +1,4 @@
+use crate::args::AvailableLanguage;
 use anyhow::Context;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -8,17 +9,21 @@ use std::{
     io::{self, Write},
     path::{Path, PathBuf},
 };
+#[cfg(feature = "python")]
+use typeshare_core::language::PythonStyle;
+use typeshare_core::language::ScalaDialect;
 
 const DEFAULT_CONFIG_FILE_NAME: &str = "typeshare.toml";
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 #[serde(default)]
 #[cfg(feature = "python")]
 pub struct PythonParams {
     pub type_mappings: HashMap<String, String>,
+    pub style: PythonStyle,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(default)]
 pub struct KotlinParams {
     pub package: String,
@@ -27,15 +32,17 @@ pub struct KotlinParams {
     pub type_mappings: HashMap<String, String>,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(default)]
 pub struct ScalaParams {
     pub package: String,
     pub module_name: String,
     pub type_mappings: HashMap<String, String>,
+    pub dialect: ScalaDialect,
+    pub circe: bool,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(default)]
 pub struct SwiftParams {
     pub prefix: String,
@@ -46,13 +53,13 @@ pub struct SwiftParams {
     pub type_mappings: HashMap<String, String>,
 }
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 #[serde(default)]
 pub struct TypeScriptParams {
     pub type_mappings: HashMap<String, String>,
 }
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 #[serde(default)]
 #[cfg(feature = "go")]
 pub struct GoParams {
@@ -62,9 +69,36 @@ pub struct GoParams {
     pub type_mappings: HashMap<String, String>,
 }
 
+/// An output generated from the same parsed source as the other targets,
+/// configured with a `[[targets]]` table.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TargetConfig {
+    /// A built in language, or else
+    pub language: Option<AvailableLanguage>,
+    /// the name of an external `typeshare-gen-<plugin>` executable.
+    pub plugin: Option<String>,
+    /// Options passed on to the plugin.
+    #[serde(default, skip_serializing_if = "toml::Table::is_empty")]
+    pub options: toml::Table,
+    pub output_file: Option<PathBuf>,
+    pub output_folder: Option<PathBuf>,
+    /// Write a file per module instead of per crate to the output folder.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub per_module: bool,
+    // Options replacing the top level tables for this target.
+    pub swift: Option<SwiftParams>,
+    pub typescript: Option<TypeScriptParams>,
+    pub kotlin: Option<KotlinParams>,
+    pub scala: Option<ScalaParams>,
+    #[cfg(feature = "python")]
+    pub python: Option<PythonParams>,
+    #[cfg(feature = "go")]
+    pub go: Option<GoParams>,
+}
+
 /// The parameters that are used to configure the behaviour of typeshare
 /// from the configuration file `typeshare.toml`
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq, Clone)]
 #[serde(default)]
 pub(crate) struct Config {
     pub swift: SwiftParams,
@@ -77,6 +111,40 @@ pub(crate) struct Config {
     pub go: GoParams,
     #[serde(skip)]
     pub target_os: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<TargetConfig>,
+}
+
+impl Config {
+    /// The configuration for a target, where the target's own language
+    /// options replace the top level ones.
+    pub(crate) fn for_target(&self, target: &TargetConfig) -> Config {
+        let mut config = Config {
+            targets: Vec::new(),
+            ..self.clone()
+        };
+        if let Some(swift) = &target.swift {
+            config.swift = swift.clone();
+        }
+        if let Some(typescript) = &target.typescript {
+            config.typescript = typescript.clone();
+        }
+        if let Some(kotlin) = &target.kotlin {
+            config.kotlin = kotlin.clone();
+        }
+        if let Some(scala) = &target.scala {
+            config.scala = scala.clone();
+        }
+        #[cfg(feature = "python")]
+        if let Some(python) = &target.python {
+            config.python = python.clone();
+        }
+        #[cfg(feature = "go")]
+        if let Some(go) = &target.go {
+            config.go = go.clone();
+        }
+        config
+    }
 }
 
 pub(crate) fn store_config(config: &Config, file_path: Option<&Path>) -> anyhow::Result<()> {
@@ -203,6 +271,52 @@ mod test {
 
         assert_eq!(config.swift.prefix, "test");
     }
+    #[test]
+    fn scala_dialect_test() {
+        let path = config_file_path("scala_config.toml");
+        let config = load_config(Some(&path)).unwrap();
+
+        assert_eq!(config.scala.dialect, ScalaDialect::Scala3);
+        assert!(config.scala.circe);
+    }
+
+    #[test]
+    fn targets_test() {
+        let path = config_file_path("targets_config.toml");
+        let config = load_config(Some(&path)).unwrap();
+
+        assert_eq!(config.targets.len(), 3);
+        assert_eq!(
+            config.targets[0].language,
+            Some(AvailableLanguage::Typescript)
+        );
+        assert_eq!(
+            config.targets[0].output_file.as_deref(),
+            Some(Path::new("web/types.ts"))
+        );
+        assert!(!config.targets[0].per_module);
+        assert!(config.targets[1].per_module);
+
+        let swift = config.for_target(&config.targets[1]);
+        assert_eq!(swift.swift.prefix, "Target");
+        assert_eq!(swift.kotlin.package, "com.example");
+        assert!(swift.targets.is_empty());
+
+        let plugin = &config.targets[2];
+        assert_eq!(plugin.language, None);
+        assert_eq!(plugin.plugin.as_deref(), Some("dsl"));
+        assert_eq!(plugin.options["namespace"].as_str(), Some("example"));
+    }
+
+    #[test]
+    #[cfg(feature = "python")]
+    fn python_style_test() {
+        let path = config_file_path("python_config.toml");
+        let config = load_config(Some(&path)).unwrap();
+
+        assert_eq!(config.python.style, PythonStyle::Msgspec);
+    }
+
     #[test]
     #[cfg(feature = "go")]
     fn go_package_test() {
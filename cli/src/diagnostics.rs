@@ -0,0 +1,140 @@
+//! Reporting parse errors.
+use codespan_reporting::{
+    diagnostic::{Diagnostic, Label},
+    files::SimpleFile,
+    term::{
+        self,
+        termcolor::{ColorChoice, StandardStream},
+    },
+};
+use log::error;
+use proc_macro2::LineColumn;
+use serde::Serialize;
+use std::{fs, ops::Range};
+use typeshare_core::parser::ErrorInfo;
+
+use crate::args::MessageFormat;
+
+/// A parse error reported as a line of JSON.
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file: &'a str,
+    message: &'a str,
+    /// Lines and columns start at 1.
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// Report parse errors in the given format. Human readable errors are written to
+/// stderr with the source they point at, and JSON errors to stdout.
+pub fn report(errors: &[ErrorInfo], format: MessageFormat) {
+    match format {
+        MessageFormat::Human => {
+            let writer = StandardStream::stderr(ColorChoice::Auto);
+            let config = term::Config::default();
+            for error in errors {
+                let emitted = fs::read_to_string(&error.file_name)
+                    .map_err(|err| err.to_string())
+                    .and_then(|source| {
+                        let range = label_range(&source, error.start, error.end);
+                        let file = SimpleFile::new(&error.file_name, source);
+                        let diagnostic = Diagnostic::error()
+                            .with_message(&error.message)
+                            .with_labels(vec![Label::primary((), range)]);
+                        term::emit(&mut writer.lock(), &config, &file, &diagnostic)
+                            .map_err(|err| err.to_string())
+                    });
+                if emitted.is_err() {
+                    error!(
+                        "Parsing error: \"{}\" in file \"{}\"",
+                        error.error, error.file_name
+                    );
+                }
+            }
+        }
+        MessageFormat::Json => {
+            for error in errors {
+                let diagnostic = JsonDiagnostic {
+                    file: &error.file_name,
+                    message: &error.message,
+                    line: error.start.line,
+                    column: error.start.column + 1,
+                    end_line: error.end.line,
+                    end_column: error.end.column + 1,
+                };
+                println!(
+                    "{}",
+                    serde_json::to_string(&diagnostic).expect("diagnostics serialize to JSON")
+                );
+            }
+        }
+    }
+}
+
+/// The byte range to underline for an error, which is cut off at the end of its
+/// first line so that errors on whole items only show the item's first line.
+fn label_range(source: &str, start: LineColumn, end: LineColumn) -> Range<usize> {
+    let start_offset = byte_offset(source, start);
+    let line_end = source[start_offset..]
+        .find('\n')
+        .map_or(source.len(), |len| start_offset + len);
+    let end_offset = byte_offset(source, end).clamp(start_offset, line_end);
+
+    if end_offset > start_offset {
+        start_offset..end_offset
+    } else {
+        // Always point at a character, if there is one.
+        let next = source[start_offset..line_end]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+        start_offset..start_offset + next
+    }
+}
+
+/// The byte offset of a line and column, where columns count characters.
+fn byte_offset(source: &str, position: LineColumn) -> usize {
+    let line_start = source
+        .split_inclusive('\n')
+        .take(position.line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    let line = source[line_start..].split('\n').next().unwrap_or_default();
+    line_start
+        + line
+            .char_indices()
+            .nth(position.column)
+            .map_or(line.len(), |(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = "#[typeshare]\nstruct Ä {\n    x: u32,\n}\n";
+
+    #[test]
+    fn label_range_counts_characters() {
+        let start = LineColumn { line: 3, column: 4 };
+        let end = LineColumn { line: 3, column: 5 };
+
+        assert_eq!(&SOURCE[label_range(SOURCE, start, end)], "x");
+    }
+
+    #[test]
+    fn label_range_stops_at_end_of_line() {
+        let start = LineColumn { line: 2, column: 7 };
+        let end = LineColumn { line: 4, column: 1 };
+
+        assert_eq!(&SOURCE[label_range(SOURCE, start, end)], "Ä {");
+    }
+
+    #[test]
+    fn label_range_points_at_a_character() {
+        let position = LineColumn { line: 2, column: 9 };
+
+        assert_eq!(&SOURCE[label_range(SOURCE, position, position)], "{");
+    }
+}
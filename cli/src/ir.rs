@@ -0,0 +1,79 @@
+//! Reading and writing the intermediate representation of parsed types.
+use anyhow::Context;
+use serde::Deserialize;
+use std::{fs, path::Path};
+use typeshare_core::ir::{IntermediateRepresentation, IR_VERSION};
+
+/// Just the version of an intermediate representation, so that it can be
+/// checked before reading the rest.
+#[derive(Deserialize)]
+struct Version {
+    version: u32,
+}
+
+/// Read an intermediate representation written by this version of typeshare.
+pub fn read_ir(path: &Path) -> anyhow::Result<IntermediateRepresentation> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read IR: {path:?}"))?;
+
+    let Version { version } = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to read the IR version: {path:?}"))?;
+    anyhow::ensure!(
+        version == IR_VERSION,
+        "The IR in {path:?} has version {version}, but this typeshare reads version {IR_VERSION}"
+    );
+
+    serde_json::from_str(&contents).with_context(|| format!("Failed to parse IR: {path:?}"))
+}
+
+/// Write an intermediate representation as JSON.
+pub fn write_ir(path: &Path, ir: &IntermediateRepresentation) -> anyhow::Result<()> {
+    let contents = serde_json::to_string_pretty(ir).context("Failed to serialize IR")?;
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).context("failed to create IR directory")?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write IR: {path:?}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{collections::BTreeMap, path::PathBuf};
+    use typeshare_core::{
+        context::OutputKind, language::SINGLE_FILE_CRATE_NAME, parser::ParsedData,
+    };
+
+    const CURRENT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+    const TEST_DIR: &str = "data/tests";
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join("typeshare_ir_round_trip.json");
+        let mut ir = IntermediateRepresentation::default();
+        ir.set_crates(
+            OutputKind::SingleFile,
+            BTreeMap::from([(SINGLE_FILE_CRATE_NAME, ParsedData::default())]),
+        );
+
+        write_ir(&path, &ir).unwrap();
+        let read = read_ir(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(read
+            .crates(OutputKind::SingleFile)
+            .unwrap()
+            .contains_key(&SINGLE_FILE_CRATE_NAME));
+        assert!(read.crates(OutputKind::MultiFile).is_none());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let path: PathBuf = [CURRENT_DIR, TEST_DIR, "future_ir.json"].iter().collect();
+        let err = read_ir(&path).unwrap_err();
+
+        assert!(err.to_string().contains("has version 999"), "{err}");
+    }
+}
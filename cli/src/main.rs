@@ -4,39 +4,41 @@
 
 mod args;
 mod config;
-mod parse;
-mod writer;
+mod diagnostics;
+mod ir;
+mod plugin;
+mod watch;
 
 use std::{
-    collections::{BTreeMap, HashMap},
-    io,
-    path::Path,
+    collections::{BTreeMap, HashSet},
+    fmt, io,
+    path::{Path, PathBuf},
 };
 
 use anyhow::{anyhow, Context};
 use clap::{CommandFactory, Parser};
 use clap_complete::aot::generate;
 use flexi_logger::AdaptiveFormat;
-use ignore::{overrides::OverrideBuilder, types::TypesBuilder, WalkBuilder};
 use log::{error, info};
-use parse::parallel_parse;
 use typeshare_core::language::GenericConstraints;
 #[cfg(feature = "go")]
 use typeshare_core::language::Go;
 #[cfg(feature = "python")]
 use typeshare_core::language::Python;
 use typeshare_core::{
-    context::ParseContext,
+    context::{OutputKind, ParseContext},
+    crate_names::CrateNames,
+    error::GeneratorError,
+    ir::IntermediateRepresentation,
     language::{CrateName, Kotlin, Language, Scala, SupportedLanguage, Swift, TypeScript},
     parser::ParsedData,
-    reconcile::reconcile_aliases,
+    sources::{parse_sources, source_walker},
+    writer::{write_files, write_generated, Output, WriteMode, WriteOptions},
 };
 
 use crate::{
-    args::{Args, Command},
+    args::{Args, Cli, Command, MessageFormat},
     config::Config,
-    parse::all_types,
-    writer::{write_generated, Output},
 };
 
 fn main() -> anyhow::Result<()> {
@@ -45,20 +47,26 @@ fn main() -> anyhow::Result<()> {
         .adaptive_format_for_stdout(AdaptiveFormat::Opt)
         .start()?;
 
-    let options = Args::parse();
+    let cli = Cli::parse();
 
-    if let Some(options) = options.subcommand {
-        match options {
+    if let Some(command) = cli.subcommand {
+        match command {
             Command::Completions { shell } => {
-                let mut cmd = Args::command();
+                let mut cmd = Cli::command();
                 let bin_name = cmd.get_name().to_string();
                 generate(shell, &mut cmd, bin_name, &mut io::stdout());
             }
+            Command::Watch(options) => {
+                return watch::watch(options.config_file.as_deref(), &options)
+                    .inspect_err(|err| error!("typeshare failed to watch types: {err}"));
+            }
         }
 
         return Ok(());
     }
 
+    let options = cli.args;
+
     // Note that this can be `None`; the relevant functions handle this case
     // on their own.
     let config_file = options.config_file.as_deref();
@@ -74,110 +82,286 @@ fn main() -> anyhow::Result<()> {
     }
 }
 
+/// What generates the code of a target.
+enum Backend {
+    /// A language built into typeshare.
+    Language(SupportedLanguage),
+    /// An external `typeshare-gen-<name>` executable, with the options to pass on.
+    Plugin { name: String, options: toml::Table },
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::Language(language_type) => write!(f, "{language_type:?}"),
+            Backend::Plugin { name, .. } => write!(f, "plugin {name}"),
+        }
+    }
+}
+
+/// An output to generate, from the command line or a `[[targets]]` table.
+struct Target {
+    backend: Backend,
+    output: PathBuf,
+    kind: OutputKind,
+    /// Delete stale files from the output folder.
+    prune: bool,
+    config: Config,
+}
+
+impl Target {
+    fn destination(&self) -> Output<'_> {
+        if self.kind.multi_file() {
+            Output::Folder(&self.output)
+        } else {
+            Output::File(&self.output)
+        }
+    }
+}
+
 fn generate_types(config_file: Option<&Path>, options: &Args) -> anyhow::Result<()> {
     info!("typeshare started generating types");
 
-    let config = config::load_config(config_file).context("Unable to read configuration file")?;
-    let config = override_configuration(config, options)?;
+    let (config, targets) = load_targets(config_file, options)?;
 
     let directories = options.directories.as_slice();
 
-    info!("Using directories: {directories:?}");
-
-    let language_type = match options.language {
-        None => panic!("no language specified; `clap` should have guaranteed its presence"),
-        Some(language) => match language {
-            args::AvailableLanguage::Kotlin => SupportedLanguage::Kotlin,
-            args::AvailableLanguage::Scala => SupportedLanguage::Scala,
-            args::AvailableLanguage::Swift => SupportedLanguage::Swift,
-            args::AvailableLanguage::Typescript => SupportedLanguage::TypeScript,
-            #[cfg(feature = "go")]
-            args::AvailableLanguage::Go => SupportedLanguage::Go,
-            #[cfg(feature = "python")]
-            args::AvailableLanguage::Python => SupportedLanguage::Python,
-        },
+    let from_ir = match &options.from_ir {
+        Some(path) => {
+            info!("Using IR: {path:?}");
+            Some(ir::read_ir(path)?)
+        }
+        None => {
+            info!("Using directories: {directories:?}");
+            None
+        }
     };
+    let mut emitted_ir = options
+        .emit_ir
+        .as_ref()
+        .map(|_| IntermediateRepresentation::default());
 
-    let destination = if let Some(ref file) = options.output.file {
-        Output::File(file)
-    } else if let Some(ref folder) = options.output.folder {
-        Output::Folder(folder)
+    let mode = if options.check {
+        WriteMode::Check
     } else {
-        panic!(
-            "Got neither a file nor a folder to output to; this indicates a
-            bug in typeshare, since `clap` is supposed to prevent this"
-        )
+        WriteMode::Write
     };
+    let mut out_of_date = Vec::new();
+
+    let no_targets = targets.is_empty();
+
+    // Each kind of output parses the sources differently, so the targets
+    // share one parse for each kind.
+    for kind in OutputKind::ALL {
+        let targets = targets
+            .iter()
+            .filter(|target| target.kind == kind)
+            .collect::<Vec<_>>();
+        // Without any targets, the IR is emitted for single and multi file
+        // output.
+        let emit_all = emitted_ir.is_some() && no_targets && kind != OutputKind::PerModule;
+        if targets.is_empty() && !emit_all {
+            continue;
+        }
 
-    let multi_file = matches!(destination, Output::Folder(_));
-    let target_os = config.target_os.clone();
-    let mut lang = language(language_type, config, multi_file);
+        let parsed_data = match &from_ir {
+            Some(ir) => ir.crates(kind).cloned().with_context(|| {
+                format!(
+                    "The IR has no types for {} output",
+                    match kind {
+                        OutputKind::SingleFile => "file",
+                        OutputKind::MultiFile => "folder",
+                        OutputKind::PerModule => "per module",
+                    }
+                )
+            })?,
+            None => parse_sources(
+                &parse_context(kind, &config),
+                &CrateNames::default(),
+                source_walker(directories, options.follow_links)?,
+            )
+            .map_err(|err| report_parse_errors(err, options.message_format))?,
+        };
+
+        if let Some(ir) = &mut emitted_ir {
+            ir.set_crates(kind, parsed_data.clone());
+        }
 
-    let parse_context = ParseContext {
-        ignored_types: lang.ignored_reference_types(),
-        multi_file,
-        target_os,
-    };
+        for target in targets {
+            out_of_date.extend(generate_target(target, parsed_data.clone(), None, mode)?);
+        }
+    }
 
-    let mut parsed_data = parallel_parse(
-        &parse_context,
-        walker_builder(directories, options)?,
-        language_type,
-    )?;
+    if let (Some(path), Some(ir)) = (&options.emit_ir, &emitted_ir) {
+        ir::write_ir(path, ir)?;
+    }
 
-    reconcile_aliases(&mut parsed_data);
+    if mode == WriteMode::Check {
+        anyhow::ensure!(
+            out_of_date.is_empty(),
+            "{} generated files are out of date",
+            out_of_date.len()
+        );
+        info!("typeshare found all generated types up to date");
+        return Ok(());
+    }
 
-    // Collect all the types into a map of the file name they
-    // belong too and the list of type names. Used for generating
-    // imports in generated files.
-    let import_candidates = if multi_file {
-        all_types(&mut parsed_data)
-    } else {
-        HashMap::new()
-    };
+    info!("typeshare finished generating types");
+    Ok(())
+}
 
-    check_parse_errors(&parsed_data)?;
+/// Load the configuration and the targets to generate.
+fn load_targets(
+    config_file: Option<&Path>,
+    options: &Args,
+) -> anyhow::Result<(Config, Vec<Target>)> {
+    let config = config::load_config(config_file).context("Unable to read configuration file")?;
+    let config = override_configuration(config, options)?;
+    let targets = targets(&config, options)?;
+    Ok((config, targets))
+}
 
-    info!(
-        "typeshare started writing {} generated types",
-        parsed_data.len()
-    );
+/// The context to parse the sources with for a kind of output.
+fn parse_context(kind: OutputKind, config: &Config) -> ParseContext<'static> {
+    ParseContext {
+        // Types mapped by a language are ignored per target instead.
+        ignored_types: Vec::new(),
+        multi_file: kind.multi_file(),
+        per_module: kind.per_module(),
+        target_os: config.target_os.clone(),
+    }
+}
 
-    write_generated(destination, lang.as_mut(), parsed_data, import_candidates)?;
+/// Write the types for one target, returning the files that were out of date.
+///
+/// `crates` restricts the output to the files of those crates.
+fn generate_target(
+    target: &Target,
+    parsed_data: BTreeMap<CrateName, ParsedData>,
+    crates: Option<&HashSet<CrateName>>,
+    mode: WriteMode,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let language_type = match &target.backend {
+        Backend::Language(language_type) => *language_type,
+        Backend::Plugin { name, options } => {
+            info!("typeshare started generating types with plugin {name}");
+            // Plugins always generate every file; unchanged ones aren't rewritten.
+            let files = plugin::generate(name, options, target.kind, parsed_data)?;
+            let owner = format!("typeshare-gen-{name}");
+            return Ok(write_files(
+                target.destination(),
+                files,
+                WriteOptions {
+                    mode,
+                    prune: target.prune,
+                    owner: &owner,
+                    crates: None,
+                },
+            )?);
+        }
+    };
+    let mut lang = language(
+        language_type,
+        target.config.clone(),
+        target.kind.multi_file(),
+    );
 
-    info!("typeshare finished generating types");
-    Ok(())
+    Ok(write_generated(
+        target.destination(),
+        lang.as_mut(),
+        language_type,
+        parsed_data,
+        WriteOptions {
+            mode,
+            prune: target.prune,
+            owner: language_type.language_extension(),
+            crates,
+        },
+    )?)
 }
 
-fn walker_builder(
-    directories: &[std::path::PathBuf],
-    options: &Args,
-) -> anyhow::Result<WalkBuilder> {
-    let mut types = TypesBuilder::new();
-    types
-        .add("rust", "*.rs")
-        .context("Failed to add rust type extensions")?;
-    types.select("rust");
-
-    let first_root = directories
-        .first()
-        .expect("directories is empty; this shouldn't be possible");
-    let overrides = OverrideBuilder::new(first_root)
-        // Don't process files inside of tools/typeshare/
-        .add("!**/tools/typeshare/**")
-        .context("Failed to parse override")?
-        .build()
-        .context("Failed to build override")?;
-    let mut walker_builder = WalkBuilder::new(first_root);
-    walker_builder
-        .sort_by_file_path(Path::cmp)
-        .types(types.build().context("Failed to build types")?)
-        .overrides(overrides)
-        .follow_links(options.follow_links);
-    for root in directories.iter().skip(1) {
-        walker_builder.add(root);
+/// The targets to generate: the language or plugin and output given on the
+/// command line, or else every `[[targets]]` table in the configuration file.
+fn targets(config: &Config, options: &Args) -> anyhow::Result<Vec<Target>> {
+    let backend = match (options.language, &options.plugin) {
+        (Some(language), _) => Some(Backend::Language(language.into())),
+        (None, Some(name)) => Some(Backend::Plugin {
+            name: name.clone(),
+            options: toml::Table::new(),
+        }),
+        (None, None) => None,
+    };
+    if let Some(backend) = backend {
+        let (output, kind) = match (&options.output.file, &options.output.folder) {
+            (Some(file), _) => (file.clone(), OutputKind::SingleFile),
+            (None, Some(folder)) if options.per_module => (folder.clone(), OutputKind::PerModule),
+            (None, Some(folder)) => (folder.clone(), OutputKind::MultiFile),
+            (None, None) => {
+                anyhow::bail!("Please provide an output with --output-file or --output-folder")
+            }
+        };
+        return Ok(vec![Target {
+            backend,
+            output,
+            kind,
+            prune: !options.no_prune,
+            config: config.clone(),
+        }]);
+    }
+
+    anyhow::ensure!(
+        options.output.file.is_none() && options.output.folder.is_none(),
+        "Please provide a language with --lang or --plugin for the output"
+    );
+    if config.targets.is_empty() && options.emit_ir.is_some() {
+        return Ok(Vec::new());
     }
-    Ok(walker_builder)
+    anyhow::ensure!(
+        !config.targets.is_empty(),
+        "Please provide a language with --lang or --plugin or define [[targets]] in typeshare.toml"
+    );
+
+    config
+        .targets
+        .iter()
+        .map(|target| {
+            let backend = match (target.language, &target.plugin) {
+                (Some(language), None) => Backend::Language(language.into()),
+                (None, Some(name)) => Backend::Plugin {
+                    name: name.clone(),
+                    options: target.options.clone(),
+                },
+                _ => anyhow::bail!("Each target needs exactly one of language or plugin"),
+            };
+            let (output, kind) = match (&target.output_file, &target.output_folder) {
+                (Some(file), None) => (file.clone(), OutputKind::SingleFile),
+                (None, Some(folder)) if target.per_module || options.per_module => {
+                    (folder.clone(), OutputKind::PerModule)
+                }
+                (None, Some(folder)) => (folder.clone(), OutputKind::MultiFile),
+                _ => anyhow::bail!(
+                    "The {backend} target needs exactly one of output_file or output_folder"
+                ),
+            };
+            anyhow::ensure!(
+                !target.per_module || kind == OutputKind::PerModule,
+                "The {backend} target needs an output_folder to write a file per module"
+            );
+            // Options given on the command line still win over the target's own.
+            let config = override_configuration(config.for_target(target), options)?;
+            #[cfg(feature = "go")]
+            if target.language == Some(args::AvailableLanguage::Go) {
+                check_go_package(&config)?;
+            }
+            Ok(Target {
+                backend,
+                output,
+                kind,
+                prune: !options.no_prune,
+                config,
+            })
+        })
+        .collect()
 }
 
 /// Get the language trait impl for the given supported language and configuration.
@@ -209,6 +393,8 @@ fn language(
             package: config.scala.package,
             module_name: config.scala.module_name,
             type_mappings: config.scala.type_mappings,
+            dialect: config.scala.dialect,
+            circe: config.scala.circe,
             ..Default::default()
         }),
         SupportedLanguage::TypeScript => Box::new(TypeScript {
@@ -230,6 +416,7 @@ fn language(
         #[cfg(feature = "python")]
         SupportedLanguage::Python => Box::new(Python {
             type_mappings: config.python.type_mappings,
+            style: config.python.style,
             ..Default::default()
         }),
         #[cfg(not(feature = "python"))]
@@ -265,6 +452,15 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
         config.scala.module_name = scala_module_name.to_string();
     }
 
+    if let Some(scala_dialect) = options.scala_dialect {
+        config.scala.dialect = scala_dialect;
+    }
+
+    #[cfg(feature = "python")]
+    if let Some(python_style) = options.python_style {
+        config.python.style = python_style;
+    }
+
     #[cfg(feature = "go")]
     {
         if let Some(go_package) = options.go_package.as_ref() {
@@ -272,10 +468,7 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
         }
 
         if matches!(options.language, Some(args::AvailableLanguage::Go)) {
-            anyhow::ensure!(
-                    !config.go.package.is_empty(),
-                   "Please provide a package name in the typeshare.toml or using --go-package <package name>"
-                );
+            check_go_package(&config)?;
         }
     }
 
@@ -284,26 +477,22 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
     Ok(config)
 }
 
-/// Prints out all parsing errors if any and returns Err.
-fn check_parse_errors(parsed_crates: &BTreeMap<CrateName, ParsedData>) -> anyhow::Result<()> {
-    let mut errors_encountered = false;
-    for data in parsed_crates
-        .values()
-        .filter(|parsed_data| !parsed_data.errors.is_empty())
-    {
-        errors_encountered = true;
-        for error in &data.errors {
-            error!(
-                "Parsing error: \"{}\" in file \"{}\"",
-                error.error, error.file_name
-            );
-        }
-    }
+#[cfg(feature = "go")]
+fn check_go_package(config: &Config) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !config.go.package.is_empty(),
+        "Please provide a package name in the typeshare.toml or using --go-package <package name>"
+    );
+    Ok(())
+}
 
-    if errors_encountered {
-        error!("Errors encountered during parsing.");
-        Err(anyhow!("Errors encountered during parsing."))
-    } else {
-        Ok(())
-    }
+/// Reports all parsing errors, if that's what the error is.
+fn report_parse_errors(err: GeneratorError, message_format: MessageFormat) -> anyhow::Error {
+    let GeneratorError::Parse(errors) = err else {
+        return err.into();
+    };
+    diagnostics::report(&errors, message_format);
+
+    error!("{} errors encountered during parsing.", errors.len());
+    anyhow!("Errors encountered during parsing.")
 }
@@ -0,0 +1,184 @@
+//! Generating types with external `typeshare-gen-<name>` executables.
+//!
+//! A plugin reads one JSON request from stdin with the parsed types and the
+//! options of its target, and writes one JSON response to stdout listing the
+//! files to generate. Anything it writes to stderr is passed through.
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Component, Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+use typeshare_core::{
+    context::OutputKind, ir::IntermediateRepresentation, language::CrateName, parser::ParsedData,
+};
+
+/// The current version of the plugin protocol. It changes whenever requests or
+/// responses change in a way older plugins can't handle.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// What a plugin is asked to generate.
+#[derive(Serialize)]
+struct Request<'a> {
+    /// The version of the protocol.
+    version: u32,
+    /// Whether to generate a file per crate into a folder instead of a single file.
+    multi_file: bool,
+    /// Whether to generate a file per module instead of per crate. The crate
+    /// names in the IR are then module names, like `my_crate::a::b`.
+    per_module: bool,
+    /// The types to generate, for the kind of output of the target.
+    ir: IntermediateRepresentation,
+    /// The `options` table of the target.
+    options: &'a toml::Table,
+}
+
+/// What a plugin generated.
+#[derive(Deserialize)]
+struct Response {
+    files: Vec<GeneratedFile>,
+}
+
+/// A file generated by a plugin.
+#[derive(Deserialize)]
+struct GeneratedFile {
+    /// The path relative to the output folder.
+    path: PathBuf,
+    contents: String,
+}
+
+/// Generate the files of a target with the plugin of the given name.
+pub fn generate(
+    name: &str,
+    options: &toml::Table,
+    kind: OutputKind,
+    parsed_data: BTreeMap<CrateName, ParsedData>,
+) -> anyhow::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let program = format!("typeshare-gen-{name}");
+    let mut ir = IntermediateRepresentation::default();
+    ir.set_crates(kind, parsed_data);
+    let request = Request {
+        version: PROTOCOL_VERSION,
+        multi_file: kind.multi_file(),
+        per_module: kind.per_module(),
+        ir,
+        options,
+    };
+
+    run(Command::new(&program), &request).with_context(|| format!("Plugin {program} failed"))
+}
+
+/// Send the request to the plugin command and check the files it responds with.
+fn run(mut command: Command, request: &Request<'_>) -> anyhow::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let request = serde_json::to_vec(request).context("Failed to serialize the request")?;
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Failed to start the plugin; is it installed and on the PATH?")?;
+
+    // Write the request from another thread, so that a plugin writing its
+    // response before reading the whole request can't block on a full pipe.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = thread::spawn(move || stdin.write_all(&request));
+    let output = child
+        .wait_with_output()
+        .context("Failed to run the plugin")?;
+    let written = writer.join().expect("writing the request doesn't panic");
+
+    anyhow::ensure!(
+        output.status.success(),
+        "The plugin exited with {}",
+        output.status
+    );
+    written.context("Failed to send the request")?;
+
+    let response: Response =
+        serde_json::from_slice(&output.stdout).context("Failed to parse the response")?;
+    response
+        .files
+        .into_iter()
+        .map(|file| {
+            check_path(&file.path)?;
+            Ok((file.path, file.contents.into_bytes()))
+        })
+        .collect()
+}
+
+/// Make sure a generated file stays inside the output folder.
+fn check_path(path: &Path) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        path.components().next().is_some()
+            && path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_))),
+        "The generated file {path:?} isn't a relative path inside the output folder"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths_stay_in_output_folder() {
+        assert!(check_path(Path::new("types.dsl")).is_ok());
+        assert!(check_path(Path::new("nested/types.dsl")).is_ok());
+
+        assert!(check_path(Path::new("")).is_err());
+        assert!(check_path(Path::new("../types.dsl")).is_err());
+        assert!(check_path(Path::new("nested/../../types.dsl")).is_err());
+        assert!(check_path(Path::new("/tmp/types.dsl")).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn run_plugin() {
+        let options = toml::Table::from_iter([("namespace".to_owned(), "example".into())]);
+        let request = Request {
+            version: PROTOCOL_VERSION,
+            multi_file: true,
+            per_module: false,
+            ir: IntermediateRepresentation::default(),
+            options: &options,
+        };
+        // Answers with the namespace from the request.
+        let mut command = Command::new("sh");
+        command.args([
+            "-c",
+            r#"namespace=$(sed 's/.*"namespace":"\([^"]*\)".*/\1/')
+            printf '{"files":[{"path":"types.dsl","contents":"%s"}]}' "$namespace""#,
+        ]);
+
+        let files = run(command, &request).unwrap();
+
+        assert_eq!(
+            files,
+            vec![(PathBuf::from("types.dsl"), b"example".to_vec())]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn failing_plugin() {
+        let request = Request {
+            version: PROTOCOL_VERSION,
+            multi_file: false,
+            per_module: false,
+            ir: IntermediateRepresentation::default(),
+            options: &toml::Table::new(),
+        };
+        let mut command = Command::new("sh");
+        command.args(["-c", "exit 3"]);
+
+        let err = run(command, &request).unwrap_err();
+
+        assert!(err.to_string().contains("exited with"), "{err}");
+    }
+}
@@ -0,0 +1,472 @@
+//! Regenerating types whenever the Rust sources change.
+use anyhow::Context;
+use log::{error, info, warn};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, SystemTime},
+};
+use typeshare_core::{
+    context::{OutputKind, ParseContext},
+    crate_names::{CrateNames, MANIFEST_FILE_NAME},
+    language::CrateName,
+    parser::ParsedData,
+    reconcile::reconcile_aliases,
+    sources::{check_parse_errors, merge_crates, parallel_parse_files, parse_file, source_walker},
+    writer::WriteMode,
+};
+
+use crate::{
+    args::{Args, MessageFormat},
+    generate_target, load_targets, parse_context, report_parse_errors, Target,
+};
+
+/// How long to wait for more changes after the first one before regenerating.
+/// Saving in an editor or running `cargo fmt` touches several files at once.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A source file and what was parsed from it when it was last modified.
+struct SourceFile {
+    modified: Option<SystemTime>,
+    parsed_data: Option<ParsedData>,
+}
+
+/// The source files parsed for one kind of output, and the crates changed
+/// since types were last generated from them.
+struct SourceFiles {
+    parse_context: ParseContext<'static>,
+    crate_names: CrateNames,
+    files: BTreeMap<PathBuf, SourceFile>,
+    changed_crates: HashSet<CrateName>,
+}
+
+/// The targets sharing one kind of output, along with their parsed source files.
+struct WatchGroup<'a> {
+    sources: SourceFiles,
+    targets: Vec<&'a Target>,
+    message_format: MessageFormat,
+}
+
+/// Generate types, then keep regenerating the outputs of changed crates until
+/// the process is stopped.
+pub fn watch(config_file: Option<&Path>, options: &Args) -> anyhow::Result<()> {
+    anyhow::ensure!(!options.check, "--check can't be used when watching");
+    anyhow::ensure!(
+        options.emit_ir.is_none() && options.from_ir.is_none(),
+        "The IR can't be used when watching"
+    );
+
+    let (config, targets) = load_targets(config_file, options)?;
+    let directories = options.directories.as_slice();
+
+    info!("Using directories: {directories:?}");
+
+    let sources = source_files(options)?;
+    let mut groups = Vec::new();
+    for kind in OutputKind::ALL {
+        let targets = targets
+            .iter()
+            .filter(|target| target.kind == kind)
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            continue;
+        }
+
+        let parse_context = parse_context(kind, &config);
+        let crate_names = CrateNames::default();
+        let mut parsed_files = parallel_parse_files(
+            &parse_context,
+            &crate_names,
+            source_walker(directories, options.follow_links)?,
+        )?;
+        let files = sources
+            .iter()
+            .map(|(path, modified)| {
+                let source_file = SourceFile {
+                    modified: *modified,
+                    parsed_data: parsed_files.remove(path),
+                };
+                (path.clone(), source_file)
+            })
+            .collect();
+
+        let mut group = WatchGroup {
+            sources: SourceFiles {
+                parse_context,
+                crate_names,
+                files,
+                changed_crates: HashSet::new(),
+            },
+            targets,
+            message_format: options.message_format,
+        };
+        group.generate(false)?;
+        groups.push(group);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).context("Failed to start watching")?;
+    for directory in directories {
+        watcher
+            .watch(directory, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {directory:?}"))?;
+    }
+
+    info!("typeshare is watching for changes");
+
+    while let Ok(event) = rx.recv() {
+        let mut changes = Changes::default();
+        changes.record(event);
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            changes.record(event);
+        }
+        // Writing the generated files also triggers events.
+        if !changes.sources && !changes.manifests {
+            continue;
+        }
+
+        let sources = source_files(options)?;
+        for group in &mut groups {
+            if changes.manifests {
+                group.sources.reload_manifests();
+            }
+            group.sources.update(&sources);
+            if group.sources.changed_crates.is_empty() {
+                continue;
+            }
+            if let Err(err) = group.generate(true) {
+                error!("typeshare failed to regenerate types: {err}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl SourceFiles {
+    /// Forget the crates read from `Cargo.toml` manifests, which can rename
+    /// crates and their dependencies, so that every file is parsed again.
+    fn reload_manifests(&mut self) {
+        info!("Reloading {MANIFEST_FILE_NAME} manifests");
+        self.crate_names = CrateNames::default();
+        for source_file in self.files.values_mut() {
+            source_file.modified = None;
+        }
+    }
+
+    /// Re-parse the source files that were modified, added or removed, and
+    /// record the crates they belong to.
+    fn update(&mut self, sources: &BTreeMap<PathBuf, Option<SystemTime>>) {
+        let removed = self
+            .files
+            .keys()
+            .filter(|path| !sources.contains_key(*path))
+            .cloned()
+            .collect::<Vec<_>>();
+        for path in removed {
+            info!("Removed {path:?}");
+            let source_file = self.files.remove(&path);
+            self.changed_crates.extend(
+                source_file
+                    .and_then(|source_file| source_file.parsed_data)
+                    .map(|parsed_data| parsed_data.crate_name),
+            );
+        }
+
+        for (path, modified) in sources {
+            let source_file = self.files.get(path);
+            if source_file.is_some_and(|source_file| {
+                source_file.modified.is_some() && source_file.modified == *modified
+            }) {
+                continue;
+            }
+
+            info!("Parsing {path:?}");
+            let parsed_data = match parse_file(&self.parse_context, &self.crate_names, path) {
+                Ok(parsed_data) => parsed_data,
+                Err(err) => {
+                    // Keep the old types until the file can be read again.
+                    error!("{err}");
+                    continue;
+                }
+            };
+
+            self.changed_crates.extend(
+                source_file
+                    .and_then(|source_file| source_file.parsed_data.as_ref())
+                    .into_iter()
+                    .chain(&parsed_data)
+                    .map(|parsed_data| parsed_data.crate_name.clone()),
+            );
+            self.files.insert(
+                path.clone(),
+                SourceFile {
+                    modified: *modified,
+                    parsed_data,
+                },
+            );
+        }
+    }
+
+    /// The parsed data of every crate.
+    fn parsed_data(&self) -> BTreeMap<CrateName, ParsedData> {
+        merge_crates(
+            self.files
+                .values()
+                .filter_map(|source_file| source_file.parsed_data.clone()),
+        )
+    }
+
+    /// The changed crates and the crates importing from them, whose files
+    /// need to be written again.
+    fn crates_to_generate(
+        &self,
+        parsed_data: &BTreeMap<CrateName, ParsedData>,
+    ) -> HashSet<CrateName> {
+        let mut crates = self.changed_crates.clone();
+        crates.extend(
+            parsed_data
+                .iter()
+                .filter(|(_, data)| {
+                    data.import_types
+                        .iter()
+                        .any(|import| self.changed_crates.contains(&import.base_crate))
+                })
+                .map(|(crate_name, _)| crate_name.clone()),
+        );
+        crates
+    }
+}
+
+impl WatchGroup<'_> {
+    /// Generate the types of every target. When `only_changed` is set, only
+    /// the changed crates and the crates importing from them are written.
+    fn generate(&mut self, only_changed: bool) -> anyhow::Result<()> {
+        let mut parsed_data = self.sources.parsed_data();
+
+        reconcile_aliases(&mut parsed_data);
+
+        check_parse_errors(&parsed_data)
+            .map_err(|err| report_parse_errors(err, self.message_format))?;
+
+        let crates = only_changed.then(|| self.sources.crates_to_generate(&parsed_data));
+
+        for target in &self.targets {
+            generate_target(
+                target,
+                parsed_data.clone(),
+                crates.as_ref(),
+                WriteMode::Write,
+            )?;
+        }
+
+        self.sources.changed_crates.clear();
+        info!("typeshare finished generating types");
+        Ok(())
+    }
+}
+
+/// The kinds of files changed by file system events.
+#[derive(Default)]
+struct Changes {
+    /// A Rust source file changed.
+    sources: bool,
+    /// A `Cargo.toml` manifest changed.
+    manifests: bool,
+}
+
+impl Changes {
+    /// Record the files changed by an event. Reading the sources to parse them
+    /// also causes events, which are ignored.
+    fn record(&mut self, event: notify::Result<notify::Event>) {
+        let event = match event {
+            Ok(event) => event,
+            Err(err) => {
+                warn!("Failed watching for changes: {err}");
+                return;
+            }
+        };
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return;
+        }
+
+        for path in &event.paths {
+            self.sources |= path.extension().is_some_and(|extension| extension == "rs");
+            self.manifests |= path
+                .file_name()
+                .is_some_and(|file_name| file_name == MANIFEST_FILE_NAME);
+        }
+    }
+}
+
+/// Every source file in the input directories with the time it was last modified.
+fn source_files(options: &Args) -> anyhow::Result<BTreeMap<PathBuf, Option<SystemTime>>> {
+    let mut sources = BTreeMap::new();
+    for entry in source_walker(&options.directories, options.follow_links)?.build() {
+        let entry = entry.context("Failed traversing")?;
+        if entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            let modified = entry
+                .metadata()
+                .ok()
+                .and_then(|metadata| metadata.modified().ok());
+            sources.insert(entry.into_path(), modified);
+        }
+    }
+    Ok(sources)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CURRENT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+    const TEST_DIR: &str = "../core/data/workspace";
+
+    fn source_path(path: &str) -> PathBuf {
+        [CURRENT_DIR, TEST_DIR, path].iter().collect()
+    }
+
+    /// The workspace fixture parsed as if every file was last modified at `UNIX_EPOCH`.
+    fn parsed_workspace() -> (SourceFiles, BTreeMap<PathBuf, Option<SystemTime>>) {
+        let sources = [
+            "app/lib/main.rs",
+            "shared-types/src/lib.rs",
+            "shared-types/src/ids/user.rs",
+        ]
+        .into_iter()
+        .map(|path| (source_path(path), Some(SystemTime::UNIX_EPOCH)))
+        .collect::<BTreeMap<_, _>>();
+
+        let mut source_files = SourceFiles {
+            parse_context: ParseContext {
+                multi_file: true,
+                ..Default::default()
+            },
+            crate_names: CrateNames::default(),
+            files: BTreeMap::new(),
+            changed_crates: HashSet::new(),
+        };
+        source_files.update(&sources);
+        source_files.changed_crates.clear();
+        (source_files, sources)
+    }
+
+    fn crate_names(names: &[&str]) -> HashSet<CrateName> {
+        names.iter().map(|name| (*name).into()).collect()
+    }
+
+    #[test]
+    fn modified_file_reparses_its_crate() {
+        let (mut source_files, mut sources) = parsed_workspace();
+        // Unchanged files keep what was parsed from them before.
+        let app = source_path("app/lib/main.rs");
+        source_files.files.get_mut(&app).unwrap().parsed_data = None;
+
+        let user = source_path("shared-types/src/ids/user.rs");
+        sources.insert(user, Some(SystemTime::now()));
+        source_files.update(&sources);
+
+        assert_eq!(source_files.changed_crates, crate_names(&["shared"]));
+        assert!(source_files.files[&app].parsed_data.is_none());
+    }
+
+    #[test]
+    fn removed_file_marks_its_crate_changed() {
+        let (mut source_files, mut sources) = parsed_workspace();
+
+        let app = source_path("app/lib/main.rs");
+        sources.remove(&app);
+        source_files.update(&sources);
+
+        assert_eq!(source_files.changed_crates, crate_names(&["app_server"]));
+        assert!(!source_files.files.contains_key(&app));
+        assert!(!source_files
+            .parsed_data()
+            .contains_key(&"app_server".into()));
+    }
+
+    #[test]
+    fn changed_manifests_rename_crates() {
+        let dir = std::env::temp_dir().join("typeshare_watch_manifests");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        let lib = dir.join("src").join("lib.rs");
+        std::fs::write(&lib, "#[typeshare]\npub struct User {}\n").unwrap();
+        let manifest = dir.join(MANIFEST_FILE_NAME);
+        std::fs::write(&manifest, "[package]\nname = \"before\"\n").unwrap();
+
+        let sources = BTreeMap::from([(lib, Some(SystemTime::UNIX_EPOCH))]);
+        let mut source_files = SourceFiles {
+            parse_context: ParseContext {
+                multi_file: true,
+                ..Default::default()
+            },
+            crate_names: CrateNames::default(),
+            files: BTreeMap::new(),
+            changed_crates: HashSet::new(),
+        };
+        source_files.update(&sources);
+        source_files.changed_crates.clear();
+
+        std::fs::write(&manifest, "[package]\nname = \"after\"\n").unwrap();
+        source_files.reload_manifests();
+        source_files.update(&sources);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            source_files.changed_crates,
+            crate_names(&["before", "after"])
+        );
+        assert!(source_files.parsed_data().contains_key(&"after".into()));
+    }
+
+    #[test]
+    fn manifest_events_are_recorded() {
+        let event = |path: &str| {
+            Ok(
+                notify::Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+                    .add_path(source_path(path)),
+            )
+        };
+
+        let mut changes = Changes::default();
+        changes.record(event("shared-types/Cargo.toml"));
+        assert!(changes.manifests && !changes.sources);
+
+        changes.record(event("shared-types/src/lib.rs"));
+        assert!(changes.manifests && changes.sources);
+    }
+
+    #[test]
+    fn importers_of_changed_crates_are_generated() {
+        let (mut source_files, mut sources) = parsed_workspace();
+
+        sources.insert(
+            source_path("shared-types/src/lib.rs"),
+            Some(SystemTime::now()),
+        );
+        source_files.update(&sources);
+
+        let mut parsed_data = source_files.parsed_data();
+        reconcile_aliases(&mut parsed_data);
+        assert_eq!(
+            source_files.crates_to_generate(&parsed_data),
+            crate_names(&["shared", "app_server"])
+        );
+
+        // Nothing imports from the app.
+        source_files.changed_crates = crate_names(&["app_server"]);
+        assert_eq!(
+            source_files.crates_to_generate(&parsed_data),
+            crate_names(&["app_server"])
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use shared::{Platform, Tagged, UserId};
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct Session {
+    pub user: UserId,
+    pub expires_in: u32,
+    pub platform: Platform,
+    pub tagged: Tagged<Session>,
+}
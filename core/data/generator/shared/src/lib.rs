@@ -0,0 +1,20 @@
+use std::marker::PhantomData;
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct UserId {
+    pub id: String,
+}
+
+#[typeshare]
+pub enum Platform {
+    Ios,
+    #[serde(other)]
+    Unknown,
+}
+
+#[typeshare]
+pub struct Tagged<T> {
+    pub value: u32,
+    pub _tag: PhantomData<T>,
+}
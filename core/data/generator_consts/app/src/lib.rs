@@ -0,0 +1,8 @@
+use lengths::{Buffer, KEY_LEN};
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct Key {
+    pub bytes: [u8; KEY_LEN],
+    pub buffer: Buffer<KEY_LEN>,
+}
@@ -0,0 +1,9 @@
+use typeshare::typeshare;
+
+#[typeshare(serialized_as = "u32")]
+pub const KEY_LEN: usize = 4;
+
+#[typeshare]
+pub struct Buffer<const N: usize> {
+    pub data: Vec<u8>,
+}
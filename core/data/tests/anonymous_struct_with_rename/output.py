@@ -49,3 +49,4 @@ class AnonymousStructWithRenameKebabCase(BaseModel):
     content: AnonymousStructWithRenameKebabCaseInner
 
 AnonymousStructWithRename = Union[AnonymousStructWithRenameList, AnonymousStructWithRenameLongFieldNames, AnonymousStructWithRenameKebabCase]
+
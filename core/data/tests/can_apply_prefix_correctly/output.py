@@ -41,3 +41,4 @@ class AdvancedColorsDictionaryReallyCoolType(BaseModel):
     c: Dict[str, ItemDetailsFieldValue]
 
 AdvancedColors = Union[AdvancedColorsString, AdvancedColorsNumber, AdvancedColorsNumberArray, AdvancedColorsReallyCoolType, AdvancedColorsArrayReallyCoolType, AdvancedColorsDictionaryReallyCoolType]
+
@@ -10,6 +10,7 @@ class ItemDetailsFieldValue(BaseModel):
     Struct comment
     """
     pass
+
 class AdvancedColorsTypes(str, Enum):
     STRING = "String"
     NUMBER = "Number"
@@ -45,6 +46,7 @@ class AdvancedColorsReallyCoolType(BaseModel):
 
 # Enum comment
 AdvancedColors = Union[AdvancedColorsString, AdvancedColorsNumber, AdvancedColorsUnsignedNumber, AdvancedColorsNumberArray, AdvancedColorsReallyCoolType]
+
 class AdvancedColors2Types(str, Enum):
     STRING = "string"
     NUMBER = "number"
@@ -74,3 +76,4 @@ class AdvancedColors2ReallyCoolType(BaseModel):
     content: ItemDetailsFieldValue
 
 AdvancedColors2 = Union[AdvancedColors2String, AdvancedColors2Number, AdvancedColors2NumberArray, AdvancedColors2ReallyCoolType]
+
@@ -17,3 +17,4 @@ class SomeEnumC(BaseModel):
     content: int
 
 SomeEnum = Union[SomeEnumA, SomeEnumC]
+
@@ -43,3 +43,4 @@ class AutofilledBySomethingElse(BaseModel):
 
 # Enum keeping track of who autofilled a field
 AutofilledBy = Union[AutofilledByUs, AutofilledBySomethingElse]
+
@@ -10,3 +10,4 @@ class Colors(str, Enum):
     RED = "Red"
     BLUE = "Blue"
     GREEN = "Green"
+
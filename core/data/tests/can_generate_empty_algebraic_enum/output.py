@@ -7,6 +7,7 @@ from typing import Literal, Union
 
 class AddressDetails(BaseModel):
     pass
+
 class AddressTypes(str, Enum):
     FIXED_ADDRESS = "FixedAddress"
     NO_FIXED_ADDRESS = "NoFixedAddress"
@@ -19,3 +20,4 @@ class AddressNoFixedAddress(BaseModel):
     type: Literal[AddressTypes.NO_FIXED_ADDRESS] = AddressTypes.NO_FIXED_ADDRESS
 
 Address = Union[AddressFixedAddress, AddressNoFixedAddress]
+
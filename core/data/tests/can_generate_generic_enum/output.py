@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel
+from typing import Dict, Generic, List, Literal, TypeVar, Union
+from typing_extensions import TypeAliasType
+
+A = TypeVar("A")
+B = TypeVar("B")
+T = TypeVar("T")
+U = TypeVar("U")
+
+
+class GenericEnumTypes(str, Enum):
+    VARIANT_A = "VariantA"
+    VARIANT_B = "VariantB"
+
+class GenericEnumVariantA(BaseModel, Generic[A]):
+    type: Literal[GenericEnumTypes.VARIANT_A] = GenericEnumTypes.VARIANT_A
+    content: A
+
+class GenericEnumVariantB(BaseModel, Generic[B]):
+    type: Literal[GenericEnumTypes.VARIANT_B] = GenericEnumTypes.VARIANT_B
+    content: B
+
+GenericEnum = TypeAliasType("GenericEnum", Union[GenericEnumVariantA[A], GenericEnumVariantB[B]], type_params=(A, B))
+
+class StructUsingGenericEnum(BaseModel):
+    enum_field: GenericEnum[str, int]
+
+class GenericEnumUsingGenericEnumTypes(str, Enum):
+    VARIANT_C = "VariantC"
+    VARIANT_D = "VariantD"
+    VARIANT_E = "VariantE"
+
+class GenericEnumUsingGenericEnumVariantC(BaseModel, Generic[T]):
+    type: Literal[GenericEnumUsingGenericEnumTypes.VARIANT_C] = GenericEnumUsingGenericEnumTypes.VARIANT_C
+    content: GenericEnum[T, T]
+
+class GenericEnumUsingGenericEnumVariantD(BaseModel, Generic[T]):
+    type: Literal[GenericEnumUsingGenericEnumTypes.VARIANT_D] = GenericEnumUsingGenericEnumTypes.VARIANT_D
+    content: GenericEnum[str, Dict[str, T]]
+
+class GenericEnumUsingGenericEnumVariantE(BaseModel):
+    type: Literal[GenericEnumUsingGenericEnumTypes.VARIANT_E] = GenericEnumUsingGenericEnumTypes.VARIANT_E
+    content: GenericEnum[str, int]
+
+GenericEnumUsingGenericEnum = TypeAliasType("GenericEnumUsingGenericEnum", Union[GenericEnumUsingGenericEnumVariantC[T], GenericEnumUsingGenericEnumVariantD[T], GenericEnumUsingGenericEnumVariantE], type_params=(T,))
+
+class GenericEnumsUsingStructVariantsVariantFInner(BaseModel, Generic[T]):
+    """
+    Generated type representing the anonymous struct variant `VariantF` of the `GenericEnumsUsingStructVariants` Rust enum
+    """
+    action: T
+
+class GenericEnumsUsingStructVariantsVariantGInner(BaseModel, Generic[T, U]):
+    """
+    Generated type representing the anonymous struct variant `VariantG` of the `GenericEnumsUsingStructVariants` Rust enum
+    """
+    action: T
+    response: U
+
+class GenericEnumsUsingStructVariantsVariantHInner(BaseModel):
+    """
+    Generated type representing the anonymous struct variant `VariantH` of the `GenericEnumsUsingStructVariants` Rust enum
+    """
+    non_generic: int
+
+class GenericEnumsUsingStructVariantsVariantIInner(BaseModel, Generic[T, U]):
+    """
+    Generated type representing the anonymous struct variant `VariantI` of the `GenericEnumsUsingStructVariants` Rust enum
+    """
+    vec: List[T]
+    action: MyType[T, U]
+
+class GenericEnumsUsingStructVariantsTypes(str, Enum):
+    VARIANT_F = "VariantF"
+    VARIANT_G = "VariantG"
+    VARIANT_H = "VariantH"
+    VARIANT_I = "VariantI"
+
+class GenericEnumsUsingStructVariantsVariantF(BaseModel, Generic[T]):
+    type: Literal[GenericEnumsUsingStructVariantsTypes.VARIANT_F] = GenericEnumsUsingStructVariantsTypes.VARIANT_F
+    content: GenericEnumsUsingStructVariantsVariantFInner[T]
+
+class GenericEnumsUsingStructVariantsVariantG(BaseModel, Generic[T, U]):
+    type: Literal[GenericEnumsUsingStructVariantsTypes.VARIANT_G] = GenericEnumsUsingStructVariantsTypes.VARIANT_G
+    content: GenericEnumsUsingStructVariantsVariantGInner[T, U]
+
+class GenericEnumsUsingStructVariantsVariantH(BaseModel):
+    type: Literal[GenericEnumsUsingStructVariantsTypes.VARIANT_H] = GenericEnumsUsingStructVariantsTypes.VARIANT_H
+    content: GenericEnumsUsingStructVariantsVariantHInner
+
+class GenericEnumsUsingStructVariantsVariantI(BaseModel, Generic[T, U]):
+    type: Literal[GenericEnumsUsingStructVariantsTypes.VARIANT_I] = GenericEnumsUsingStructVariantsTypes.VARIANT_I
+    content: GenericEnumsUsingStructVariantsVariantIInner[T, U]
+
+GenericEnumsUsingStructVariants = TypeAliasType("GenericEnumsUsingStructVariants", Union[GenericEnumsUsingStructVariantsVariantF[T], GenericEnumsUsingStructVariantsVariantG[T, U], GenericEnumsUsingStructVariantsVariantH, GenericEnumsUsingStructVariantsVariantI[T, U]], type_params=(T, U))
+
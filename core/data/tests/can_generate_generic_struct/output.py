@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel
+from typing import Generic, List, Literal, TypeVar, Union
+
+A = TypeVar("A")
+B = TypeVar("B")
+T = TypeVar("T")
+
+
+class GenericStruct(BaseModel, Generic[A, B]):
+    field_a: A
+    field_b: List[B]
+
+class GenericStructUsingGenericStruct(BaseModel, Generic[T]):
+    struct_field: GenericStruct[str, T]
+    second_struct_field: GenericStruct[T, str]
+    third_struct_field: GenericStruct[T, List[T]]
+
+class EnumUsingGenericStructTypes(str, Enum):
+    VARIANT_A = "VariantA"
+    VARIANT_B = "VariantB"
+    VARIANT_C = "VariantC"
+    VARIANT_D = "VariantD"
+
+class EnumUsingGenericStructVariantA(BaseModel):
+    type: Literal[EnumUsingGenericStructTypes.VARIANT_A] = EnumUsingGenericStructTypes.VARIANT_A
+    content: GenericStruct[str, float]
+
+class EnumUsingGenericStructVariantB(BaseModel):
+    type: Literal[EnumUsingGenericStructTypes.VARIANT_B] = EnumUsingGenericStructTypes.VARIANT_B
+    content: GenericStruct[str, int]
+
+class EnumUsingGenericStructVariantC(BaseModel):
+    type: Literal[EnumUsingGenericStructTypes.VARIANT_C] = EnumUsingGenericStructTypes.VARIANT_C
+    content: GenericStruct[str, bool]
+
+class EnumUsingGenericStructVariantD(BaseModel):
+    type: Literal[EnumUsingGenericStructTypes.VARIANT_D] = EnumUsingGenericStructTypes.VARIANT_D
+    content: GenericStructUsingGenericStruct[None]
+
+EnumUsingGenericStruct = Union[EnumUsingGenericStructVariantA, EnumUsingGenericStructVariantB, EnumUsingGenericStructVariantC, EnumUsingGenericStructVariantD]
+
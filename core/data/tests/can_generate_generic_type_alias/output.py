@@ -0,0 +1,11 @@
+from __future__ import annotations
+
+from typing import List, Optional, TypeVar
+
+T = TypeVar("T")
+
+
+GenericTypeAlias = List[T]
+
+NonGenericAlias = GenericTypeAlias[Optional[str]]
+
@@ -9,7 +9,7 @@ type Person struct {
 	// This is another comment
 	Name string `json:"name"`
 	Age int `json:"age"`
-	Info *string `json:"info,omitempty"`
+	Info *string `json:"info"`
 	Emails []string `json:"emails"`
 	Location Location `json:"location"`
 }
@@ -6,6 +6,7 @@ from typing import List, Optional
 
 class Location(BaseModel):
     pass
+
 class Person(BaseModel):
     """
     This is a comment.
@@ -6,7 +6,7 @@ export interface Person {
 	/** This is another comment */
 	name: string;
 	age: number;
-	info?: string;
+	info: string | null;
 	emails: string[];
 	location: Location;
 }
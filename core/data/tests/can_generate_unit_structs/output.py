@@ -5,3 +5,4 @@ from pydantic import BaseModel
 
 class UnitStruct(BaseModel):
     pass
+
@@ -47,6 +47,7 @@ class AutofilledBySomethingElse(BaseModel):
 
 # Enum keeping track of who autofilled a field
 AutofilledBy = Union[AutofilledByUs, AutofilledBySomethingElse]
+
 class EnumWithManyVariantsAnonVariantInner(BaseModel):
     """
     Generated type representing the anonymous struct variant `AnonVariant` of the `EnumWithManyVariants` Rust enum
@@ -92,3 +93,4 @@ class EnumWithManyVariantsAnotherAnonVariant(BaseModel):
 
 # This is a comment (yareek sameek wuz here)
 EnumWithManyVariants = Union[EnumWithManyVariantsUnitVariant, EnumWithManyVariantsTupleVariantString, EnumWithManyVariantsAnonVariant, EnumWithManyVariantsTupleVariantInt, EnumWithManyVariantsAnotherUnitVariant, EnumWithManyVariantsAnotherAnonVariant]
+
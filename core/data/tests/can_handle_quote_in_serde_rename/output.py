@@ -8,3 +8,4 @@ class Colors(str, Enum):
     This is a comment.
     """
     GREEN = "Green\""
+
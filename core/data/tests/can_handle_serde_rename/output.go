@@ -9,7 +9,7 @@ type Person struct {
 	Name string `json:"name"`
 	Age int `json:"age"`
 	ExtraSpecialField1 int `json:"extraSpecialFieldOne"`
-	ExtraSpecialField2 *[]string `json:"extraSpecialFieldTwo,omitempty"`
+	ExtraSpecialField2 *[]string `json:"extraSpecialFieldTwo"`
 	NonStandardDataType OtherType `json:"nonStandardDataType"`
-	NonStandardDataTypeInArray *[]OtherType `json:"nonStandardDataTypeInArray,omitempty"`
+	NonStandardDataTypeInArray *[]OtherType `json:"nonStandardDataTypeInArray"`
 }
@@ -4,7 +4,7 @@ export interface Person {
 	lastName: string;
 	age: number;
 	extraSpecialField1: number;
-	extraSpecialField2?: string[];
+	extraSpecialField2: string[] | null;
 }
 
 /** This is a Person2 struct with UPPERCASE rename */
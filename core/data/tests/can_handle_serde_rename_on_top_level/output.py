@@ -6,6 +6,7 @@ from typing import List, Optional
 
 class OtherType(BaseModel):
     pass
+
 class PersonTwo(BaseModel):
     """
     This is a comment.
@@ -6,8 +6,8 @@ export interface PersonTwo {
 	name: string;
 	age: number;
 	extraSpecialFieldOne: number;
-	extraSpecialFieldTwo?: string[];
+	extraSpecialFieldTwo: string[] | null;
 	nonStandardDataType: OtherType;
-	nonStandardDataTypeInArray?: OtherType[];
+	nonStandardDataTypeInArray: OtherType[] | null;
 }
 
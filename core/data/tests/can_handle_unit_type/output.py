@@ -22,3 +22,4 @@ class EnumHasVoidTypeHasAUnit(BaseModel):
 
 # This enum has a variant associated with unit data
 EnumHasVoidType = EnumHasVoidTypeHasAUnit
+
@@ -0,0 +1,33 @@
+const KEY_LEN: usize = 4;
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Key {
+    pub bytes: [u8; KEY_LEN],
+    pub checksum: [u8; { KEY_LEN }],
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Buffer<const N: usize> {
+    pub data: [u32; N],
+    pub keys: Vec<Key>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Borrowed<'a, T, const N: usize> {
+    pub name: &'a str,
+    pub label: Cow<'a, str>,
+    pub values: [T; N],
+    pub buffer: Buffer<N>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Keyring {
+    pub current: Buffer<KEY_LEN>,
+    /// `KEY_COUNT` is not defined in this file
+    pub keys: [Key; KEY_COUNT],
+    pub buffers: Vec<Buffer<KEY_COUNT>>,
+}
@@ -0,0 +1,24 @@
+package proto
+
+import "encoding/json"
+
+type Key struct {
+	Bytes [4]int `json:"bytes"`
+	Checksum [4]int `json:"checksum"`
+}
+type Buffer struct {
+	Data []uint32 `json:"data"`
+	Keys []Key `json:"keys"`
+}
+type Borrowed[T any] struct {
+	Name string `json:"name"`
+	Label string `json:"label"`
+	Values []T `json:"values"`
+	Buffer Buffer `json:"buffer"`
+}
+type Keyring struct {
+	Current Buffer `json:"current"`
+	// `KEY_COUNT` is not defined in this file
+	Keys []Key `json:"keys"`
+	Buffers []Buffer `json:"buffers"`
+}
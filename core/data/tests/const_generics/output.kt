@@ -0,0 +1,33 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Key (
+	val bytes: List<UByte>,
+	val checksum: List<UByte>
+)
+
+@Serializable
+data class Buffer (
+	val data: List<UInt>,
+	val keys: List<Key>
+)
+
+@Serializable
+data class Borrowed<T> (
+	val name: String,
+	val label: String,
+	val values: List<T>,
+	val buffer: Buffer
+)
+
+@Serializable
+data class Keyring (
+	val current: Buffer,
+	/// `KEY_COUNT` is not defined in this file
+	val keys: List<Key>,
+	val buffers: List<Buffer>
+)
+
@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+from pydantic import BaseModel
+from typing import Generic, List, TypeVar
+
+T = TypeVar("T")
+
+
+class Key(BaseModel):
+    bytes: List[int]
+    checksum: List[int]
+
+class Buffer(BaseModel):
+    data: List[int]
+    keys: List[Key]
+
+class Borrowed(BaseModel, Generic[T]):
+    name: str
+    label: str
+    values: List[T]
+    buffer: Buffer
+
+class Keyring(BaseModel):
+    current: Buffer
+    keys: List[Key]
+    """
+    `KEY_COUNT` is not defined in this file
+    """
+    buffers: List[Buffer]
+
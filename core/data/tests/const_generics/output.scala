@@ -0,0 +1,37 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Int
+
+}
+package onepassword {
+
+case class Borrowed[T] (
+	name: String,
+	label: String,
+	values: Vector[T],
+	buffer: Buffer
+)
+
+case class Buffer (
+	data: Vector[UInt],
+	keys: Vector[Key]
+)
+
+case class Key (
+	bytes: Vector[UByte],
+	checksum: Vector[UByte]
+)
+
+case class Keyring (
+	current: Buffer,
+	// `KEY_COUNT` is not defined in this file
+	keys: Vector[Key],
+	buffers: Vector[Buffer]
+)
+
+}
@@ -0,0 +1,48 @@
+import Foundation
+
+public struct Key: Codable {
+	public let bytes: [UInt8]
+	public let checksum: [UInt8]
+
+	public init(bytes: [UInt8], checksum: [UInt8]) {
+		self.bytes = bytes
+		self.checksum = checksum
+	}
+}
+
+public struct Buffer: Codable {
+	public let data: [UInt32]
+	public let keys: [Key]
+
+	public init(data: [UInt32], keys: [Key]) {
+		self.data = data
+		self.keys = keys
+	}
+}
+
+public struct Borrowed<T: Codable>: Codable {
+	public let name: String
+	public let label: String
+	public let values: [T]
+	public let buffer: Buffer
+
+	public init(name: String, label: String, values: [T], buffer: Buffer) {
+		self.name = name
+		self.label = label
+		self.values = values
+		self.buffer = buffer
+	}
+}
+
+public struct Keyring: Codable {
+	public let current: Buffer
+	/// `KEY_COUNT` is not defined in this file
+	public let keys: [Key]
+	public let buffers: [Buffer]
+
+	public init(current: Buffer, keys: [Key], buffers: [Buffer]) {
+		self.current = current
+		self.keys = keys
+		self.buffers = buffers
+	}
+}
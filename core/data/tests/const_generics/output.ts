@@ -0,0 +1,24 @@
+export interface Key {
+	bytes: [number, number, number, number];
+	checksum: [number, number, number, number];
+}
+
+export interface Buffer {
+	data: number[];
+	keys: Key[];
+}
+
+export interface Borrowed<T> {
+	name: string;
+	label: string;
+	values: T[];
+	buffer: Buffer;
+}
+
+export interface Keyring {
+	current: Buffer;
+	/** `KEY_COUNT` is not defined in this file */
+	keys: Key[];
+	buffers: Buffer[];
+}
+
@@ -14,3 +14,4 @@ class Colors(str, Enum):
     """
     Green is a cool color
     """
+
@@ -11,6 +11,7 @@ class AlwaysAccept(BaseModel):
     we use --target-os.
     """
     pass
+
 class DefinedTwice(BaseModel):
     model_config = ConfigDict(populate_by_name=True)
 
@@ -18,19 +19,26 @@ class DefinedTwice(BaseModel):
 
 class Excluded(BaseModel):
     pass
+
 class ManyStruct(BaseModel):
     pass
+
 class MultipleTargets(BaseModel):
     pass
+
 class NestedNotTarget1(BaseModel):
     pass
+
 class OtherExcluded(BaseModel):
     pass
+
 class AlwaysAcceptEnum(str, Enum):
     VARIANT1 = "Variant1"
     VARIANT2 = "Variant2"
+
 class SomeEnum(str, Enum):
     pass
+
 class TestEnumVariant7Inner(BaseModel):
     """
     Generated type representing the anonymous struct variant `Variant7` of the `TestEnum` Rust enum
@@ -72,3 +80,4 @@ class TestEnumVariant9(BaseModel):
     content: TestEnumVariant9Inner
 
 TestEnum = Union[TestEnumVariant1, TestEnumVariant5, TestEnumVariant7, TestEnumVariant8, TestEnumVariant9]
+
@@ -13,6 +13,6 @@ type Types struct {
 	Array []string `json:"array"`
 	FixedLengthArray [4]string `json:"fixed_length_array"`
 	Dictionary map[string]int `json:"dictionary"`
-	OptionalDictionary *map[string]int `json:"optional_dictionary,omitempty"`
+	OptionalDictionary *map[string]int `json:"optional_dictionary"`
 	CustomType CustomType `json:"custom_type"`
 }
@@ -6,6 +6,7 @@ from typing import Dict, List, Optional
 
 class CustomType(BaseModel):
     pass
+
 class Types(BaseModel):
     model_config = ConfigDict(populate_by_name=True)
 
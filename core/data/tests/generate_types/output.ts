@@ -10,7 +10,7 @@ export interface Types {
 	array: string[];
 	fixed_length_array: [string, string, string, string];
 	dictionary: Record<string, number>;
-	optional_dictionary?: Record<string, number>;
+	optional_dictionary: Record<string, number> | null;
 	custom_type: CustomType;
 }
 
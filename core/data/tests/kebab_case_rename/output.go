@@ -5,6 +5,6 @@ import "encoding/json"
 // This is a comment.
 type Things struct {
 	Bla string `json:"bla"`
-	SomeLabel *string `json:"label,omitempty"`
-	LabelLeft *string `json:"label-left,omitempty"`
+	SomeLabel *string `json:"label"`
+	LabelLeft *string `json:"label-left"`
 }
@@ -1,7 +1,7 @@
 /** This is a comment. */
 export interface Things {
 	bla: string;
-	label?: string;
-	"label-left"?: string;
+	label: string | null;
+	"label-left": string | null;
 }
 
@@ -16,5 +16,5 @@ type E struct {
 }
 type D struct {
 	DependsOn C `json:"dependsOn"`
-	AlsoDependsOn *E `json:"alsoDependsOn,omitempty"`
+	AlsoDependsOn *E `json:"alsoDependsOn"`
 }
@@ -25,7 +25,7 @@ public struct C: Codable {
 }
 
 public struct E: Codable {
-	public let dependsOn: D
+	@Indirect public var dependsOn: D
 
 	public init(dependsOn: D) {
 		self.dependsOn = dependsOn
@@ -34,10 +34,72 @@ public struct E: Codable {
 
 public struct D: Codable {
 	public let dependsOn: C
-	public let alsoDependsOn: E?
+	@Indirect public var alsoDependsOn: E?
 
 	public init(dependsOn: C, alsoDependsOn: E?) {
 		self.dependsOn = dependsOn
 		self.alsoDependsOn = alsoDependsOn
 	}
 }
+
+/// Stores its value on the heap so that structs can reference themselves
+@propertyWrapper
+public struct Indirect<T> {
+	private final class Box {
+		var value: T
+		init(_ value: T) { self.value = value }
+	}
+
+	private var box: Box
+
+	public init(wrappedValue: T) {
+		box = Box(wrappedValue)
+	}
+
+	public var wrappedValue: T {
+		get { box.value }
+		set {
+			if isKnownUniquelyReferenced(&box) {
+				box.value = newValue
+			} else {
+				box = Box(newValue)
+			}
+		}
+	}
+}
+
+extension Indirect: Codable where T: Codable {
+	public init(from decoder: Decoder) throws {
+		self.init(wrappedValue: try T(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		try wrappedValue.encode(to: encoder)
+	}
+}
+
+extension Indirect: Equatable where T: Equatable {
+	public static func == (lhs: Indirect<T>, rhs: Indirect<T>) -> Bool {
+		lhs.wrappedValue == rhs.wrappedValue
+	}
+}
+
+extension Indirect: Hashable where T: Hashable {
+	public func hash(into hasher: inout Hasher) {
+		hasher.combine(wrappedValue)
+	}
+}
+
+extension Indirect: @unchecked Sendable where T: Sendable {}
+
+extension KeyedDecodingContainer {
+	public func decode<T: Decodable>(_ type: Indirect<T?>.Type, forKey key: Key) throws -> Indirect<T?> {
+		Indirect(wrappedValue: try decodeIfPresent(T.self, forKey: key))
+	}
+}
+
+extension KeyedEncodingContainer {
+	public mutating func encode<T: Encodable>(_ value: Indirect<T?>, forKey key: Key) throws {
+		try encodeIfPresent(value.wrappedValue, forKey: key)
+	}
+}
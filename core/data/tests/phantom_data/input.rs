@@ -0,0 +1,38 @@
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Id<T>(String, PhantomData<T>);
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Page<T, Marker> {
+    pub items: Vec<T>,
+    pub next: Option<Id<Marker>>,
+    pub _marker: PhantomData<Marker>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Typed<State> {
+    pub value: u32,
+    #[serde(skip)]
+    pub cached: bool,
+    pub state: std::marker::PhantomData<State>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Message<T> {
+    Text(String),
+    /// Serialized with `null` content
+    Ping(PhantomData<T>),
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Catalog {
+    pub pages: Vec<Page<String, Catalog>>,
+    pub current: Typed<Catalog>,
+    pub last_message: Message<Catalog>,
+}
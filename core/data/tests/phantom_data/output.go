@@ -0,0 +1,89 @@
+package proto
+
+import "encoding/json"
+
+type Id string
+
+type Page[T any] struct {
+	Items []T `json:"items"`
+	Next *Id `json:"next"`
+	Marker *struct{} `json:"_marker"`
+}
+type Typed struct {
+	Value uint32 `json:"value"`
+	State *struct{} `json:"state"`
+}
+type MessageTypes string
+const (
+	MessageTypeVariantText MessageTypes = "Text"
+	// Serialized with `null` content
+	MessageTypeVariantPing MessageTypes = "Ping"
+)
+type Message struct{ 
+	Type MessageTypes `json:"type"`
+	content interface{}
+}
+
+func (m *Message) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    MessageTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	m.Type = enum.Tag
+	switch m.Type {
+	case MessageTypeVariantText:
+		var res string
+		m.content = &res
+	case MessageTypeVariantPing:
+		var res struct{}
+		m.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &m.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (m Message) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    MessageTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = m.Type
+    enum.Content = m.content
+    return json.Marshal(enum)
+}
+
+func (m Message) Text() string {
+	res, _ := m.content.(*string)
+	return *res
+}
+func (m Message) Ping() struct{} {
+	res, _ := m.content.(*struct{})
+	return *res
+}
+
+func NewMessageTypeVariantText(content string) Message {
+    return Message{
+        Type: MessageTypeVariantText,
+        content: &content,
+    }
+}
+func NewMessageTypeVariantPing(content struct{}) Message {
+    return Message{
+        Type: MessageTypeVariantPing,
+        content: &content,
+    }
+}
+
+type Catalog struct {
+	Pages []Page[string] `json:"pages"`
+	Current Typed `json:"current"`
+	LastMessage Message `json:"last_message"`
+}
@@ -0,0 +1,38 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+typealias Id = String
+
+@Serializable
+data class Page<T> (
+	val items: List<T>,
+	val next: Id? = null,
+	val _marker: Unit? = null
+)
+
+@Serializable
+data class Typed (
+	val value: UInt,
+	val state: Unit? = null
+)
+
+@Serializable
+sealed class Message {
+	@Serializable
+	@SerialName("Text")
+	data class Text(val content: String): Message()
+	/// Serialized with `null` content
+	@Serializable
+	@SerialName("Ping")
+	data class Ping(val content: Unit): Message()
+}
+
+@Serializable
+data class Catalog (
+	val pages: List<Page<String>>,
+	val current: Typed,
+	val last_message: Message
+)
+
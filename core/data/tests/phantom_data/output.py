@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel, ConfigDict, Field
+from typing import Generic, List, Literal, Optional, TypeVar, Union
+
+T = TypeVar("T")
+
+
+Id = str
+
+class Page(BaseModel, Generic[T]):
+    model_config = ConfigDict(populate_by_name=True)
+
+    items: List[T]
+    next: Optional[Id] = Field(default=None)
+    marker: Optional[None] = Field(alias="_marker", default=None)
+
+class Typed(BaseModel):
+    value: int
+    state: Optional[None] = Field(default=None)
+
+class MessageTypes(str, Enum):
+    TEXT = "Text"
+    PING = "Ping"
+
+class MessageText(BaseModel):
+    type: Literal[MessageTypes.TEXT] = MessageTypes.TEXT
+    content: str
+
+class MessagePing(BaseModel):
+    """
+    Serialized with `null` content
+    """
+    type: Literal[MessageTypes.PING] = MessageTypes.PING
+    content: None
+
+Message = Union[MessageText, MessagePing]
+
+class Catalog(BaseModel):
+    pages: List[Page[str]]
+    current: Typed
+    last_message: Message
+
@@ -0,0 +1,86 @@
+import Foundation
+
+public typealias Id = String
+
+public struct Page<T: Codable>: Codable {
+	public let items: [T]
+	public let next: Id?
+	public let _marker: CodableVoid?
+
+	public init(items: [T], next: Id?, _marker: CodableVoid?) {
+		self.items = items
+		self.next = next
+		self._marker = _marker
+	}
+}
+
+public struct Typed: Codable {
+	public let value: UInt32
+	public let state: CodableVoid?
+
+	public init(value: UInt32, state: CodableVoid?) {
+		self.value = value
+		self.state = state
+	}
+}
+
+public enum Message: Codable {
+	case text(String)
+	/// Serialized with `null` content
+	case ping(CodableVoid)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case text = "Text",
+			ping = "Ping"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .text:
+				if let content = try? container.decode(String.self, forKey: .content) {
+					self = .text(content)
+					return
+				}
+			case .ping:
+				if let content = try? container.decode(CodableVoid.self, forKey: .content) {
+					self = .ping(content)
+					return
+				}
+			}
+		}
+		throw DecodingError.typeMismatch(Message.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Message"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .text(let content):
+			try container.encode(CodingKeys.text, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .ping(let content):
+			try container.encode(CodingKeys.ping, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
+
+public struct Catalog: Codable {
+	public let pages: [Page<String>]
+	public let current: Typed
+	public let last_message: Message
+
+	public init(pages: [Page<String>], current: Typed, last_message: Message) {
+		self.pages = pages
+		self.current = current
+		self.last_message = last_message
+	}
+}
+
+/// () isn't codable, so we use this instead to represent Rust's unit type
+public struct CodableVoid: Codable {}
@@ -0,0 +1,24 @@
+export type Id = string;
+
+export interface Page<T> {
+	items: T[];
+	next: Id | null;
+	_marker?: null;
+}
+
+export interface Typed {
+	value: number;
+	state?: null;
+}
+
+export type Message = 
+	| { type: "Text", content: string }
+	/** Serialized with `null` content */
+	| { type: "Ping", content: undefined };
+
+export interface Catalog {
+	pages: Page<string>[];
+	current: Typed;
+	last_message: Message;
+}
+
@@ -0,0 +1,43 @@
+/// A user of the system.
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// The user's display name.
+    display_name: String,
+    age: u32,
+    nickname: Option<String>,
+    #[serde(default)]
+    is_admin: bool,
+    class: String,
+    homepage: url::Url,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Page<T> {
+    items: Vec<T>,
+    next_cursor: Option<String>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Empty {}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A response envelope.
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Response<T> {
+    Pending,
+    Done(T),
+    Failed { code: u32, message: String },
+}
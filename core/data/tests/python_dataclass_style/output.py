@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+from enum import Enum
+from typing import Generic, List, Literal, Optional, TypeVar, Union
+from typing_extensions import TypeAliasType
+import dataclasses
+
+T = TypeVar("T")
+
+
+@dataclasses.dataclass(kw_only=True)
+class Empty:
+    pass
+
+@dataclasses.dataclass(kw_only=True)
+class Page(Generic[T]):
+    items: List[T]
+    next_cursor: Optional[str] = None
+
+@dataclasses.dataclass(kw_only=True)
+class User:
+    """
+    A user of the system.
+    """
+    display_name: str = dataclasses.field(metadata={"alias": "displayName"})
+    """
+    The user's display name.
+    """
+    age: int
+    nickname: Optional[str] = None
+    is_admin: bool = dataclasses.field(default=False, metadata={"alias": "isAdmin"})
+    class_: str = dataclasses.field(metadata={"alias": "class"})
+    homepage: str
+
+class Color(str, Enum):
+    RED = "Red"
+    GREEN = "Green"
+    BLUE = "Blue"
+
+@dataclasses.dataclass(kw_only=True)
+class ResponseFailedInner:
+    """
+    Generated type representing the anonymous struct variant `Failed` of the `Response` Rust enum
+    """
+    code: int
+    message: str
+
+class ResponseTypes(str, Enum):
+    PENDING = "Pending"
+    DONE = "Done"
+    FAILED = "Failed"
+
+@dataclasses.dataclass(kw_only=True)
+class ResponsePending:
+    type: Literal[ResponseTypes.PENDING] = ResponseTypes.PENDING
+
+@dataclasses.dataclass(kw_only=True)
+class ResponseDone(Generic[T]):
+    type: Literal[ResponseTypes.DONE] = ResponseTypes.DONE
+    content: T
+
+@dataclasses.dataclass(kw_only=True)
+class ResponseFailed:
+    type: Literal[ResponseTypes.FAILED] = ResponseTypes.FAILED
+    content: ResponseFailedInner
+
+# A response envelope.
+Response = TypeAliasType("Response", Union[ResponsePending, ResponseDone[T], ResponseFailed], type_params=(T,))
+
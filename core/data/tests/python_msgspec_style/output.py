@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+from enum import Enum
+from typing import Generic, List, Optional, TypeVar, Union
+from typing_extensions import TypeAliasType
+import msgspec
+
+T = TypeVar("T")
+
+
+class Empty(msgspec.Struct, kw_only=True):
+    pass
+
+class Page(msgspec.Struct, Generic[T], kw_only=True):
+    items: List[T]
+    next_cursor: Optional[str] = None
+
+class User(msgspec.Struct, kw_only=True):
+    """
+    A user of the system.
+    """
+    display_name: str = msgspec.field(name="displayName")
+    """
+    The user's display name.
+    """
+    age: int
+    nickname: Optional[str] = None
+    is_admin: bool = msgspec.field(default=False, name="isAdmin")
+    class_: str = msgspec.field(name="class")
+    homepage: str
+
+class Color(str, Enum):
+    RED = "Red"
+    GREEN = "Green"
+    BLUE = "Blue"
+
+class ResponseFailedInner(msgspec.Struct, kw_only=True):
+    """
+    Generated type representing the anonymous struct variant `Failed` of the `Response` Rust enum
+    """
+    code: int
+    message: str
+
+class ResponsePending(msgspec.Struct, tag_field="type", tag="Pending"):
+    pass
+
+class ResponseDone(msgspec.Struct, Generic[T], tag_field="type", tag="Done"):
+    content: T
+
+class ResponseFailed(msgspec.Struct, tag_field="type", tag="Failed"):
+    content: ResponseFailedInner
+
+# A response envelope.
+Response = TypeAliasType("Response", Union[ResponsePending, ResponseDone[T], ResponseFailed], type_params=(T,))
+
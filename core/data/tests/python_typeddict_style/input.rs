@@ -0,0 +1,54 @@
+/// A user of the system.
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// The user's display name.
+    display_name: String,
+    age: u32,
+    nickname: Option<String>,
+    #[serde(default)]
+    is_admin: bool,
+    class: String,
+    homepage: url::Url,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Page<T> {
+    items: Vec<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Empty {}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A response envelope.
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Response<T> {
+    Pending,
+    Done(T),
+    Failed { code: u32, message: String },
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Headers {
+    /// The media type of the body.
+    content_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_length: Option<u32>,
+}
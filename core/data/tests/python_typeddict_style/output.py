@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+from typing import Generic, List, Literal, NotRequired, Optional, TypeVar, TypedDict, Union
+from typing_extensions import TypeAliasType
+
+T = TypeVar("T")
+
+
+class Empty(TypedDict):
+    pass
+
+Headers = TypedDict("Headers", {
+    # The media type of the body.
+    "content-type": str,
+    "content-length": NotRequired[Optional[int]],
+})
+
+class Page(TypedDict, Generic[T]):
+    items: List[T]
+    next_cursor: NotRequired[Optional[str]]
+
+# A user of the system.
+User = TypedDict("User", {
+    # The user's display name.
+    "displayName": str,
+    "age": int,
+    "nickname": Optional[str],
+    "isAdmin": NotRequired[bool],
+    "class": str,
+    "homepage": str,
+})
+
+Color = Literal["Red", "Green", "Blue"]
+
+class ResponseFailedInner(TypedDict):
+    """
+    Generated type representing the anonymous struct variant `Failed` of the `Response` Rust enum
+    """
+    code: int
+    message: str
+
+class ResponsePending(TypedDict):
+    type: Literal["Pending"]
+
+class ResponseDone(TypedDict, Generic[T]):
+    type: Literal["Done"]
+    content: T
+
+class ResponseFailed(TypedDict):
+    type: Literal["Failed"]
+    content: ResponseFailedInner
+
+# A response envelope.
+Response = TypeAliasType("Response", Union[ResponsePending, ResponseDone[T], ResponseFailed], type_params=(T,))
+
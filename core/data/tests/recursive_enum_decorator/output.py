@@ -35,6 +35,7 @@ class MoreOptionsBuilt(BaseModel):
     content: MoreOptionsBuiltInner
 
 MoreOptions = Union[MoreOptionsNews, MoreOptionsExactly, MoreOptionsBuilt]
+
 class OptionsTypes(str, Enum):
     RED = "red"
     BANANA = "banana"
@@ -53,3 +54,4 @@ class OptionsVermont(BaseModel):
     content: Options
 
 Options = Union[OptionsRed, OptionsBanana, OptionsVermont]
+
@@ -0,0 +1,26 @@
+/// A node in a tree that refers back to its parent.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Node {
+    pub name: String,
+    pub children: Vec<Node>,
+    pub parent: Option<Box<Node>>,
+}
+
+#[typeshare]
+pub struct LinkedList {
+    pub value: u32,
+    pub next: Box<LinkedList>,
+}
+
+/// Refers to itself through `Child`, so their fields referring to each other are boxed.
+#[typeshare]
+pub struct Parent {
+    pub child: Option<Child>,
+}
+
+#[typeshare]
+pub struct Child {
+    pub parent: Option<Box<Parent>>,
+    pub siblings: Vec<Child>,
+}
@@ -0,0 +1,105 @@
+import Foundation
+
+/// Refers to itself through `Child`, so their fields referring to each other are boxed.
+public struct Parent: Codable {
+	@Indirect public var child: Child?
+
+	public init(child: Child?) {
+		self.child = child
+	}
+}
+
+public struct Child: Codable {
+	@Indirect public var parent: Parent?
+	public let siblings: [Child]
+
+	public init(parent: Parent?, siblings: [Child]) {
+		self.parent = parent
+		self.siblings = siblings
+	}
+}
+
+public struct LinkedList: Codable {
+	public let value: UInt32
+	@Indirect public var next: LinkedList
+
+	public init(value: UInt32, next: LinkedList) {
+		self.value = value
+		self.next = next
+	}
+}
+
+/// A node in a tree that refers back to its parent.
+public struct Node: Codable {
+	public let name: String
+	public let children: [Node]
+	@Indirect public var parent: Node?
+
+	public init(name: String, children: [Node], parent: Node?) {
+		self.name = name
+		self.children = children
+		self.parent = parent
+	}
+}
+
+/// Stores its value on the heap so that structs can reference themselves
+@propertyWrapper
+public struct Indirect<T> {
+	private final class Box {
+		var value: T
+		init(_ value: T) { self.value = value }
+	}
+
+	private var box: Box
+
+	public init(wrappedValue: T) {
+		box = Box(wrappedValue)
+	}
+
+	public var wrappedValue: T {
+		get { box.value }
+		set {
+			if isKnownUniquelyReferenced(&box) {
+				box.value = newValue
+			} else {
+				box = Box(newValue)
+			}
+		}
+	}
+}
+
+extension Indirect: Codable where T: Codable {
+	public init(from decoder: Decoder) throws {
+		self.init(wrappedValue: try T(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		try wrappedValue.encode(to: encoder)
+	}
+}
+
+extension Indirect: Equatable where T: Equatable {
+	public static func == (lhs: Indirect<T>, rhs: Indirect<T>) -> Bool {
+		lhs.wrappedValue == rhs.wrappedValue
+	}
+}
+
+extension Indirect: Hashable where T: Hashable {
+	public func hash(into hasher: inout Hasher) {
+		hasher.combine(wrappedValue)
+	}
+}
+
+extension Indirect: @unchecked Sendable where T: Sendable {}
+
+extension KeyedDecodingContainer {
+	public func decode<T: Decodable>(_ type: Indirect<T?>.Type, forKey key: Key) throws -> Indirect<T?> {
+		Indirect(wrappedValue: try decodeIfPresent(T.self, forKey: key))
+	}
+}
+
+extension KeyedEncodingContainer {
+	public mutating func encode<T: Encodable>(_ value: Indirect<T?>, forKey key: Key) throws {
+		try encodeIfPresent(value.wrappedValue, forKey: key)
+	}
+}
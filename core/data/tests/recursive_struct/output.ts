@@ -0,0 +1,22 @@
+/** Refers to itself through `Child`, so their fields referring to each other are boxed. */
+export interface Parent {
+	child: Child | null;
+}
+
+export interface Child {
+	parent: Parent | null;
+	siblings: Child[];
+}
+
+export interface LinkedList {
+	value: number;
+	next: LinkedList;
+}
+
+/** A node in a tree that refers back to its parent. */
+export interface Node {
+	name: string;
+	children: Node[];
+	parent: Node | null;
+}
+
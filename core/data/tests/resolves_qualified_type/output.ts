@@ -3,7 +3,7 @@ export interface QualifiedTypes {
 	qualified: string;
 	qualified_vec: string[];
 	qualified_hashmap: Record<string, string>;
-	qualified_optional?: string;
-	qualfied_optional_hashmap_vec?: Record<string, string[]>;
+	qualified_optional: string | null;
+	qualfied_optional_hashmap_vec: Record<string, string[]> | null;
 }
 
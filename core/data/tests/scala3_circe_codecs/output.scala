@@ -0,0 +1,139 @@
+package com.agilebits
+
+import io.circe.{Decoder, DecodingFailure, Encoder, Json}
+import io.circe.syntax.*
+
+package onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Int
+
+class Marker extends Serializable
+
+object Marker {
+	given encoder: Encoder[Marker] = Encoder.instance(_ => Json.obj())
+	given decoder: Decoder[Marker] = Decoder.const(new Marker)
+}
+
+case class Page[T] (
+	items: Vector[T]
+)
+
+object Page {
+	given encoder[T: Encoder]: Encoder[Page[T]] = Encoder.instance { value =>
+		Json.obj(
+			"items" -> value.items.asJson
+		)
+	}
+	given decoder[T: Decoder]: Decoder[Page[T]] = Decoder.instance { c =>
+		for {
+			items <- c.downField("items").as[Vector[T]]
+		} yield Page(items)
+	}
+}
+
+case class User (
+	// The user's display name.
+	displayName: String,
+	age: UInt,
+	nickname: Option[String] = None
+)
+
+object User {
+	given encoder: Encoder[User] = Encoder.instance { value =>
+		Json.obj(
+			"displayName" -> value.displayName.asJson,
+			"age" -> value.age.asJson,
+			"nickname" -> value.nickname.asJson
+		)
+	}
+	given decoder: Decoder[User] = Decoder.instance { c =>
+		for {
+			displayName <- c.downField("displayName").as[String]
+			age <- c.downField("age").as[UInt]
+			nickname <- c.downField("nickname").as[Option[String]]
+		} yield User(displayName, age, nickname)
+	}
+}
+
+// Some colors.
+enum Color(val serialName: String) {
+	case Red extends Color("red")
+	// A calm color.
+	case Green extends Color("green")
+}
+
+object Color {
+	given encoder: Encoder[Color] = Encoder.encodeString.contramap(_.serialName)
+	given decoder: Decoder[Color] = Decoder.decodeString.emap {
+		case "red" => Right(Color.Red)
+		case "green" => Right(Color.Green)
+		case other => Left(s"Unknown Color variant: $other")
+	}
+}
+
+enum Response[T](val serialName: String) {
+	case Pending[T]() extends Response[T]("Pending")
+	case Done[T](content: T) extends Response[T]("Done")
+}
+
+object Response {
+	given encoder[T: Encoder]: Encoder[Response[T]] = Encoder.instance {
+		case Response.Pending() => Json.obj("type" -> Json.fromString("Pending"))
+		case Response.Done(content) => Json.obj("type" -> Json.fromString("Done"), "content" -> content.asJson)
+	}
+	given decoder[T: Decoder]: Decoder[Response[T]] = Decoder.instance { c =>
+		c.downField("type").as(Decoder.decodeString).flatMap {
+			case "Pending" => Right(Response.Pending[T]())
+			case "Done" => c.downField("content").as[T].map(Response.Done[T](_))
+			case other => Left(DecodingFailure(s"Unknown Response variant: $other", c.history))
+		}
+	}
+}
+
+// Generated type representing the anonymous struct variant `Rectangle` of the `Shape` Rust enum
+case class ShapeRectangleInner (
+	width: Double,
+	height: Double
+)
+
+object ShapeRectangleInner {
+	given encoder: Encoder[ShapeRectangleInner] = Encoder.instance { value =>
+		Json.obj(
+			"width" -> value.width.asJson,
+			"height" -> value.height.asJson
+		)
+	}
+	given decoder: Decoder[ShapeRectangleInner] = Decoder.instance { c =>
+		for {
+			width <- c.downField("width").as[Double]
+			height <- c.downField("height").as[Double]
+		} yield ShapeRectangleInner(width, height)
+	}
+}
+
+enum Shape(val serialName: String) {
+	case Empty extends Shape("Empty")
+	case Circle(data: Double) extends Shape("Circle")
+	case Rectangle(data: ShapeRectangleInner) extends Shape("Rectangle")
+}
+
+object Shape {
+	given encoder: Encoder[Shape] = Encoder.instance {
+		case Shape.Empty => Json.obj("kind" -> Json.fromString("Empty"))
+		case Shape.Circle(content) => Json.obj("kind" -> Json.fromString("Circle"), "data" -> content.asJson)
+		case Shape.Rectangle(content) => Json.obj("kind" -> Json.fromString("Rectangle"), "data" -> content.asJson)
+	}
+	given decoder: Decoder[Shape] = Decoder.instance { c =>
+		c.downField("kind").as(Decoder.decodeString).flatMap {
+			case "Empty" => Right(Shape.Empty)
+			case "Circle" => c.downField("data").as[Double].map(Shape.Circle(_))
+			case "Rectangle" => c.downField("data").as[ShapeRectangleInner].map(Shape.Rectangle(_))
+			case other => Left(DecodingFailure(s"Unknown Shape variant: $other", c.history))
+		}
+	}
+}
+
+}
@@ -0,0 +1,46 @@
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// The user's display name.
+    display_name: String,
+    age: u32,
+    nickname: Option<String>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Marker {}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Page<T> {
+    items: Vec<T>,
+}
+
+/// Some colors.
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    Red,
+    /// A calm color.
+    Green,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Shape {
+    Empty,
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Response<T> {
+    Pending,
+    Done(T),
+}
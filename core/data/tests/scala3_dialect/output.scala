@@ -0,0 +1,47 @@
+package com.agilebits
+
+package onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Int
+
+class Marker extends Serializable
+
+case class Page[T] (
+	items: Vector[T]
+)
+
+case class User (
+	// The user's display name.
+	displayName: String,
+	age: UInt,
+	nickname: Option[String] = None
+)
+
+// Some colors.
+enum Color(val serialName: String) {
+	case Red extends Color("red")
+	// A calm color.
+	case Green extends Color("green")
+}
+
+enum Response[T](val serialName: String) {
+	case Pending[T]() extends Response[T]("Pending")
+	case Done[T](content: T) extends Response[T]("Done")
+}
+
+// Generated type representing the anonymous struct variant `Rectangle` of the `Shape` Rust enum
+case class ShapeRectangleInner (
+	width: Double,
+	height: Double
+)
+
+enum Shape(val serialName: String) {
+	case Empty extends Shape("Empty")
+	case Circle(data: Double) extends Shape("Circle")
+	case Rectangle(data: ShapeRectangleInner) extends Shape("Rectangle")
+}
+
+}
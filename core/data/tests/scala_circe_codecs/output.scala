@@ -0,0 +1,156 @@
+package com.agilebits
+
+import io.circe.{Decoder, DecodingFailure, Encoder, Json}
+import io.circe.syntax._
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Int
+
+}
+package onepassword {
+
+class Marker extends Serializable
+
+object Marker {
+	implicit val encoder: Encoder[Marker] = Encoder.instance(_ => Json.obj())
+	implicit val decoder: Decoder[Marker] = Decoder.const(new Marker)
+}
+
+case class Page[T] (
+	items: Vector[T]
+)
+
+object Page {
+	implicit def encoder[T: Encoder]: Encoder[Page[T]] = Encoder.instance { value =>
+		Json.obj(
+			"items" -> value.items.asJson
+		)
+	}
+	implicit def decoder[T: Decoder]: Decoder[Page[T]] = Decoder.instance { c =>
+		for {
+			items <- c.downField("items").as[Vector[T]]
+		} yield Page(items)
+	}
+}
+
+case class User (
+	// The user's display name.
+	displayName: String,
+	age: UInt,
+	nickname: Option[String] = None
+)
+
+object User {
+	implicit val encoder: Encoder[User] = Encoder.instance { value =>
+		Json.obj(
+			"displayName" -> value.displayName.asJson,
+			"age" -> value.age.asJson,
+			"nickname" -> value.nickname.asJson
+		)
+	}
+	implicit val decoder: Decoder[User] = Decoder.instance { c =>
+		for {
+			displayName <- c.downField("displayName").as[String]
+			age <- c.downField("age").as[UInt]
+			nickname <- c.downField("nickname").as[Option[String]]
+		} yield User(displayName, age, nickname)
+	}
+}
+
+// Some colors.
+sealed trait Color {
+	def serialName: String
+}
+object Color {
+	case object Red extends Color {
+		val serialName: String = "red"
+	}
+	// A calm color.
+	case object Green extends Color {
+		val serialName: String = "green"
+	}
+	implicit val encoder: Encoder[Color] = Encoder.encodeString.contramap(_.serialName)
+	implicit val decoder: Decoder[Color] = Decoder.decodeString.emap {
+		case "red" => Right(Color.Red)
+		case "green" => Right(Color.Green)
+		case other => Left(s"Unknown Color variant: $other")
+	}
+}
+
+sealed trait Response[T] {
+	def serialName: String
+}
+object Response {
+	case object Pending extends Response[T] {
+		val serialName: String = "Pending"
+	}
+	case class Done[T](content: T) extends Response[T] {
+		val serialName: String = "Done"
+	}
+	implicit def encoder[T: Encoder]: Encoder[Response[T]] = Encoder.instance {
+		case Response.Pending => Json.obj("type" -> Json.fromString("Pending"))
+		case Response.Done(content) => Json.obj("type" -> Json.fromString("Done"), "content" -> content.asJson)
+	}
+	implicit def decoder[T: Decoder]: Decoder[Response[T]] = Decoder.instance { c =>
+		c.downField("type").as(Decoder.decodeString).flatMap {
+			case "Pending" => Right(Response.Pending)
+			case "Done" => c.downField("content").as[T].map(Response.Done[T](_))
+			case other => Left(DecodingFailure(s"Unknown Response variant: $other", c.history))
+		}
+	}
+}
+
+// Generated type representing the anonymous struct variant `Rectangle` of the `Shape` Rust enum
+case class ShapeRectangleInner (
+	width: Double,
+	height: Double
+)
+
+object ShapeRectangleInner {
+	implicit val encoder: Encoder[ShapeRectangleInner] = Encoder.instance { value =>
+		Json.obj(
+			"width" -> value.width.asJson,
+			"height" -> value.height.asJson
+		)
+	}
+	implicit val decoder: Decoder[ShapeRectangleInner] = Decoder.instance { c =>
+		for {
+			width <- c.downField("width").as[Double]
+			height <- c.downField("height").as[Double]
+		} yield ShapeRectangleInner(width, height)
+	}
+}
+
+sealed trait Shape {
+	def serialName: String
+}
+object Shape {
+	case object Empty extends Shape {
+		val serialName: String = "Empty"
+	}
+	case class Circle(data: Double) extends Shape {
+		val serialName: String = "Circle"
+	}
+	case class Rectangle(data: ShapeRectangleInner) extends Shape {
+		val serialName: String = "Rectangle"
+	}
+	implicit val encoder: Encoder[Shape] = Encoder.instance {
+		case Shape.Empty => Json.obj("kind" -> Json.fromString("Empty"))
+		case Shape.Circle(content) => Json.obj("kind" -> Json.fromString("Circle"), "data" -> content.asJson)
+		case Shape.Rectangle(content) => Json.obj("kind" -> Json.fromString("Rectangle"), "data" -> content.asJson)
+	}
+	implicit val decoder: Decoder[Shape] = Decoder.instance { c =>
+		c.downField("kind").as(Decoder.decodeString).flatMap {
+			case "Empty" => Right(Shape.Empty)
+			case "Circle" => c.downField("data").as[Double].map(Shape.Circle(_))
+			case "Rectangle" => c.downField("data").as[ShapeRectangleInner].map(Shape.Rectangle(_))
+			case other => Left(DecodingFailure(s"Unknown Shape variant: $other", c.history))
+		}
+	}
+}
+
+}
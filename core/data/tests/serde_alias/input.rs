@@ -0,0 +1,17 @@
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    #[serde(alias = "user_name", alias = "login")]
+    pub display_name: String,
+    #[serde(alias = "mail")]
+    pub email: Option<String>,
+    pub age: u32,
+}
+
+#[typeshare]
+pub enum Status {
+    #[serde(alias = "enabled")]
+    Active,
+    #[serde(alias = "disabled", alias = "off")]
+    Inactive,
+}
@@ -0,0 +1,54 @@
+package proto
+
+import "encoding/json"
+
+type Profile struct {
+	DisplayName string `json:"displayName"`
+	Email *string `json:"email"`
+	Age uint32 `json:"age"`
+}
+
+func (p *Profile) UnmarshalJSON(data []byte) error {
+	type plain Profile
+	var aux struct {
+		*plain
+		DisplayNameAlias0 *string `json:"user_name"`
+		DisplayNameAlias1 *string `json:"login"`
+		EmailAlias0 **string `json:"mail"`
+	}
+	aux.plain = (*plain)(p)
+	if err := json.Unmarshal(data, &aux); err != nil {
+		return err
+	}
+	if aux.DisplayNameAlias0 != nil {
+		p.DisplayName = *aux.DisplayNameAlias0
+	}
+	if aux.DisplayNameAlias1 != nil {
+		p.DisplayName = *aux.DisplayNameAlias1
+	}
+	if aux.EmailAlias0 != nil {
+		p.Email = *aux.EmailAlias0
+	}
+	return nil
+}
+type Status string
+const (
+	StatusActive Status = "Active"
+	StatusInactive Status = "Inactive"
+)
+
+func (s *Status) UnmarshalJSON(data []byte) error {
+	var value string
+	if err := json.Unmarshal(data, &value); err != nil {
+		return err
+	}
+	switch value {
+	case "enabled":
+		*s = StatusActive
+	case "disabled", "off":
+		*s = StatusInactive
+	default:
+		*s = Status(value)
+	}
+	return nil
+}
@@ -0,0 +1,27 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.json.JsonNames
+
+@Serializable
+data class Profile (
+	@JsonNames("user_name", "login")
+	val displayName: String,
+	@JsonNames("mail")
+	val email: String? = null,
+	val age: UInt
+)
+
+@Serializable
+enum class Status(val string: String) {
+	@SerialName("Active")
+	@JsonNames("enabled")
+	Active("Active"),
+	@SerialName("Inactive")
+	@JsonNames("disabled", "off")
+	Inactive("Inactive"),
+}
+
@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import AliasChoices, BaseModel, ConfigDict, Field
+from typing import Optional
+
+
+class Profile(BaseModel):
+    model_config = ConfigDict(populate_by_name=True)
+
+    display_name: str = Field(alias="displayName", validation_alias=AliasChoices("displayName", "user_name", "login"))
+    email: Optional[str] = Field(validation_alias=AliasChoices("email", "mail"), default=None)
+    age: int
+
+class Status(str, Enum):
+    ACTIVE = "Active"
+    INACTIVE = "Inactive"
+
+    @classmethod
+    def _missing_(cls, value):
+        if value == "enabled":
+            return cls.ACTIVE
+        if value in ("disabled", "off"):
+            return cls.INACTIVE
+        return None
+
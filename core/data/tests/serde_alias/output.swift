@@ -0,0 +1,54 @@
+import Foundation
+
+public struct Profile: Codable {
+	public let displayName: String
+	public let email: String?
+	public let age: UInt32
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case displayName,
+			email,
+			age
+	}
+
+	enum AliasCodingKeys: String, CodingKey {
+		case user_name = "user_name",
+			login = "login",
+			mail = "mail"
+	}
+
+	public init(displayName: String, email: String?, age: UInt32) {
+		self.displayName = displayName
+		self.email = email
+		self.age = age
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let aliasContainer = try decoder.container(keyedBy: AliasCodingKeys.self)
+		self.displayName = try container.decodeIfPresent(String.self, forKey: .displayName) ?? aliasContainer.decodeIfPresent(String.self, forKey: .user_name) ?? aliasContainer.decode(String.self, forKey: .login)
+		self.email = try container.decodeIfPresent(String.self, forKey: .email) ?? aliasContainer.decodeIfPresent(String.self, forKey: .mail)
+		self.age = try container.decode(UInt32.self, forKey: .age)
+	}
+}
+
+public enum Status: String, Codable {
+	case active = "Active"
+	case inactive = "Inactive"
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		let rawValue = try container.decode(RawValue.self)
+		switch rawValue {
+		case "enabled":
+			self = .active
+		case "disabled", "off":
+			self = .inactive
+		default:
+			guard let value = Self(rawValue: rawValue) else {
+				throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown value for Status: \(rawValue)")
+			}
+			self = value
+		}
+	}
+}
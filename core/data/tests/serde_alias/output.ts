@@ -0,0 +1,11 @@
+export interface Profile {
+	displayName: string;
+	email: string | null;
+	age: number;
+}
+
+export enum Status {
+	Active = "Active",
+	Inactive = "Inactive",
+}
+
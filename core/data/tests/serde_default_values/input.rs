@@ -0,0 +1,78 @@
+fn default_port() -> u16 {
+    8080
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct ServerConfig {
+    #[serde(default = "default_host")]
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub verbose: bool,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[typeshare]
+#[serde(default)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff_factor: f64,
+    pub label: String,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff_factor: 1.5,
+            label: String::from("retry"),
+        }
+    }
+}
+
+struct Primary;
+
+impl Primary {
+    fn port() -> u16 {
+        443
+    }
+}
+
+struct Fallback;
+
+impl Fallback {
+    fn port() -> u16 {
+        80
+    }
+}
+
+mod defaults {
+    pub fn timeout_secs() -> u32 {
+        30
+    }
+}
+
+#[typeshare]
+pub struct Endpoint {
+    #[serde(default = "Primary::port")]
+    pub port: u16,
+    #[serde(default = "Fallback::port")]
+    pub fallback_port: u16,
+    #[serde(default = "defaults::timeout_secs")]
+    pub timeout_secs: u32,
+    #[serde(default = "Self::retries")]
+    pub retries: u32,
+}
+
+impl Endpoint {
+    fn retries() -> u32 {
+        5
+    }
+}
@@ -0,0 +1,28 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Endpoint (
+	val port: UShort = 443u,
+	val fallback_port: UShort = 80u,
+	val timeout_secs: UInt = 30u,
+	val retries: UInt = 5u
+)
+
+@Serializable
+data class RetryPolicy (
+	val max_attempts: UInt = 3u,
+	val backoff_factor: Double = 1.5,
+	val label: String = "retry"
+)
+
+@Serializable
+data class ServerConfig (
+	val host: String = "localhost",
+	val port: UShort = 8080u,
+	val verbose: Boolean = false,
+	val name: String? = null
+)
+
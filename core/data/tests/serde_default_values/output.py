@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+from pydantic import BaseModel, Field
+from typing import Optional
+
+
+class Endpoint(BaseModel):
+    port: int = Field(default=443)
+    fallback_port: int = Field(default=80)
+    timeout_secs: int = Field(default=30)
+    retries: int = Field(default=5)
+
+class RetryPolicy(BaseModel):
+    max_attempts: int = Field(default=3)
+    backoff_factor: float = Field(default=1.5)
+    label: str = Field(default="retry")
+
+class ServerConfig(BaseModel):
+    host: str = Field(default="localhost")
+    port: int = Field(default=8080)
+    verbose: bool = Field(default=False)
+    name: Optional[str] = Field(default=None)
+
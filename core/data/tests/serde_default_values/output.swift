@@ -0,0 +1,84 @@
+import Foundation
+
+public struct Endpoint: Codable {
+	public let port: UInt16
+	public let fallback_port: UInt16
+	public let timeout_secs: UInt32
+	public let retries: UInt32
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case port,
+			fallback_port,
+			timeout_secs,
+			retries
+	}
+
+	public init(port: UInt16 = 443, fallback_port: UInt16 = 80, timeout_secs: UInt32 = 30, retries: UInt32 = 5) {
+		self.port = port
+		self.fallback_port = fallback_port
+		self.timeout_secs = timeout_secs
+		self.retries = retries
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.port = try container.decodeIfPresent(UInt16.self, forKey: .port) ?? 443
+		self.fallback_port = try container.decodeIfPresent(UInt16.self, forKey: .fallback_port) ?? 80
+		self.timeout_secs = try container.decodeIfPresent(UInt32.self, forKey: .timeout_secs) ?? 30
+		self.retries = try container.decodeIfPresent(UInt32.self, forKey: .retries) ?? 5
+	}
+}
+
+public struct RetryPolicy: Codable {
+	public let max_attempts: UInt32
+	public let backoff_factor: Double
+	public let label: String
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case max_attempts,
+			backoff_factor,
+			label
+	}
+
+	public init(max_attempts: UInt32 = 3, backoff_factor: Double = 1.5, label: String = "retry") {
+		self.max_attempts = max_attempts
+		self.backoff_factor = backoff_factor
+		self.label = label
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.max_attempts = try container.decodeIfPresent(UInt32.self, forKey: .max_attempts) ?? 3
+		self.backoff_factor = try container.decodeIfPresent(Double.self, forKey: .backoff_factor) ?? 1.5
+		self.label = try container.decodeIfPresent(String.self, forKey: .label) ?? "retry"
+	}
+}
+
+public struct ServerConfig: Codable {
+	public let host: String
+	public let port: UInt16
+	public let verbose: Bool
+	public let name: String?
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case host,
+			port,
+			verbose,
+			name
+	}
+
+	public init(host: String = "localhost", port: UInt16 = 8080, verbose: Bool = false, name: String?) {
+		self.host = host
+		self.port = port
+		self.verbose = verbose
+		self.name = name
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.host = try container.decodeIfPresent(String.self, forKey: .host) ?? "localhost"
+		self.port = try container.decodeIfPresent(UInt16.self, forKey: .port) ?? 8080
+		self.verbose = try container.decodeIfPresent(Bool.self, forKey: .verbose) ?? false
+		self.name = try container.decodeIfPresent(String.self, forKey: .name)
+	}
+}
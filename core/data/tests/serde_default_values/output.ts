@@ -0,0 +1,20 @@
+export interface Endpoint {
+	port?: number;
+	fallback_port?: number;
+	timeout_secs?: number;
+	retries?: number;
+}
+
+export interface RetryPolicy {
+	max_attempts?: number;
+	backoff_factor?: number;
+	label?: string;
+}
+
+export interface ServerConfig {
+	host?: string;
+	port?: number;
+	verbose?: boolean;
+	name: string | null;
+}
+
@@ -0,0 +1,28 @@
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Platform {
+    #[serde(alias = "iOS")]
+    Ios,
+    Android,
+    /// A platform added after this client was built
+    #[serde(other)]
+    Unknown,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Event {
+    Opened(String),
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Device {
+    pub name: String,
+    pub platform: Platform,
+    pub previous_platform: Option<Platform>,
+}
@@ -0,0 +1,48 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.json.JsonNames
+import kotlinx.serialization.modules.SerializersModule
+
+@Serializable
+enum class Platform(val string: String) {
+	@SerialName("Ios")
+	@JsonNames("iOS")
+	Ios("Ios"),
+	@SerialName("Android")
+	Android("Android"),
+	/// A platform added after this client was built
+	@SerialName("Unknown")
+	Unknown("Unknown"),
+}
+
+@Serializable
+data class Device (
+	val name: String,
+	val platform: Platform = Platform.Unknown,
+	val previous_platform: Platform? = null
+)
+
+@Serializable
+sealed class Event {
+	@Serializable
+	@SerialName("Opened")
+	data class Opened(val content: String): Event()
+	@Serializable
+	@SerialName("Closed")
+	object Closed: Event()
+	@Serializable
+	@SerialName("Unknown")
+	object Unknown: Event()
+}
+
+/// Decodes tags added after this client was built as `Event.Unknown`. Add it to the
+/// `serializersModule` of your `Json`.
+@OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+val EventSerializersModule = SerializersModule {
+	polymorphicDefaultDeserializer(Event::class) { Event.Unknown.serializer() }
+}
+
@@ -0,0 +1,43 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel, Field
+from typing import Literal, Optional, Union
+
+
+class Platform(str, Enum):
+    IOS = "Ios"
+    ANDROID = "Android"
+    UNKNOWN = "Unknown"
+    """
+    A platform added after this client was built
+    """
+
+    @classmethod
+    def _missing_(cls, value):
+        if value == "iOS":
+            return cls.IOS
+        return cls.UNKNOWN
+
+class Device(BaseModel):
+    name: str
+    platform: Platform
+    previous_platform: Optional[Platform] = Field(default=None)
+
+class EventTypes(str, Enum):
+    OPENED = "Opened"
+    CLOSED = "Closed"
+    UNKNOWN = "Unknown"
+
+class EventOpened(BaseModel):
+    type: Literal[EventTypes.OPENED] = EventTypes.OPENED
+    content: str
+
+class EventClosed(BaseModel):
+    type: Literal[EventTypes.CLOSED] = EventTypes.CLOSED
+
+class EventUnknown(BaseModel):
+    type: Literal[EventTypes.UNKNOWN] = EventTypes.UNKNOWN
+
+Event = Union[EventOpened, EventClosed, EventUnknown]
+
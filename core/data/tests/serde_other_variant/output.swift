@@ -0,0 +1,82 @@
+import Foundation
+
+public enum Platform: String, Codable {
+	case ios = "Ios"
+	case android = "Android"
+	/// A platform added after this client was built
+	case unknown = "Unknown"
+
+	public init(from decoder: Decoder) throws {
+		let rawValue = try decoder.singleValueContainer().decode(RawValue.self)
+		switch rawValue {
+		case "iOS":
+			self = .ios
+		default:
+			self = Self(rawValue: rawValue) ?? .unknown
+		}
+	}
+}
+
+public struct Device: Codable {
+	public let name: String
+	public let platform: Platform
+	public let previous_platform: Platform?
+
+	public init(name: String, platform: Platform, previous_platform: Platform?) {
+		self.name = name
+		self.platform = platform
+		self.previous_platform = previous_platform
+	}
+}
+
+public enum Event: Codable {
+	case opened(String)
+	case closed
+	case unknown
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case opened = "Opened",
+			closed = "Closed",
+			unknown = "Unknown"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .opened:
+				if let content = try? container.decode(String.self, forKey: .content) {
+					self = .opened(content)
+					return
+				}
+			case .closed:
+				self = .closed
+				return
+			case .unknown:
+				self = .unknown
+				return
+			}
+		} else if (try? container.decode(String.self, forKey: .type)) != nil {
+			self = .unknown
+			return
+		}
+		throw DecodingError.typeMismatch(Event.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Event"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .opened(let content):
+			try container.encode(CodingKeys.opened, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .closed:
+			try container.encode(CodingKeys.closed, forKey: .type)
+		case .unknown:
+			try container.encode(CodingKeys.unknown, forKey: .type)
+		}
+	}
+}
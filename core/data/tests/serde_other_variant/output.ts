@@ -0,0 +1,20 @@
+export const Platform = {
+	Ios: "Ios",
+	Android: "Android",
+	/** A platform added after this client was built */
+	Unknown: "Unknown",
+} as const;
+
+export type Platform = (typeof Platform)[keyof typeof Platform] | (string & {});
+
+export interface Device {
+	name: string;
+	platform: Platform;
+	previous_platform: Platform | null;
+}
+
+export type Event = 
+	| { type: "Opened", content: string }
+	| { type: "Closed", content?: undefined }
+	| { type: "Unknown" | (string & {}), content?: undefined };
+
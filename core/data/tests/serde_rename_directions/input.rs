@@ -0,0 +1,19 @@
+#[typeshare]
+#[serde(rename_all(serialize = "camelCase", deserialize = "camelCase"))]
+pub struct Settings {
+    pub user_name: String,
+    #[serde(rename(serialize = "ID", deserialize = "ID"))]
+    pub identifier: String,
+    pub enabled: bool,
+}
+
+#[typeshare]
+#[serde(tag = "type", content = "content", rename_all_fields = "camelCase")]
+pub enum Event {
+    Created {
+        created_at: String,
+        created_by: String,
+    },
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    Deleted { deleted_at: String },
+}
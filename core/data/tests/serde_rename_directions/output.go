@@ -0,0 +1,86 @@
+package proto
+
+import "encoding/json"
+
+type Settings struct {
+	UserName string `json:"userName"`
+	Identifier string `json:"ID"`
+	Enabled bool `json:"enabled"`
+}
+// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+type EventCreatedInner struct {
+	CreatedAt string `json:"createdAt"`
+	CreatedBy string `json:"createdBy"`
+}
+// Generated type representing the anonymous struct variant `Deleted` of the `Event` Rust enum
+type EventDeletedInner struct {
+	DeletedAt string `json:"DELETED_AT"`
+}
+type EventTypes string
+const (
+	EventTypeVariantCreated EventTypes = "Created"
+	EventTypeVariantDeleted EventTypes = "Deleted"
+)
+type Event struct{ 
+	Type EventTypes `json:"type"`
+	content interface{}
+}
+
+func (e *Event) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	e.Type = enum.Tag
+	switch e.Type {
+	case EventTypeVariantCreated:
+		var res EventCreatedInner
+		e.content = &res
+	case EventTypeVariantDeleted:
+		var res EventDeletedInner
+		e.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &e.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e Event) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = e.Type
+    enum.Content = e.content
+    return json.Marshal(enum)
+}
+
+func (e Event) Created() *EventCreatedInner {
+	res, _ := e.content.(*EventCreatedInner)
+	return res
+}
+func (e Event) Deleted() *EventDeletedInner {
+	res, _ := e.content.(*EventDeletedInner)
+	return res
+}
+
+func NewEventTypeVariantCreated(content *EventCreatedInner) Event {
+    return Event{
+        Type: EventTypeVariantCreated,
+        content: content,
+    }
+}
+func NewEventTypeVariantDeleted(content *EventDeletedInner) Event {
+    return Event{
+        Type: EventTypeVariantDeleted,
+        content: content,
+    }
+}
+
@@ -0,0 +1,35 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Settings (
+	val userName: String,
+	val ID: String,
+	val enabled: Boolean
+)
+
+/// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+@Serializable
+data class EventCreatedInner (
+	val createdAt: String,
+	val createdBy: String
+)
+
+/// Generated type representing the anonymous struct variant `Deleted` of the `Event` Rust enum
+@Serializable
+data class EventDeletedInner (
+	val DELETED_AT: String
+)
+
+@Serializable
+sealed class Event {
+	@Serializable
+	@SerialName("Created")
+	data class Created(val content: EventCreatedInner): Event()
+	@Serializable
+	@SerialName("Deleted")
+	data class Deleted(val content: EventDeletedInner): Event()
+}
+
@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+from enum import Enum
+from pydantic import BaseModel, ConfigDict, Field
+from typing import Literal, Union
+
+
+class Settings(BaseModel):
+    model_config = ConfigDict(populate_by_name=True)
+
+    user_name: str = Field(alias="userName")
+    identifier: str = Field(alias="ID")
+    enabled: bool
+
+class EventCreatedInner(BaseModel):
+    """
+    Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+    """
+    model_config = ConfigDict(populate_by_name=True)
+
+    created_at: str = Field(alias="createdAt")
+    created_by: str = Field(alias="createdBy")
+
+class EventDeletedInner(BaseModel):
+    """
+    Generated type representing the anonymous struct variant `Deleted` of the `Event` Rust enum
+    """
+    model_config = ConfigDict(populate_by_name=True)
+
+    deleted_at: str = Field(alias="DELETED_AT")
+
+class EventTypes(str, Enum):
+    CREATED = "Created"
+    DELETED = "Deleted"
+
+class EventCreated(BaseModel):
+    type: Literal[EventTypes.CREATED] = EventTypes.CREATED
+    content: EventCreatedInner
+
+class EventDeleted(BaseModel):
+    type: Literal[EventTypes.DELETED] = EventTypes.DELETED
+    content: EventDeletedInner
+
+Event = Union[EventCreated, EventDeleted]
+
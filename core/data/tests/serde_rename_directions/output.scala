@@ -0,0 +1,34 @@
+package com.agilebits
+
+package onepassword {
+
+case class Settings (
+	userName: String,
+	ID: String,
+	enabled: Boolean
+)
+
+// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+case class EventCreatedInner (
+	createdAt: String,
+	createdBy: String
+)
+
+// Generated type representing the anonymous struct variant `Deleted` of the `Event` Rust enum
+case class EventDeletedInner (
+	DELETED_AT: String
+)
+
+sealed trait Event {
+	def serialName: String
+}
+object Event {
+	case class Created(content: EventCreatedInner) extends Event {
+		val serialName: String = "Created"
+	}
+	case class Deleted(content: EventDeletedInner) extends Event {
+		val serialName: String = "Deleted"
+	}
+}
+
+}
@@ -0,0 +1,78 @@
+import Foundation
+
+public struct Settings: Codable {
+	public let userName: String
+	public let ID: String
+	public let enabled: Bool
+
+	public init(userName: String, ID: String, enabled: Bool) {
+		self.userName = userName
+		self.ID = ID
+		self.enabled = enabled
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+public struct EventCreatedInner: Codable {
+	public let createdAt: String
+	public let createdBy: String
+
+	public init(createdAt: String, createdBy: String) {
+		self.createdAt = createdAt
+		self.createdBy = createdBy
+	}
+}
+
+/// Generated type representing the anonymous struct variant `Deleted` of the `Event` Rust enum
+public struct EventDeletedInner: Codable {
+	public let DELETED_AT: String
+
+	public init(DELETED_AT: String) {
+		self.DELETED_AT = DELETED_AT
+	}
+}
+public enum Event: Codable {
+	case created(EventCreatedInner)
+	case deleted(EventDeletedInner)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case created = "Created",
+			deleted = "Deleted"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .created:
+				if let content = try? container.decode(EventCreatedInner.self, forKey: .content) {
+					self = .created(content)
+					return
+				}
+			case .deleted:
+				if let content = try? container.decode(EventDeletedInner.self, forKey: .content) {
+					self = .deleted(content)
+					return
+				}
+			}
+		}
+		throw DecodingError.typeMismatch(Event.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Event"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .created(let content):
+			try container.encode(CodingKeys.created, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .deleted(let content):
+			try container.encode(CodingKeys.deleted, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
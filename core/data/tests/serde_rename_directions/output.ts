@@ -0,0 +1,15 @@
+export interface Settings {
+	userName: string;
+	ID: string;
+	enabled: boolean;
+}
+
+export type Event = 
+	| { type: "Created", content: {
+	createdAt: string;
+	createdBy: string;
+}}
+	| { type: "Deleted", content: {
+	DELETED_AT: string;
+}};
+
@@ -6,7 +6,7 @@ type AliasTest []SomethingFoo
 
 type Test struct {
 	Field1 SomethingFoo `json:"field1"`
-	Field2 *SomethingFoo `json:"field2,omitempty"`
+	Field2 *SomethingFoo `json:"field2"`
 }
 type Foo string
 const (
@@ -2,7 +2,7 @@ export type AliasTest = SomethingFoo[];
 
 export interface Test {
 	field1: SomethingFoo;
-	field2?: SomethingFoo;
+	field2: SomethingFoo | null;
 }
 
 export enum SomethingFoo {
@@ -0,0 +1,20 @@
+/// The priority of a task, serialized as its number
+#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 1,
+    Medium,
+    /// Urgent tasks are handled first
+    High = 10,
+    Critical,
+}
+
+#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum Offset {
+    Behind = -1,
+    Level,
+    Ahead,
+}
@@ -0,0 +1,19 @@
+package proto
+
+import "encoding/json"
+
+type Offset int
+const (
+	OffsetBehind Offset = -1
+	OffsetLevel Offset = 0
+	OffsetAhead Offset = 1
+)
+// The priority of a task, serialized as its number
+type Priority int
+const (
+	PriorityLow Priority = 1
+	PriorityMedium Priority = 2
+	// Urgent tasks are handled first
+	PriorityHigh Priority = 10
+	PriorityCritical Priority = 11
+)
@@ -0,0 +1,56 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.descriptors.PrimitiveKind
+import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+
+@Serializable(with = OffsetSerializer::class)
+enum class Offset(val value: Int) {
+	Behind(-1),
+	Level(0),
+	Ahead(1),
+}
+
+object OffsetSerializer : KSerializer<Offset> {
+	override val descriptor = PrimitiveSerialDescriptor("Offset", PrimitiveKind.INT)
+
+	override fun serialize(encoder: Encoder, value: Offset) {
+		encoder.encodeInt(value.value.toInt())
+	}
+
+	override fun deserialize(decoder: Decoder): Offset {
+		val value = decoder.decodeInt()
+		return Offset.entries.firstOrNull { it.value.toInt() == value }
+			?: throw SerializationException("Unknown Offset: $value")
+	}
+}
+
+/// The priority of a task, serialized as its number
+@Serializable(with = PrioritySerializer::class)
+enum class Priority(val value: UByte) {
+	Low(1u),
+	Medium(2u),
+	/// Urgent tasks are handled first
+	High(10u),
+	Critical(11u),
+}
+
+object PrioritySerializer : KSerializer<Priority> {
+	override val descriptor = PrimitiveSerialDescriptor("Priority", PrimitiveKind.INT)
+
+	override fun serialize(encoder: Encoder, value: Priority) {
+		encoder.encodeInt(value.value.toInt())
+	}
+
+	override fun deserialize(decoder: Decoder): Priority {
+		val value = decoder.decodeInt()
+		return Priority.entries.firstOrNull { it.value.toInt() == value }
+			?: throw SerializationException("Unknown Priority: $value")
+	}
+}
+
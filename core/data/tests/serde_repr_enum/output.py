@@ -0,0 +1,22 @@
+from __future__ import annotations
+
+from enum import Enum
+
+
+class Offset(int, Enum):
+    BEHIND = -1
+    LEVEL = 0
+    AHEAD = 1
+
+class Priority(int, Enum):
+    """
+    The priority of a task, serialized as its number
+    """
+    LOW = 1
+    MEDIUM = 2
+    HIGH = 10
+    """
+    Urgent tasks are handled first
+    """
+    CRITICAL = 11
+
@@ -0,0 +1,16 @@
+import Foundation
+
+public enum Offset: Int32, Codable {
+	case behind = -1
+	case level = 0
+	case ahead = 1
+}
+
+/// The priority of a task, serialized as its number
+public enum Priority: UInt8, Codable {
+	case low = 1
+	case medium = 2
+	/// Urgent tasks are handled first
+	case high = 10
+	case critical = 11
+}
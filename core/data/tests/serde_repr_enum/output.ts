@@ -0,0 +1,15 @@
+export enum Offset {
+	Behind = -1,
+	Level = 0,
+	Ahead = 1,
+}
+
+/** The priority of a task, serialized as its number */
+export enum Priority {
+	Low = 1,
+	Medium = 2,
+	/** Urgent tasks are handled first */
+	High = 10,
+	Critical = 11,
+}
+
@@ -0,0 +1,38 @@
+/// An identifier that is written as its inner string.
+#[typeshare]
+#[serde(transparent)]
+pub struct UserId {
+    pub value: String,
+}
+
+#[typeshare]
+#[serde(transparent)]
+pub struct Tags {
+    pub inner: Vec<String>,
+    #[serde(skip)]
+    pub cache: Option<String>,
+    pub marker: PhantomData<u8>,
+}
+
+/// A color that is written as a hex string.
+#[typeshare]
+#[serde(into = "String", try_from = "String")]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+#[typeshare]
+#[serde(into = "u32", from = "u32")]
+pub enum Priority {
+    Low,
+    High,
+}
+
+#[typeshare]
+pub struct Settings {
+    pub owner: UserId,
+    pub accent: Color,
+    pub priority: Priority,
+}
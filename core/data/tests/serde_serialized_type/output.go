@@ -0,0 +1,19 @@
+package proto
+
+import "encoding/json"
+
+// A color that is written as a hex string.
+type Color string
+
+type Priority uint32
+
+type Tags []string
+
+// An identifier that is written as its inner string.
+type UserId string
+
+type Settings struct {
+	Owner UserId `json:"owner"`
+	Accent Color `json:"accent"`
+	Priority Priority `json:"priority"`
+}
@@ -0,0 +1,22 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+/// A color that is written as a hex string.
+typealias Color = String
+
+typealias Priority = UInt
+
+typealias Tags = List<String>
+
+/// An identifier that is written as its inner string.
+typealias UserId = String
+
+@Serializable
+data class Settings (
+	val owner: UserId,
+	val accent: Color,
+	val priority: Priority
+)
+
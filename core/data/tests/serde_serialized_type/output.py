@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+from pydantic import BaseModel
+from typing import List
+
+
+Color = str
+
+"""
+A color that is written as a hex string.
+"""
+Priority = int
+
+Tags = List[str]
+
+UserId = str
+
+"""
+An identifier that is written as its inner string.
+"""
+class Settings(BaseModel):
+    owner: UserId
+    accent: Color
+    priority: Priority
+
@@ -0,0 +1,29 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Int
+
+// A color that is written as a hex string.
+type Color = String
+
+type Priority = UInt
+
+type Tags = Vector[String]
+
+// An identifier that is written as its inner string.
+type UserId = String
+
+}
+package onepassword {
+
+case class Settings (
+	owner: UserId,
+	accent: Color,
+	priority: Priority
+)
+
+}
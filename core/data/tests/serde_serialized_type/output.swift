@@ -0,0 +1,23 @@
+import Foundation
+
+/// A color that is written as a hex string.
+public typealias Color = String
+
+public typealias Priority = UInt32
+
+public typealias Tags = [String]
+
+/// An identifier that is written as its inner string.
+public typealias UserId = String
+
+public struct Settings: Codable {
+	public let owner: UserId
+	public let accent: Color
+	public let priority: Priority
+
+	public init(owner: UserId, accent: Color, priority: Priority) {
+		self.owner = owner
+		self.accent = accent
+		self.priority = priority
+	}
+}
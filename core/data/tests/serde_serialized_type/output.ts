@@ -0,0 +1,16 @@
+/** A color that is written as a hex string. */
+export type Color = string;
+
+export type Priority = number;
+
+export type Tags = string[];
+
+/** An identifier that is written as its inner string. */
+export type UserId = string;
+
+export interface Settings {
+	owner: UserId;
+	accent: Color;
+	priority: Priority;
+}
+
@@ -21,3 +21,4 @@ class SomeEnumOther(BaseModel):
     content: int
 
 SomeEnum = Union[SomeEnumContext, SomeEnumOther]
+
@@ -1,6 +1,6 @@
 export interface EditItemViewModelSaveRequest {
 	context: string;
 	values: EditItemSaveValue[];
-	fill_action?: AutoFillItemActionRequest;
+	fill_action: AutoFillItemActionRequest | null;
 }
 
@@ -0,0 +1,10 @@
+#[typeshare]
+pub struct Update {
+    /// Written as `null` when missing
+    pub title: Option<String>,
+    /// Left out entirely when missing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,11 @@
+package proto
+
+import "encoding/json"
+
+type Update struct {
+	// Written as `null` when missing
+	Title *string `json:"title"`
+	// Left out entirely when missing
+	Description *string `json:"description,omitempty"`
+	Tags *[]string `json:"tags,omitempty"`
+}
@@ -0,0 +1,14 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Update (
+	/// Written as `null` when missing
+	val title: String? = null,
+	/// Left out entirely when missing
+	val description: String? = null,
+	val tags: List<String>? = null
+)
+
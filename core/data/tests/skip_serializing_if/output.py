@@ -0,0 +1,17 @@
+from __future__ import annotations
+
+from pydantic import BaseModel, Field
+from typing import List, Optional
+
+
+class Update(BaseModel):
+    title: Optional[str] = Field(default=None)
+    """
+    Written as `null` when missing
+    """
+    description: Optional[str] = Field(default=None)
+    """
+    Left out entirely when missing
+    """
+    tags: Optional[List[str]] = Field(default=None)
+
@@ -0,0 +1,15 @@
+import Foundation
+
+public struct Update: Codable {
+	/// Written as `null` when missing
+	public let title: String?
+	/// Left out entirely when missing
+	public let description: String?
+	public let tags: [String]?
+
+	public init(title: String?, description: String?, tags: [String]?) {
+		self.title = title
+		self.description = description
+		self.tags = tags
+	}
+}
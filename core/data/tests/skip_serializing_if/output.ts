@@ -0,0 +1,8 @@
+export interface Update {
+	/** Written as `null` when missing */
+	title: string | null;
+	/** Left out entirely when missing */
+	description?: string;
+	tags?: string[];
+}
+
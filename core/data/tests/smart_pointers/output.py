@@ -64,3 +64,4 @@ class BoxyColorsGreen(BaseModel):
 
 # This is a comment.
 BoxyColors = Union[BoxyColorsRed, BoxyColorsBlue, BoxyColorsGreen]
+
@@ -7,6 +7,7 @@ from typing import List, Literal, Union
 
 class ItemDetailsFieldValue(BaseModel):
     pass
+
 class AdvancedColorsTypes(str, Enum):
     STRING = "string"
     NUMBER = "number"
@@ -30,3 +31,4 @@ class AdvancedColorsReallyCoolType(BaseModel):
     content: ItemDetailsFieldValue
 
 AdvancedColors = Union[AdvancedColorsString, AdvancedColorsNumber, AdvancedColorsNumberArray, AdvancedColorsReallyCoolType]
+
@@ -5,6 +5,6 @@ import kotlinx.serialization.SerialName
 
 @Serializable
 data class Foo (
-	val bar: Boolean? = null
+	val bar: Boolean = false
 )
 
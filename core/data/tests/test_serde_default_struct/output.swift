@@ -1,9 +1,18 @@
 import Foundation
 
 public struct Foo: Codable {
-	public let bar: Bool?
+	public let bar: Bool
 
-	public init(bar: Bool?) {
+	enum CodingKeys: String, CodingKey, Codable {
+		case bar
+	}
+
+	public init(bar: Bool = false) {
 		self.bar = bar
 	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.bar = try container.decodeIfPresent(Bool.self, forKey: .bar) ?? false
+	}
 }
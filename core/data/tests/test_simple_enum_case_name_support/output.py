@@ -10,3 +10,4 @@ class Colors(str, Enum):
     RED = "red"
     BLUE = "blue-ish"
     GREEN = "Green"
+
@@ -0,0 +1,8 @@
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub timeout: std::time::Duration,
+    /// Not `std::time::Duration`, so this needs a type mapping
+    pub grace_period: time::Duration,
+}
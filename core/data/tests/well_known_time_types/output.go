@@ -0,0 +1,13 @@
+package proto
+
+import (
+	"encoding/json"
+	"time"
+)
+
+type Session struct {
+	StartedAt time.Time `json:"started_at"`
+	Timeout map[string]uint64 `json:"timeout"`
+	// Not `std::time::Duration`, so this needs a type mapping
+	GracePeriod Duration `json:"grace_period"`
+}
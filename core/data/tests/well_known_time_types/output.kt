@@ -0,0 +1,13 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Session (
+	val started_at: String,
+	val timeout: HashMap<String, ULong>,
+	/// Not `std::time::Duration`, so this needs a type mapping
+	val grace_period: Duration
+)
+
@@ -0,0 +1,12 @@
+package com.agilebits
+
+package onepassword {
+
+case class Session (
+	started_at: String,
+	timeout: Map[String, ULong],
+	// Not `std::time::Duration`, so this needs a type mapping
+	grace_period: Duration
+)
+
+}
@@ -0,0 +1,25 @@
+import Foundation
+
+public struct Session: Codable {
+	public let started_at: String
+	public let timeout: RustDuration
+	/// Not `std::time::Duration`, so this needs a type mapping
+	public let grace_period: Duration
+
+	public init(started_at: String, timeout: RustDuration, grace_period: Duration) {
+		self.started_at = started_at
+		self.timeout = timeout
+		self.grace_period = grace_period
+	}
+}
+
+/// Rust's `Duration`, which serde writes as whole seconds and the nanoseconds past them
+public struct RustDuration: Codable {
+	public let secs: UInt64
+	public let nanos: UInt32
+
+	public init(secs: UInt64, nanos: UInt32) {
+		self.secs = secs
+		self.nanos = nanos
+	}
+}
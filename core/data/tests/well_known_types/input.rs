@@ -0,0 +1,18 @@
+use billing::Decimal;
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: uuid::Uuid,
+    pub related: Vec<Uuid>,
+    pub link: Option<url::Url>,
+    pub total: rust_decimal::Decimal,
+    /// Imported from another crate, so this is not `rust_decimal::Decimal`
+    pub discount: Decimal,
+    pub due: chrono::NaiveDate,
+    pub attachment: serde_bytes::ByteBuf,
+    #[serde(with = "serde_bytes")]
+    pub signature: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub thumbnail: Option<Vec<u8>>,
+}
@@ -0,0 +1,16 @@
+package proto
+
+import "encoding/json"
+
+type Invoice struct {
+	Id string `json:"id"`
+	Related []string `json:"related"`
+	Link *string `json:"link"`
+	Total string `json:"total"`
+	// Imported from another crate, so this is not `rust_decimal::Decimal`
+	Discount Decimal `json:"discount"`
+	Due string `json:"due"`
+	Attachment []int `json:"attachment"`
+	Signature []int `json:"signature"`
+	Thumbnail *[]int `json:"thumbnail"`
+}
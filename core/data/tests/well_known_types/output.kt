@@ -0,0 +1,19 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Invoice (
+	val id: String,
+	val related: List<String>,
+	val link: String? = null,
+	val total: String,
+	/// Imported from another crate, so this is not `rust_decimal::Decimal`
+	val discount: Decimal,
+	val due: String,
+	val attachment: List<UByte>,
+	val signature: List<UByte>,
+	val thumbnail: List<UByte>? = null
+)
+
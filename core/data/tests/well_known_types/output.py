@@ -0,0 +1,24 @@
+from __future__ import annotations
+
+from datetime import date
+from decimal import Decimal
+from pydantic import BaseModel, Field
+from pydantic.networks import AnyUrl
+from typing import List, Optional
+from uuid import UUID
+
+
+class Invoice(BaseModel):
+    id: UUID
+    related: List[UUID]
+    link: Optional[AnyUrl] = Field(default=None)
+    total: Decimal
+    discount: Decimal
+    """
+    Imported from another crate, so this is not `rust_decimal::Decimal`
+    """
+    due: date
+    attachment: List[int]
+    signature: List[int]
+    thumbnail: Optional[List[int]] = Field(default=None)
+
@@ -0,0 +1,18 @@
+package com.agilebits
+
+package onepassword {
+
+case class Invoice (
+	id: String,
+	related: Vector[String],
+	link: Option[String] = None,
+	total: String,
+	// Imported from another crate, so this is not `rust_decimal::Decimal`
+	discount: Decimal,
+	due: String,
+	attachment: Vector[UByte],
+	signature: Vector[UByte],
+	thumbnail: Option[Vector[UByte]] = None
+)
+
+}
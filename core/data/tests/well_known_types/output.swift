@@ -0,0 +1,26 @@
+import Foundation
+
+public struct Invoice: Codable {
+	public let id: UUID
+	public let related: [UUID]
+	public let link: URL?
+	public let total: String
+	/// Imported from another crate, so this is not `rust_decimal::Decimal`
+	public let discount: Decimal
+	public let due: String
+	public let attachment: [UInt8]
+	public let signature: [UInt8]
+	public let thumbnail: [UInt8]?
+
+	public init(id: UUID, related: [UUID], link: URL?, total: String, discount: Decimal, due: String, attachment: [UInt8], signature: [UInt8], thumbnail: [UInt8]?) {
+		self.id = id
+		self.related = related
+		self.link = link
+		self.total = total
+		self.discount = discount
+		self.due = due
+		self.attachment = attachment
+		self.signature = signature
+		self.thumbnail = thumbnail
+	}
+}
@@ -0,0 +1,13 @@
+export interface Invoice {
+	id: string;
+	related: string[];
+	link: string | null;
+	total: string;
+	/** Imported from another crate, so this is not `rust_decimal::Decimal` */
+	discount: Decimal;
+	due: string;
+	attachment: number[];
+	signature: number[];
+	thumbnail: number[] | null;
+}
+
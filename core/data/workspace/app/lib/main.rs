@@ -0,0 +1,9 @@
+use common::Account;
+use models::User;
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct Session {
+    pub user: User,
+    pub account: Account,
+}
@@ -0,0 +1,7 @@
+//! Left out of the sources by the `.ignore` file next to the workspace manifest.
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct Generated {
+    pub id: String,
+}
@@ -0,0 +1,8 @@
+use typeshare::typeshare;
+
+mod user;
+
+#[typeshare]
+pub struct AccountId {
+    pub id: String,
+}
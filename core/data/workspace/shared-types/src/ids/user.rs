@@ -0,0 +1,8 @@
+use super::AccountId;
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct UserId {
+    pub id: String,
+    pub account: AccountId,
+}
@@ -0,0 +1,10 @@
+use typeshare::typeshare;
+
+#[path = "../types/extra.rs"]
+mod extra;
+mod ids;
+
+#[typeshare]
+pub struct User {
+    pub name: String,
+}
@@ -0,0 +1,6 @@
+use typeshare::typeshare;
+
+#[typeshare]
+pub struct Account {
+    pub id: String,
+}
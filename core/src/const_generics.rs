@@ -0,0 +1,288 @@
+//! Resolve array lengths and const generic arguments, first in the file of each
+//! typeshared item and then across every parsed crate.
+use crate::{
+    language::CrateName,
+    parser::{has_typeshare_annotation, ParsedData},
+    rust_types::{RustConstExpr, RustType, SpecialRustType},
+};
+use log::warn;
+use quote::ToTokens;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use syn::{
+    parse_quote,
+    spanned::Spanned as _,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    AngleBracketedGenericArguments, Expr, ExprLit, ExprPath, File, GenericArgument, GenericParam,
+    Generics, Item, ItemConst, Lit, LitInt, Type,
+};
+
+/// Rewrite the typeshared items in `file` so that every array has a literal length where
+/// the file alone is enough to tell.
+///
+/// Lengths that name an integer constant defined in the file are replaced by its value.
+/// Lengths that name a constant from elsewhere are left for [`resolve_const_arguments`].
+/// Any other length, such as a const generic parameter, can't be known ahead of time, so
+/// the array degrades to a `Vec`. Const generic arguments are dropped from type paths
+/// since no target language can express them.
+pub(crate) fn resolve_const_generics(file: &mut File) {
+    let mut consts = ConstCollector::default();
+    consts.visit_file(file);
+    ArrayResolver {
+        consts: consts.values,
+        const_params: HashSet::new(),
+    }
+    .visit_file_mut(file);
+}
+
+/// Collects every `const` in a file whose value is an integer literal.
+#[derive(Default)]
+struct ConstCollector {
+    values: HashMap<String, usize>,
+}
+
+impl Visit<'_> for ConstCollector {
+    fn visit_item_const(&mut self, c: &ItemConst) {
+        if let Some(value) = int_literal(&c.expr) {
+            self.values.insert(c.ident.to_string(), value);
+        }
+        visit::visit_item_const(self, c);
+    }
+}
+
+struct ArrayResolver {
+    consts: HashMap<String, usize>,
+    /// The const generic parameters of the item being rewritten.
+    const_params: HashSet<String>,
+}
+
+impl ArrayResolver {
+    fn array_len(&self, len: &Expr) -> Option<usize> {
+        match len {
+            Expr::Lit(_) => int_literal(len),
+            Expr::Path(path) if path.qself.is_none() => {
+                let ident = path.path.segments.last()?.ident.to_string();
+                if self.const_params.contains(&ident) {
+                    return None;
+                }
+                self.consts.get(&ident).copied()
+            }
+            Expr::Block(block) => match block.block.stmts.as_slice() {
+                [syn::Stmt::Expr(expr, None)] => self.array_len(expr),
+                _ => None,
+            },
+            Expr::Group(group) => self.array_len(&group.expr),
+            Expr::Paren(paren) => self.array_len(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// The constant from another file that a length names, if it does.
+    fn foreign_const<'e>(&self, len: &'e Expr) -> Option<&'e ExprPath> {
+        match len {
+            Expr::Path(path) if path.qself.is_none() => {
+                let ident = path.path.segments.last()?.ident.to_string();
+                (!self.const_params.contains(&ident) && !self.consts.contains_key(&ident))
+                    .then_some(path)
+            }
+            Expr::Block(block) => match block.block.stmts.as_slice() {
+                [syn::Stmt::Expr(expr, None)] => self.foreign_const(expr),
+                _ => None,
+            },
+            Expr::Group(group) => self.foreign_const(&group.expr),
+            Expr::Paren(paren) => self.foreign_const(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Whether a generic argument names a const parameter or a constant of the file. The
+    /// parser tells those apart from types by where they are defined, since `Buffer<LEN>`
+    /// reads the same either way.
+    fn is_const_arg(&self, arg: &GenericArgument) -> bool {
+        match arg {
+            GenericArgument::Type(Type::Path(path)) if path.qself.is_none() => {
+                path.path.get_ident().is_some_and(|ident| {
+                    let ident = ident.to_string();
+                    self.const_params.contains(&ident) || self.consts.contains_key(&ident)
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl VisitMut for ArrayResolver {
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        let generics = match item {
+            Item::Struct(s) if has_typeshare_annotation(&s.attrs) => &s.generics,
+            Item::Enum(e) if has_typeshare_annotation(&e.attrs) => &e.generics,
+            Item::Type(t) if has_typeshare_annotation(&t.attrs) => &t.generics,
+            Item::Mod(_) => return visit_mut::visit_item_mut(self, item),
+            _ => return,
+        };
+        self.const_params = const_params(generics);
+        visit_mut::visit_item_mut(self, item);
+        self.const_params.clear();
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        visit_mut::visit_type_mut(self, ty);
+
+        let Type::Array(array) = ty else {
+            return;
+        };
+        if let Some(path) = self.foreign_const(&array.len) {
+            array.len = Expr::Path(path.clone());
+            return;
+        }
+        match self.array_len(&array.len) {
+            Some(len) => {
+                array.len = Expr::Lit(ExprLit {
+                    attrs: Vec::new(),
+                    lit: Lit::Int(LitInt::new(&len.to_string(), array.len.span())),
+                });
+            }
+            None => {
+                warn!(
+                    "The length of `{}` is not a constant typeshare can resolve, so it will be generated as a variable-length array",
+                    array.to_token_stream()
+                );
+                let elem = &array.elem;
+                *ty = parse_quote!(Vec<#elem>);
+            }
+        }
+    }
+
+    fn visit_angle_bracketed_generic_arguments_mut(
+        &mut self,
+        arguments: &mut AngleBracketedGenericArguments,
+    ) {
+        arguments.args = std::mem::take(&mut arguments.args)
+            .into_iter()
+            .filter(|arg| !self.is_const_arg(arg))
+            .collect();
+        visit_mut::visit_angle_bracketed_generic_arguments_mut(self, arguments);
+    }
+}
+
+/// For generic types with const parameters, which of their type and const parameters, in
+/// order, are const.
+pub(crate) fn const_generic_kinds(generics: &Generics) -> Option<Vec<bool>> {
+    let kinds = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(_) => Some(false),
+            GenericParam::Const(_) => Some(true),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    kinds.contains(&true).then_some(kinds)
+}
+
+/// Resolve what the files could not resolve on their own, once every crate is parsed.
+///
+/// Array lengths naming a typeshared integer constant of any crate get its value, and the
+/// rest degrade to a `Vec`. Arguments of typeshared generic types are dropped where their
+/// definition has a const parameter, as are arguments naming typeshared constants.
+pub(crate) fn resolve_const_arguments(crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>) {
+    let consts = crate_parsed_data
+        .values()
+        .flat_map(|parsed_data| &parsed_data.consts)
+        .filter_map(|c| match c.expr {
+            RustConstExpr::Int(value) => {
+                Some((c.id.original.clone(), usize::try_from(value).ok()?))
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    let const_generics = crate_parsed_data
+        .values()
+        .flat_map(|parsed_data| &parsed_data.const_generics)
+        .map(|(name, kinds)| (name.clone(), kinds.clone()))
+        .collect::<HashMap<_, _>>();
+
+    for parsed_data in crate_parsed_data.values_mut() {
+        for ty in parsed_data.types_mut() {
+            resolve_type(ty, &consts, &const_generics);
+        }
+        // Constants only used as lengths or arguments are no longer referenced
+        parsed_data
+            .import_types
+            .retain(|imported| !consts.contains_key(&imported.type_name));
+    }
+}
+
+fn resolve_type(
+    ty: &mut RustType,
+    consts: &HashMap<String, usize>,
+    const_generics: &HashMap<String, Vec<bool>>,
+) {
+    match ty {
+        RustType::Simple { .. } => (),
+        RustType::Generic { id, parameters } => {
+            let is_const = |param: &RustType| matches!(param, RustType::Simple { id } if consts.contains_key(id));
+            match const_generics.get(id.as_str()) {
+                // Nothing was dropped yet, so the arguments line up with the parameters
+                Some(kinds) if kinds.len() == parameters.len() => {
+                    let mut kinds = kinds.iter();
+                    parameters.retain(|_| !kinds.next().unwrap());
+                }
+                Some(kinds) if !kinds.contains(&false) => parameters.clear(),
+                _ => parameters.retain(|param| !is_const(param)),
+            }
+            for param in parameters.iter_mut() {
+                resolve_type(param, consts, const_generics);
+            }
+            if parameters.is_empty() {
+                *ty = RustType::Simple {
+                    id: std::mem::take(id),
+                };
+            }
+        }
+        RustType::Special(SpecialRustType::ConstLenArray(elem, len)) => {
+            resolve_type(elem, consts, const_generics);
+            let elem = std::mem::replace(elem, Box::new(RustType::Special(SpecialRustType::Unit)));
+            *ty = match consts.get(len.as_str()) {
+                Some(len) => RustType::Special(SpecialRustType::Array(elem, *len)),
+                None => {
+                    warn!(
+                        "The length `{len}` is not a typeshared constant, so `[{elem}; {len}]` will be generated as a variable-length array"
+                    );
+                    RustType::Special(SpecialRustType::Vec(elem))
+                }
+            };
+        }
+        RustType::Special(
+            SpecialRustType::Vec(elem)
+            | SpecialRustType::Array(elem, _)
+            | SpecialRustType::Slice(elem)
+            | SpecialRustType::Option(elem)
+            | SpecialRustType::Pointer { inner: elem, .. },
+        ) => resolve_type(elem, consts, const_generics),
+        RustType::Special(SpecialRustType::HashMap(key, value)) => {
+            resolve_type(key, consts, const_generics);
+            resolve_type(value, consts, const_generics);
+        }
+        RustType::Special(_) => (),
+    }
+}
+
+fn const_params(generics: &Generics) -> HashSet<String> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Const(param) => Some(param.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn int_literal(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
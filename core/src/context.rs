@@ -10,10 +10,40 @@ pub struct ParseContext<'a> {
     pub ignored_types: Vec<&'a str>,
     /// Multi file output enabled.
     pub multi_file: bool,
+    /// Write a file per module instead of per crate. Crate names are then the
+    /// names of modules, like `my_crate::a::b`.
+    pub per_module: bool,
     /// `target_os` filtering.
     pub target_os: Vec<String>,
 }
 
+/// How the types are split into files. The sources are parsed differently for
+/// each kind of output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+    /// All the types in a single file.
+    SingleFile,
+    /// A file per crate.
+    MultiFile,
+    /// A file per module.
+    PerModule,
+}
+
+impl OutputKind {
+    /// Every kind of output.
+    pub const ALL: [Self; 3] = [Self::SingleFile, Self::MultiFile, Self::PerModule];
+
+    /// Whether the types are written to more than one file.
+    pub fn multi_file(self) -> bool {
+        self != Self::SingleFile
+    }
+
+    /// Whether the types are written to a file per module.
+    pub fn per_module(self) -> bool {
+        self == Self::PerModule
+    }
+}
+
 /// Parsing context for a single rust source file.
 pub struct ParseFileContext {
     /// Source code content
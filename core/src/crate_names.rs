@@ -0,0 +1,456 @@
+//! Naming crates after their `Cargo.toml` manifests.
+use crate::{error::GeneratorError, language::CrateName, parser::ParsedData};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// The file name of package and workspace manifests.
+pub const MANIFEST_FILE_NAME: &str = "Cargo.toml";
+
+/// The parts of a `Cargo.toml` manifest needed to name crates.
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+struct Manifest {
+    package: Option<Package>,
+    lib: Option<Lib>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    dev_dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    build_dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    target: BTreeMap<String, Platform>,
+    workspace: Option<Workspace>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Lib {
+    name: Option<String>,
+    path: Option<PathBuf>,
+}
+
+/// Dependencies only used on some platforms.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Platform {
+    #[serde(default)]
+    dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    dev_dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    build_dependencies: BTreeMap<String, Dependency>,
+}
+
+#[derive(Deserialize)]
+struct Workspace {
+    #[serde(default)]
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Dependency {
+    Version(#[allow(dead_code)] String),
+    Detailed(DetailedDependency),
+}
+
+#[derive(Deserialize)]
+struct DetailedDependency {
+    /// The package, when the dependency is renamed.
+    package: Option<String>,
+    path: Option<PathBuf>,
+    #[serde(default)]
+    workspace: bool,
+}
+
+impl Manifest {
+    fn read(path: &Path) -> Result<Self, GeneratorError> {
+        let contents = fs::read_to_string(path).map_err(|source| GeneratorError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        toml::from_str(&contents).map_err(|source| GeneratorError::Manifest {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })
+    }
+
+    /// The name the package's library is used under in Rust code.
+    fn crate_name(&self) -> Option<CrateName> {
+        let lib_name = self.lib.as_ref().and_then(|lib| lib.name.clone());
+        let package_name = self.package.as_ref().map(|package| package.name.clone());
+        lib_name
+            .or(package_name)
+            .map(|name| CrateName::from(name.replace('-', "_")))
+    }
+
+    fn all_dependencies(&self) -> impl Iterator<Item = (&String, &Dependency)> {
+        [
+            &self.dependencies,
+            &self.dev_dependencies,
+            &self.build_dependencies,
+        ]
+        .into_iter()
+        .chain(self.target.values().flat_map(|platform| {
+            [
+                &platform.dependencies,
+                &platform.dev_dependencies,
+                &platform.build_dependencies,
+            ]
+        }))
+        .flatten()
+    }
+}
+
+/// A crate the source files belong to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CrateInfo {
+    pub name: CrateName,
+    /// The source file of the crate's root module, whose directory the module
+    /// tree mirrors.
+    root_file: PathBuf,
+    /// The directory of the package.
+    dir: PathBuf,
+    /// The crates of renamed dependencies, by the names they're used under.
+    renames: HashMap<CrateName, CrateName>,
+}
+
+impl CrateInfo {
+    /// Read the crate of a package manifest, or `None` for a virtual manifest.
+    fn read(manifest_path: &Path) -> Result<Option<Self>, GeneratorError> {
+        let manifest = Manifest::read(manifest_path)?;
+        let Some(name) = manifest.crate_name() else {
+            return Ok(None);
+        };
+        let package_dir = manifest_path.parent().unwrap_or(Path::new(""));
+
+        let mut workspace = None;
+        let mut renames = HashMap::new();
+        for (key, dependency) in manifest.all_dependencies() {
+            let Dependency::Detailed(dependency) = dependency else {
+                continue;
+            };
+            let (dependency, dependency_dir) = if dependency.workspace {
+                if workspace.is_none() {
+                    workspace = Some(find_workspace(package_dir)?);
+                }
+                let Some((workspace_dir, workspace)) = workspace.as_ref().and_then(Option::as_ref)
+                else {
+                    continue;
+                };
+                let Some(Dependency::Detailed(dependency)) = workspace
+                    .workspace
+                    .as_ref()
+                    .and_then(|workspace| workspace.dependencies.get(key))
+                else {
+                    continue;
+                };
+                (dependency, workspace_dir.as_path())
+            } else {
+                (dependency, package_dir)
+            };
+
+            // Without a rename, the dependency is used under its own name.
+            let Some(package) = &dependency.package else {
+                continue;
+            };
+            let dependency_name = match &dependency.path {
+                Some(path) => Manifest::read(&dependency_dir.join(path).join(MANIFEST_FILE_NAME))?
+                    .crate_name(),
+                None => None,
+            }
+            .unwrap_or_else(|| CrateName::from(package.replace('-', "_")));
+            renames.insert(CrateName::from(key.replace('-', "_")), dependency_name);
+        }
+
+        let root_file = match manifest.lib.as_ref().and_then(|lib| lib.path.as_ref()) {
+            Some(path) => package_dir.join(path),
+            None => package_dir.join("src").join("lib.rs"),
+        };
+
+        Ok(Some(Self {
+            name,
+            root_file,
+            dir: package_dir.to_path_buf(),
+            renames,
+        }))
+    }
+
+    /// The crate of a file outside of any package, named after the directory
+    /// containing its `src` directory.
+    fn guess(path: &Path) -> Option<Self> {
+        let name = CrateName::find_crate_name(path)?;
+        let root = path.ancestors().find(|dir| dir.ends_with("src"))?;
+        Some(Self {
+            name,
+            root_file: root.join("lib.rs"),
+            dir: root.parent().unwrap_or(root).to_path_buf(),
+            renames: HashMap::new(),
+        })
+    }
+
+    /// The path of the module a source file of this crate declares, mirroring
+    /// its path from the crate's root module: `src/a/b.rs` and `src/a/b/mod.rs`
+    /// are both `a::b`. Files outside of the root module's directory mirror
+    /// their path in the package instead.
+    pub fn module_path(&self, path: &Path) -> Vec<String> {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if path == self.root_file {
+            return Vec::new();
+        }
+        let root = self.root_file.parent().unwrap_or(&self.dir);
+        let relative = path
+            .strip_prefix(root)
+            .or_else(|_| path.strip_prefix(&self.dir))
+            .unwrap_or(&path);
+
+        let mut segments = relative
+            .with_extension("")
+            .iter()
+            .map(|segment| segment.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        if segments.last().is_some_and(|segment| segment == "mod") {
+            segments.pop();
+        }
+        // Binaries have their own root module next to the library's.
+        if segments.len() == 1 && segments[0] == "main" {
+            segments.clear();
+        }
+        segments
+    }
+
+    /// The crate, or module of a crate, a name imported in this crate refers to.
+    pub fn resolve(&self, crate_name: CrateName) -> CrateName {
+        let mut segments = crate_name.segments();
+        match segments
+            .next()
+            .and_then(|first| self.renames.get(&CrateName::from(first)))
+        {
+            Some(renamed) => renamed.module(segments),
+            None => crate_name.clone(),
+        }
+    }
+
+    /// Point the imports of a file in this crate at the crates they come from.
+    pub fn resolve_imports(&self, parsed_data: &mut ParsedData) {
+        parsed_data.import_types = parsed_data
+            .import_types
+            .drain()
+            .map(|mut import| {
+                import.base_crate = self.resolve(import.base_crate);
+                import
+            })
+            .collect();
+    }
+}
+
+/// The manifest of the workspace a package belongs to, if any.
+fn find_workspace(package_dir: &Path) -> Result<Option<(PathBuf, Manifest)>, GeneratorError> {
+    for dir in package_dir.ancestors().skip(1) {
+        let manifest_path = dir.join(MANIFEST_FILE_NAME);
+        if !manifest_path.is_file() {
+            continue;
+        }
+        let manifest = Manifest::read(&manifest_path)?;
+        if manifest.workspace.is_some() {
+            return Ok(Some((dir.to_path_buf(), manifest)));
+        }
+    }
+    Ok(None)
+}
+
+/// Finds the crate of each source file from the closest `Cargo.toml` with a
+/// `[package]` above it, remembering the crate of every directory it looked at.
+#[derive(Default)]
+pub struct CrateNames {
+    crates: Mutex<HashMap<PathBuf, Option<Arc<CrateInfo>>>>,
+}
+
+impl CrateNames {
+    /// The crate a source file belongs to. Outside of any package, the crate is
+    /// named after the directory containing `src`, or `None` without one.
+    pub fn crate_of(&self, path: &Path) -> Result<Option<Arc<CrateInfo>>, GeneratorError> {
+        let path = fs::canonicalize(path).map_err(|source| GeneratorError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let crate_info = match path.parent() {
+            Some(dir) => self.crate_in(dir)?,
+            None => None,
+        };
+        Ok(crate_info.or_else(|| CrateInfo::guess(&path).map(Arc::new)))
+    }
+
+    fn crate_in(&self, dir: &Path) -> Result<Option<Arc<CrateInfo>>, GeneratorError> {
+        if let Some(crate_info) = self.crates.lock().unwrap().get(dir) {
+            return Ok(crate_info.clone());
+        }
+
+        let manifest_path = dir.join(MANIFEST_FILE_NAME);
+        let crate_info = match manifest_path
+            .is_file()
+            .then(|| CrateInfo::read(&manifest_path))
+            .transpose()?
+            .flatten()
+        {
+            Some(crate_info) => Some(Arc::new(crate_info)),
+            None => match dir.parent() {
+                Some(parent) => self.crate_in(parent)?,
+                None => None,
+            },
+        };
+
+        self.crates
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), crate_info.clone());
+        Ok(crate_info)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{context::ParseContext, sources::parse_file};
+
+    const CURRENT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+    const TEST_DIR: &str = "data/workspace";
+
+    fn source_path(path: &str) -> PathBuf {
+        [CURRENT_DIR, TEST_DIR, path].iter().collect()
+    }
+
+    fn crate_name(crate_names: &CrateNames, path: &str) -> CrateName {
+        crate_names
+            .crate_of(&source_path(path))
+            .unwrap()
+            .unwrap()
+            .name
+            .clone()
+    }
+
+    #[test]
+    fn names_crates_after_manifests() {
+        let crate_names = CrateNames::default();
+
+        // `[lib] path` points outside of `src`.
+        assert_eq!(
+            crate_name(&crate_names, "app/lib/main.rs"),
+            "app_server".into()
+        );
+        // `[lib] name` differs from the package and its directory.
+        assert_eq!(
+            crate_name(&crate_names, "shared-types/src/lib.rs"),
+            "shared".into()
+        );
+        assert_eq!(
+            crate_name(&crate_names, "shared-types/types/extra.rs"),
+            "shared".into()
+        );
+    }
+
+    #[test]
+    fn resolves_renamed_dependencies() {
+        let crate_names = CrateNames::default();
+        let app = crate_names
+            .crate_of(&source_path("app/lib/main.rs"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(app.resolve("models".into()), "shared".into());
+        // Renamed in the workspace manifest.
+        assert_eq!(app.resolve("common".into()), "shared".into());
+        assert_eq!(app.resolve("other".into()), "other".into());
+    }
+
+    #[test]
+    fn mirrors_module_paths() {
+        let crate_names = CrateNames::default();
+        let module_path = |path: &str| {
+            let path = source_path(path);
+            crate_names
+                .crate_of(&path)
+                .unwrap()
+                .unwrap()
+                .module_path(&path)
+        };
+
+        assert!(module_path("app/lib/main.rs").is_empty());
+        assert!(module_path("shared-types/src/lib.rs").is_empty());
+        assert_eq!(module_path("shared-types/src/ids/mod.rs"), ["ids"]);
+        assert_eq!(module_path("shared-types/src/ids/user.rs"), ["ids", "user"]);
+        // Outside of `src`, the path in the package is mirrored instead.
+        assert_eq!(
+            module_path("shared-types/types/extra.rs"),
+            ["types", "extra"]
+        );
+    }
+
+    #[test]
+    fn names_modules_of_parsed_files() {
+        let crate_names = CrateNames::default();
+        let parse_context = ParseContext {
+            multi_file: true,
+            per_module: true,
+            ..Default::default()
+        };
+        let parse = |path: &str| {
+            parse_file(&parse_context, &crate_names, &source_path(path))
+                .unwrap()
+                .unwrap()
+        };
+
+        let user = parse("shared-types/src/ids/user.rs");
+        assert_eq!(user.crate_name, "shared::ids::user".into());
+        let imports = user
+            .import_types
+            .iter()
+            .map(|import| (import.base_crate.as_str(), import.type_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(imports, [("shared::ids", "AccountId")]);
+
+        // Renamed dependencies resolve to the root module of their crate.
+        let app = parse("app/lib/main.rs");
+        assert_eq!(app.crate_name, "app_server".into());
+        assert!(app
+            .import_types
+            .iter()
+            .all(|import| import.base_crate == "shared".into()));
+    }
+
+    #[test]
+    fn resolves_imports_of_parsed_files() {
+        let crate_names = CrateNames::default();
+        let parse_context = ParseContext {
+            multi_file: true,
+            ..Default::default()
+        };
+
+        let parsed_data = parse_file(
+            &parse_context,
+            &crate_names,
+            &source_path("app/lib/main.rs"),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(parsed_data.crate_name, "app_server".into());
+        let mut base_crates = parsed_data
+            .import_types
+            .iter()
+            .map(|import| (import.base_crate.as_str(), import.type_name.as_str()))
+            .collect::<Vec<_>>();
+        base_crates.sort();
+        assert_eq!(base_crates, [("shared", "Account"), ("shared", "User")]);
+    }
+}
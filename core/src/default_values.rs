@@ -0,0 +1,211 @@
+//! Statically resolve the values used by `#[serde(default)]`.
+use crate::rust_types::RustDefaultValue;
+use itertools::Itertools as _;
+use std::collections::HashMap;
+use syn::{
+    visit::{self, Visit},
+    Block, Expr, ExprLit, File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Lit, Member, Stmt, Type,
+    UnOp,
+};
+
+/// The literal values returned by functions and `Default` impls in a file.
+#[derive(Debug, Default)]
+pub(crate) struct DefaultValues {
+    /// Values returned by functions that take no arguments, by the path of the function
+    /// in the file, such as `defaults::port`, or `Config::port` for associated functions.
+    functions: HashMap<String, DefaultFunction>,
+    /// Field values of the `Self { .. }` literal in each `impl Default`, by type name.
+    impls: HashMap<String, HashMap<String, RustDefaultValue>>,
+    /// The modules around the item being visited.
+    modules: Vec<String>,
+    /// The type of the `impl` block being visited.
+    impl_ty: Option<String>,
+}
+
+#[derive(Debug)]
+struct DefaultFunction {
+    value: RustDefaultValue,
+    /// How many segments a path needs to name the function, which is two for associated
+    /// functions since `port` can't refer to `Config::port`.
+    min_segments: usize,
+}
+
+impl DefaultValues {
+    /// Collect the default values that can be resolved from `file`.
+    pub(crate) fn collect(file: &File) -> Self {
+        let mut values = Self::default();
+        values.visit_file(file);
+        values
+    }
+
+    /// The value returned by the function at `path`, as given to `#[serde(default = "...")]`
+    /// on a field of `self_ty`. Paths relative to another module than the function's are
+    /// matched by their last segments, as long as only one function matches.
+    pub(crate) fn function(&self, path: &str, self_ty: &str) -> Option<&RustDefaultValue> {
+        let segments = path
+            .split("::")
+            .skip_while(|segment| matches!(*segment, "crate" | "self" | "super"))
+            .map(|segment| if segment == "Self" { self_ty } else { segment })
+            .collect::<Vec<_>>();
+        let path = segments.join("::");
+        if let Some(function) = self.functions.get(&path) {
+            return Some(&function.value);
+        }
+
+        let suffix = format!("::{path}");
+        let mut matches = self.functions.iter().filter(|(function_path, function)| {
+            function_path.ends_with(&suffix) && segments.len() >= function.min_segments
+        });
+        match (matches.next(), matches.next()) {
+            (Some((_, function)), None) => Some(&function.value),
+            _ => None,
+        }
+    }
+
+    /// The value of `field` in the `Default` impl for `ty`.
+    pub(crate) fn field(&self, ty: &str, field: &str) -> Option<&RustDefaultValue> {
+        self.impls.get(ty)?.get(field)
+    }
+
+    fn collect_function(&mut self, name: String, has_inputs: bool, block: &Block) {
+        if let Some(value) = (!has_inputs).then(|| block_value(block)).flatten() {
+            let path = self
+                .modules
+                .iter()
+                .chain(&self.impl_ty)
+                .chain([&name])
+                .join("::");
+            let min_segments = if self.impl_ty.is_some() { 2 } else { 1 };
+            self.functions.insert(
+                path,
+                DefaultFunction {
+                    value,
+                    min_segments,
+                },
+            );
+        }
+    }
+}
+
+impl Visit<'_> for DefaultValues {
+    fn visit_item_mod(&mut self, m: &ItemMod) {
+        self.modules.push(m.ident.to_string());
+        visit::visit_item_mod(self, m);
+        self.modules.pop();
+    }
+
+    fn visit_item_fn(&mut self, f: &ItemFn) {
+        self.collect_function(f.sig.ident.to_string(), !f.sig.inputs.is_empty(), &f.block);
+        visit::visit_item_fn(self, f);
+    }
+
+    fn visit_impl_item_fn(&mut self, f: &ImplItemFn) {
+        self.collect_function(f.sig.ident.to_string(), !f.sig.inputs.is_empty(), &f.block);
+        visit::visit_impl_item_fn(self, f);
+    }
+
+    fn visit_item_impl(&mut self, i: &ItemImpl) {
+        let is_default_impl = i.trait_.as_ref().is_some_and(|(_, path, _)| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Default")
+        });
+        let self_ty = match i.self_ty.as_ref() {
+            Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        };
+
+        if let (true, Some(self_ty)) = (is_default_impl, self_ty.clone()) {
+            let fields = i
+                .items
+                .iter()
+                .find_map(|item| match item {
+                    syn::ImplItem::Fn(f) if f.sig.ident == "default" => block_expr(&f.block),
+                    _ => None,
+                })
+                .and_then(|expr| match expr {
+                    Expr::Struct(s) => Some(s),
+                    _ => None,
+                })
+                .map(|s| {
+                    s.fields
+                        .iter()
+                        .filter_map(|field| match &field.member {
+                            Member::Named(ident) => {
+                                Some((ident.to_string(), literal_value(&field.expr)?))
+                            }
+                            Member::Unnamed(_) => None,
+                        })
+                        .collect::<HashMap<_, _>>()
+                });
+            if let Some(fields) = fields {
+                self.impls.insert(self_ty, fields);
+            }
+        }
+
+        let outer_impl_ty = std::mem::replace(&mut self.impl_ty, self_ty);
+        visit::visit_item_impl(self, i);
+        self.impl_ty = outer_impl_ty;
+    }
+}
+
+/// The expression a block evaluates to, if it consists of only that expression.
+fn block_expr(block: &Block) -> Option<&Expr> {
+    match block.stmts.as_slice() {
+        [Stmt::Expr(expr, None)] => Some(expr),
+        [Stmt::Expr(Expr::Return(ret), _)] => ret.expr.as_deref(),
+        _ => None,
+    }
+}
+
+fn block_value(block: &Block) -> Option<RustDefaultValue> {
+    block_expr(block).and_then(literal_value)
+}
+
+/// Evaluate `expr` if it is a literal, including conversions of string literals
+/// such as `"text".to_string()` and `String::from("text")`.
+fn literal_value(expr: &Expr) -> Option<RustDefaultValue> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Bool(b) => Some(RustDefaultValue::Bool(b.value)),
+            Lit::Int(int) => int.base10_parse().ok().map(RustDefaultValue::Int),
+            Lit::Float(float) => float.base10_parse().ok().map(RustDefaultValue::Float),
+            Lit::Str(s) => Some(RustDefaultValue::String(s.value())),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            match literal_value(&unary.expr)? {
+                RustDefaultValue::Int(int) => Some(RustDefaultValue::Int(-int)),
+                RustDefaultValue::Float(float) => Some(RustDefaultValue::Float(-float)),
+                _ => None,
+            }
+        }
+        Expr::MethodCall(call)
+            if call.args.is_empty()
+                && ["to_string", "to_owned", "into"]
+                    .contains(&call.method.to_string().as_str()) =>
+        {
+            match literal_value(&call.receiver)? {
+                value @ RustDefaultValue::String(_) => Some(value),
+                _ => None,
+            }
+        }
+        Expr::Call(call) if call.args.len() == 1 => match call.func.as_ref() {
+            Expr::Path(path)
+                if path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .eq(["String", "from"]) =>
+            {
+                literal_value(&call.args[0])
+            }
+            _ => None,
+        },
+        Expr::Block(block) => block_value(&block.block),
+        Expr::Group(group) => literal_value(&group.expr),
+        Expr::Paren(paren) => literal_value(&paren.expr),
+        _ => None,
+    }
+}
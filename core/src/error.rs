@@ -1,6 +1,8 @@
 //! Error types for parsing.
+use crate::parser::ErrorInfo;
 use itertools::Itertools as _;
-use proc_macro2::Span;
+use proc_macro2::{LineColumn, Span};
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -12,6 +14,23 @@ pub struct ParseErrorWithSpan {
     span: Span,
 }
 
+impl ParseErrorWithSpan {
+    /// The error without its location.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// Where the error starts in the source file.
+    pub fn start(&self) -> LineColumn {
+        self.span.start()
+    }
+
+    /// Where the error ends in the source file.
+    pub fn end(&self) -> LineColumn {
+        self.span.end()
+    }
+}
+
 impl std::error::Error for ParseErrorWithSpan {}
 
 impl std::fmt::Display for ParseErrorWithSpan {
@@ -56,6 +75,16 @@ pub enum ParseError {
     RustConstTypeInvalid,
     #[error("The serde flatten attribute is not currently supported")]
     SerdeFlattenNotAllowed,
+    #[error("`{ident}` is serialized as \"{serialize}\" but deserialized as \"{deserialize}\", which cannot be expressed in the generated types")]
+    AsymmetricSerdeRename {
+        ident: String,
+        serialize: String,
+        deserialize: String,
+    },
+    #[error("The serde into type `{into}` differs from the from type `{from}`, which cannot be expressed in the generated types")]
+    SerdeConversionMismatch { into: String, from: String },
+    #[error("The discriminant of enum variant `{0}` must be an integer literal")]
+    UnsupportedDiscriminant(String),
     #[error("IO error: {0}")]
     IOError(String),
 }
@@ -87,6 +116,49 @@ pub enum GenerationError {
     PostGeneration(String),
 }
 
+#[derive(Debug, Error)]
+/// Errors while generating types with a [`Generator`](crate::Generator) or the
+/// command line tool.
+pub enum GeneratorError {
+    /// No input directory was given.
+    #[error("No input directory was given")]
+    NoInput,
+    /// No target language was given.
+    #[error("No target language was given")]
+    NoTarget,
+    /// Reading a source file or writing a generated file failed.
+    #[error("Failed to access {path:?}: {source}")]
+    Io {
+        /// The file or directory that couldn't be accessed.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// Walking the input directories failed.
+    #[error("Failed traversing the sources: {0}")]
+    Walk(#[from] ignore::Error),
+    /// A `Cargo.toml` manifest couldn't be parsed.
+    #[error("Failed to parse {path:?}: {source}")]
+    Manifest {
+        /// The manifest.
+        path: PathBuf,
+        /// The underlying error.
+        source: Box<toml::de::Error>,
+    },
+    /// None of the sources had types to write to a single file.
+    #[error("No types were found to write to {0:?}")]
+    NoTypes(PathBuf),
+    /// A single output file was given more or less than one generated file.
+    #[error("Expected exactly one generated file for {0:?}")]
+    FileCount(PathBuf),
+    /// The Rust sources have errors, which are all reported together.
+    #[error("{} errors encountered during parsing", .0.len())]
+    Parse(Vec<ErrorInfo>),
+    /// The language failed to generate its types.
+    #[error(transparent)]
+    Generation(#[from] GenerationError),
+}
+
 #[derive(Debug, Error)]
 #[allow(missing_docs)]
 pub enum RustTypeParseError {
@@ -0,0 +1,125 @@
+//! Generating types from a build script, without the command line tool.
+//!
+//! ```no_run
+//! use typeshare_core::{language::TypeScript, Generator};
+//!
+//! // In `build.rs`:
+//! println!("cargo:rerun-if-changed=src");
+//! Generator::new()
+//!     .input_dir("src")
+//!     .target(TypeScript::default())
+//!     .write_to("web/src/types.ts")
+//!     .expect("failed to generate types");
+//! ```
+use crate::{
+    context::ParseContext,
+    crate_names::CrateNames,
+    error::GeneratorError,
+    language::{Go, Kotlin, Language, Python, Scala, SupportedLanguage, Swift, TypeScript},
+    sources::{parse_sources, source_walker},
+    writer::{write_generated, Output, WriteMode, WriteOptions},
+};
+use std::path::{Path, PathBuf};
+
+/// A language built into typeshare, which a [`Generator`] can write.
+pub trait BuiltinLanguage: Language {
+    /// Which language this is.
+    fn language_type(&self) -> SupportedLanguage;
+}
+
+macro_rules! builtin_language {
+    ($($language:ident),*) => {
+        $(impl BuiltinLanguage for $language {
+            fn language_type(&self) -> SupportedLanguage {
+                SupportedLanguage::$language
+            }
+        })*
+    };
+}
+
+builtin_language!(Go, Kotlin, Python, Scala, Swift, TypeScript);
+
+/// Walks Rust sources, parses and reconciles their types and writes them in
+/// a target language, the same way the command line tool does.
+#[derive(Default)]
+pub struct Generator {
+    input_dirs: Vec<PathBuf>,
+    target_os: Vec<String>,
+    target: Option<(SupportedLanguage, Box<dyn Language>)>,
+}
+
+impl Generator {
+    /// Create a generator without any inputs or target.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a directory to search recursively for Rust sources.
+    pub fn input_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.input_dirs.push(dir.into());
+        self
+    }
+
+    /// Only generate types available on these target operating systems.
+    pub fn target_os(mut self, target_os: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.target_os = target_os.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the language to generate, along with its options.
+    pub fn target(mut self, language: impl BuiltinLanguage + 'static) -> Self {
+        self.target = Some((language.language_type(), Box::new(language)));
+        self
+    }
+
+    /// Write every type to a single file.
+    pub fn write_to(self, file: impl AsRef<Path>) -> Result<(), GeneratorError> {
+        self.generate(Output::File(file.as_ref()))
+    }
+
+    /// Write the types of each crate to their own file in a folder. Crates are
+    /// named after their `Cargo.toml` manifests, like the command line tool
+    /// names them.
+    ///
+    /// Languages that need to know, such as [`Swift`], should have multi file
+    /// output turned on in their options.
+    pub fn write_to_folder(self, folder: impl AsRef<Path>) -> Result<(), GeneratorError> {
+        self.generate(Output::Folder(folder.as_ref()))
+    }
+
+    /// Parse and reconcile the types of every input file, then write them for
+    /// the target.
+    fn generate(self, destination: Output<'_>) -> Result<(), GeneratorError> {
+        if self.input_dirs.is_empty() {
+            return Err(GeneratorError::NoInput);
+        }
+        let (language_type, mut lang) = self.target.ok_or(GeneratorError::NoTarget)?;
+
+        let parse_context = ParseContext {
+            // Types mapped by the language are ignored when writing them.
+            ignored_types: Vec::new(),
+            multi_file: matches!(destination, Output::Folder(_)),
+            per_module: false,
+            target_os: self.target_os,
+        };
+        let crate_parsed_data = parse_sources(
+            &parse_context,
+            &CrateNames::default(),
+            source_walker(&self.input_dirs, false)?,
+        )?;
+
+        write_generated(
+            destination,
+            lang.as_mut(),
+            language_type,
+            crate_parsed_data,
+            WriteOptions {
+                mode: WriteMode::Write,
+                prune: true,
+                owner: language_type.language_extension(),
+                crates: None,
+            },
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//! A serializable intermediate representation of the parsed types, which can be
+//! stored and used to generate code later without parsing the Rust sources again.
+use crate::{context::OutputKind, language::CrateName, parser::ParsedData};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The current version of the intermediate representation. It changes whenever
+/// the representation changes in a way older readers can't handle.
+pub const IR_VERSION: u32 = 1;
+
+/// The reconciled types of each crate, for the kinds of output they were parsed for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntermediateRepresentation {
+    /// The version of the representation.
+    pub version: u32,
+    /// All the types together, for output to a single file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub single_file: Option<BTreeMap<CrateName, ParsedData>>,
+    /// The types of each crate, for output to a file per crate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multi_file: Option<BTreeMap<CrateName, ParsedData>>,
+    /// The types of each module, for output to a file per module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_module: Option<BTreeMap<CrateName, ParsedData>>,
+}
+
+impl Default for IntermediateRepresentation {
+    fn default() -> Self {
+        Self {
+            version: IR_VERSION,
+            single_file: None,
+            multi_file: None,
+            per_module: None,
+        }
+    }
+}
+
+impl IntermediateRepresentation {
+    /// The types parsed for a kind of output.
+    pub fn crates(&self, kind: OutputKind) -> Option<&BTreeMap<CrateName, ParsedData>> {
+        match kind {
+            OutputKind::SingleFile => self.single_file.as_ref(),
+            OutputKind::MultiFile => self.multi_file.as_ref(),
+            OutputKind::PerModule => self.per_module.as_ref(),
+        }
+    }
+
+    /// Store the types parsed for a kind of output.
+    pub fn set_crates(&mut self, kind: OutputKind, crates: BTreeMap<CrateName, ParsedData>) {
+        let slot = match kind {
+            OutputKind::SingleFile => &mut self.single_file,
+            OutputKind::MultiFile => &mut self.multi_file,
+            OutputKind::PerModule => &mut self.per_module,
+        };
+        *slot = Some(crates);
+    }
+}
@@ -3,10 +3,12 @@ use std::io::Write;
 use crate::language::SupportedLanguage;
 use crate::parser::ParsedData;
 use crate::rename::RenameExt;
-use crate::rust_types::{RustConst, RustConstExpr, RustItem, RustTypeFormatError, SpecialRustType};
+use crate::rust_types::{
+    RustConst, RustConstExpr, RustItem, RustType, RustTypeFormatError, SpecialRustType,
+};
 use crate::{
     language::Language,
-    rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
+    rust_types::{RustEnum, RustEnumShared, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
     topsort::topsort,
 };
 use std::collections::{BTreeSet, HashMap, HashSet};
@@ -90,7 +92,7 @@ impl Language for Go {
         });
 
         for alias in alias_iter {
-            if types_mapping_to_struct.contains(alias.r#type.id()) {
+            if types_mapping_to_struct.contains(alias.r#type.unwrap_pointers().id()) {
                 types_mapping_to_struct.insert(alias.id.original.as_str());
             }
         }
@@ -126,7 +128,9 @@ impl Language for Go {
         };
 
         Ok(match special_ty {
-            SpecialRustType::Vec(rtype) => format!("[]{}", self.format_type(rtype, generic_types)?),
+            SpecialRustType::Vec(rtype) | SpecialRustType::ConstLenArray(rtype, _) => {
+                format!("[]{}", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Array(rtype, len) => {
                 format!("[{}]{}", len, self.format_type(rtype, generic_types)?)
             }
@@ -144,6 +148,9 @@ impl Language for Go {
                     self.format_type(rtype, generic_types)?
                 )
             }
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                return self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => format!(
                 "map[{}]{}",
                 self.format_type(rtype1, generic_types)?,
@@ -165,10 +172,22 @@ impl Language for Go {
             SpecialRustType::Bool => "bool".into(),
             SpecialRustType::F32 => "float32".into(),
             SpecialRustType::F64 => "float64".into(),
-            SpecialRustType::DateTime => {
+            SpecialRustType::DateTime | SpecialRustType::ChronoDateTime => {
                 self.add_import("time");
                 "time.Time".into()
             }
+            SpecialRustType::NaiveDate
+            | SpecialRustType::Uuid
+            | SpecialRustType::Url
+            | SpecialRustType::Decimal => "string".into(),
+            // `{ "secs": u64, "nanos": u32 }`
+            SpecialRustType::Duration => self.format_special_type(
+                &SpecialRustType::HashMap(
+                    RustType::Special(SpecialRustType::String).into(),
+                    RustType::Special(SpecialRustType::U64).into(),
+                ),
+                generic_types,
+            )?,
         })
     }
 
@@ -244,7 +263,15 @@ impl Language for Go {
             .iter()
             .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
 
-        writeln!(w, "}}")
+        writeln!(w, "}}")?;
+
+        // Go doesn't allow declaring types inside generic functions, so the alias
+        // decoder can only be written for non-generic structs.
+        if rs.generic_types.is_empty() && rs.fields.iter().any(|f| !f.aliases.is_empty()) {
+            self.write_alias_decoder(w, rs)?;
+        }
+
+        Ok(())
     }
 
     fn write_imports(
@@ -279,9 +306,15 @@ impl Go {
 
         match e {
             RustEnum::Unit(shared) => {
+                let underlying = match &shared.repr {
+                    Some(repr) => self
+                        .format_special_type(repr, &[])
+                        .map_err(std::io::Error::other)?,
+                    None => "string".to_string(),
+                };
                 writeln!(
                     w,
-                    "type {} string",
+                    "type {} {underlying}",
                     self.acronyms_to_uppercase(&shared.id.original)
                 )?;
 
@@ -291,19 +324,33 @@ impl Go {
                     RustEnumVariant::Unit(variant_shared) => {
                         writeln!(w)?;
                         write_comments(w, 1, &variant_shared.comments)?;
+                        let value =
+                            match variant_shared.discriminant.filter(|_| shared.is_numeric()) {
+                                Some(discriminant) => discriminant.to_string(),
+                                None => format!("{:?}", &variant_shared.id.renamed),
+                            };
                         write!(
                             w,
-                            "\t{}{} {} = {:?}",
+                            "\t{}{} {} = {value}",
                             self.acronyms_to_uppercase(&shared.id.original),
                             self.acronyms_to_uppercase(&variant_shared.id.original),
                             self.acronyms_to_uppercase(&shared.id.original),
-                            &variant_shared.id.renamed
                         )
                     }
                     _ => unreachable!(),
                 })?;
 
-                writeln!(w, "\n)")
+                writeln!(w, "\n)")?;
+
+                if !shared.is_numeric()
+                    && shared
+                        .variants
+                        .iter()
+                        .any(|v| !v.shared().aliases.is_empty())
+                {
+                    self.write_enum_alias_decoder(w, shared)?;
+                }
+                Ok(())
             }
             RustEnum::Algebraic {
                 tag_key,
@@ -489,7 +536,9 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
         };
 
         let go_type = self.acronyms_to_uppercase(&type_name);
-        let is_optional = field.ty.is_optional() || field.has_default;
+        // serde writes `None` as `null` unless the field is skipped
+        let is_optional =
+            field.skip_serializing_if.is_some() || (field.has_default && !field.ty.is_optional());
         let formatted_renamed_id = format!("{:?}", &field.id.renamed);
         let renamed_id = &formatted_renamed_id[1..formatted_renamed_id.len() - 1];
         writeln!(
@@ -509,6 +558,105 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
         Ok(())
     }
 
+    /// Write an `UnmarshalJSON` method that also accepts the serde aliases of each field.
+    fn write_alias_decoder(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        let struct_name = self.acronyms_to_uppercase(&rs.id.renamed);
+        let short_name = rs.id.renamed[..1].to_lowercase();
+
+        let mut alias_fields = Vec::new();
+        let mut assignments = Vec::new();
+        for f in &rs.fields {
+            let field_name = self.format_field_name(f.id.original.to_string(), true);
+            let go_type = match f.type_override(SupportedLanguage::Go) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&f.ty, &rs.generic_types)
+                    .map_err(std::io::Error::other)?,
+            };
+            let go_type = self.acronyms_to_uppercase(&go_type);
+            // Fields with a default are already pointers, see `write_field`.
+            let (go_type, deref) = if f.has_default && !f.ty.is_optional() {
+                (format!("*{go_type}"), "")
+            } else {
+                (go_type, "*")
+            };
+
+            for (i, alias) in f.aliases.iter().enumerate() {
+                let alias_field = format!("{field_name}Alias{i}");
+                alias_fields.push(format!("\t\t{alias_field} *{go_type} `json:{alias:?}`"));
+                assignments.push(format!(
+                    "\tif aux.{alias_field} != nil {{\n\t\t{short_name}.{field_name} = {deref}aux.{alias_field}\n\t}}"
+                ));
+            }
+        }
+
+        writeln!(
+            w,
+            r#"
+func ({short_name} *{struct_name}) UnmarshalJSON(data []byte) error {{
+	type plain {struct_name}
+	var aux struct {{
+		*plain
+{alias_fields}
+	}}
+	aux.plain = (*plain)({short_name})
+	if err := json.Unmarshal(data, &aux); err != nil {{
+		return err
+	}}
+{assignments}
+	return nil
+}}"#,
+            alias_fields = alias_fields.join("\n"),
+            assignments = assignments.join("\n"),
+        )
+    }
+
+    /// Write an `UnmarshalJSON` method that also accepts the serde aliases of each variant.
+    fn write_enum_alias_decoder(
+        &mut self,
+        w: &mut dyn Write,
+        shared: &RustEnumShared,
+    ) -> std::io::Result<()> {
+        let enum_name = self.acronyms_to_uppercase(&shared.id.original);
+        let short_name = shared.id.original[..1].to_lowercase();
+
+        let cases = shared
+            .variants
+            .iter()
+            .filter(|v| !v.shared().aliases.is_empty())
+            .map(|v| {
+                format!(
+                    "\tcase {}:\n\t\t*{short_name} = {enum_name}{}",
+                    v.shared()
+                        .aliases
+                        .iter()
+                        .map(|a| format!("{a:?}"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    self.acronyms_to_uppercase(&v.shared().id.original),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        writeln!(
+            w,
+            r#"
+func ({short_name} *{enum_name}) UnmarshalJSON(data []byte) error {{
+	var value string
+	if err := json.Unmarshal(data, &value); err != nil {{
+		return err
+	}}
+	switch value {{
+{cases}
+	default:
+		*{short_name} = {enum_name}(value)
+	}}
+	return nil
+}}"#
+        )
+    }
+
     // Convert any of the configured acronyms to uppercase to follow Go's formatting standard.
     // If self.uppercase_acronyms contains ID (or id), Id will get replaced by ID.
     fn acronyms_to_uppercase(&self, name: &str) -> String {
@@ -1,15 +1,21 @@
-use super::{Language, ScopedCrateTypes};
+use super::{CrateName, Language, ScopedCrateTypes};
 use crate::language::SupportedLanguage;
 use crate::parser::{remove_dash_from_identifier, DecoratorKind, ParsedData};
-use crate::rust_types::{RustTypeFormatError, SpecialRustType};
+use crate::rust_types::{RustDefaultValue, RustType, RustTypeFormatError, SpecialRustType};
 use crate::{
     rename::RenameExt,
-    rust_types::{Id, RustConst, RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
+    rust_types::{
+        Id, RustConst, RustEnum, RustEnumShared, RustEnumVariant, RustField, RustStruct,
+        RustTypeAlias,
+    },
 };
 use itertools::Itertools;
 use joinery::JoinableIterator;
 use lazy_format::lazy_format;
-use std::{collections::BTreeSet, collections::HashMap, io::Write};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::Write,
+};
 
 const INLINE: &str = "JvmInline";
 
@@ -27,6 +33,9 @@ pub struct Kotlin {
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// The `#[serde(other)]` variant of each unit enum in any crate, used as the
+    /// default value of fields with that enum's type.
+    pub catch_all_variants: HashMap<String, String>,
 }
 
 impl Language for Kotlin {
@@ -54,7 +63,7 @@ impl Language for Kotlin {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
-            SpecialRustType::Vec(rtype) => {
+            SpecialRustType::Vec(rtype) | SpecialRustType::ConstLenArray(rtype, _) => {
                 format!("List<{}>", self.format_type(rtype, generic_types)?)
             }
             SpecialRustType::Array(rtype, _) => {
@@ -66,6 +75,9 @@ impl Language for Kotlin {
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                return self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => {
                 format!(
                     "HashMap<{}, {}>",
@@ -89,16 +101,33 @@ impl Language for Kotlin {
             SpecialRustType::Bool => "Boolean".into(),
             SpecialRustType::F32 => "Float".into(),
             SpecialRustType::F64 => "Double".into(),
-            // TODO: https://github.com/1Password/typeshare/issues/237
-            SpecialRustType::DateTime => {
-                return Err(RustTypeFormatError::UnsupportedSpecialType(
-                    special_ty.to_string(),
-                ))
-            }
+            // Dates and times are kept as the RFC3339 or ISO8601 strings they serialize as
+            SpecialRustType::DateTime
+            | SpecialRustType::ChronoDateTime
+            | SpecialRustType::NaiveDate
+            | SpecialRustType::Uuid
+            | SpecialRustType::Url
+            | SpecialRustType::Decimal => "String".into(),
+            // `{ "secs": u64, "nanos": u32 }`
+            SpecialRustType::Duration => self.format_special_type(
+                &SpecialRustType::HashMap(
+                    RustType::Special(SpecialRustType::String).into(),
+                    RustType::Special(SpecialRustType::U64).into(),
+                ),
+                generic_types,
+            )?,
         })
     }
 
+    fn pre_generation(&mut self, crate_parsed_data: &BTreeMap<CrateName, ParsedData>) {
+        for parsed_data in crate_parsed_data.values() {
+            self.collect_catch_all_variants(parsed_data);
+        }
+    }
+
     fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> std::io::Result<()> {
+        // A file generated on its own still knows the enums it defines.
+        self.collect_catch_all_variants(parsed_data);
         if !self.package.is_empty() {
             if !self.no_version_header {
                 writeln!(w, "/**")?;
@@ -106,14 +135,49 @@ impl Language for Kotlin {
                 writeln!(w, " */")?;
                 writeln!(w)?;
             }
+            let uses_aliases = uses_aliases(parsed_data);
+            if uses_aliases {
+                // `@JsonNames` is still an experimental API
+                writeln!(
+                    w,
+                    "@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)"
+                )?;
+                writeln!(w)?;
+            }
             if parsed_data.multi_file {
-                writeln!(w, "package {}.{}", self.package, parsed_data.crate_name)?;
+                writeln!(
+                    w,
+                    "package {}.{}",
+                    self.package,
+                    parsed_data.crate_name.segments().join(".")
+                )?;
             } else {
                 writeln!(w, "package {}", self.package)?;
             }
             writeln!(w)?;
             writeln!(w, "import kotlinx.serialization.Serializable")?;
             writeln!(w, "import kotlinx.serialization.SerialName")?;
+            if uses_aliases {
+                writeln!(w, "import kotlinx.serialization.json.JsonNames")?;
+            }
+            if parsed_data.enums.iter().any(is_algebraic_with_catch_all) {
+                writeln!(w, "import kotlinx.serialization.modules.SerializersModule")?;
+            }
+            if parsed_data
+                .enums
+                .iter()
+                .any(|e| matches!(e, RustEnum::Unit(shared) if shared.is_numeric()))
+            {
+                writeln!(w, "import kotlinx.serialization.KSerializer")?;
+                writeln!(w, "import kotlinx.serialization.SerializationException")?;
+                writeln!(w, "import kotlinx.serialization.descriptors.PrimitiveKind")?;
+                writeln!(
+                    w,
+                    "import kotlinx.serialization.descriptors.PrimitiveSerialDescriptor"
+                )?;
+                writeln!(w, "import kotlinx.serialization.encoding.Decoder")?;
+                writeln!(w, "import kotlinx.serialization.encoding.Encoder")?;
+            }
             writeln!(w)?;
         }
 
@@ -141,6 +205,9 @@ impl Language for Kotlin {
                     comments: vec![],
                     has_default: false,
                     decorators: HashMap::new(),
+                    aliases: vec![],
+                    skip_serializing_if: None,
+                    default_value: None,
                 },
                 &[],
                 false,
@@ -255,7 +322,14 @@ impl Language for Kotlin {
         })?;
 
         self.write_comments(w, 0, &e.shared().comments)?;
-        writeln!(w, "@Serializable")?;
+        match e {
+            RustEnum::Unit(shared) if shared.is_numeric() => writeln!(
+                w,
+                "@Serializable(with = {}{}Serializer::class)",
+                self.prefix, shared.id.renamed
+            )?,
+            _ => writeln!(w, "@Serializable")?,
+        }
 
         let generic_parameters = if !e.shared().generic_types.is_empty() {
             format!("<{}>", e.shared().generic_types.join(", "))
@@ -264,10 +338,18 @@ impl Language for Kotlin {
         };
 
         match e {
-            RustEnum::Unit(..) => {
+            RustEnum::Unit(shared) => {
+                let value = match &shared.repr {
+                    Some(repr) => format!(
+                        "val value: {}",
+                        self.format_special_type(repr, &[])
+                            .map_err(std::io::Error::other)?
+                    ),
+                    None => "val string: String".to_string(),
+                };
                 write!(
                     w,
-                    "enum class {}{}{}(val string: String) ",
+                    "enum class {}{}{}({value}) ",
                     self.prefix,
                     &e.shared().id.renamed,
                     generic_parameters
@@ -288,7 +370,33 @@ impl Language for Kotlin {
 
         self.write_enum_variants(w, e)?;
 
-        writeln!(w, "}}\n")
+        writeln!(w, "}}\n")?;
+
+        if let RustEnum::Unit(
+            shared @ RustEnumShared {
+                repr: Some(repr), ..
+            },
+        ) = e
+        {
+            self.write_repr_serializer(w, shared, repr)?;
+        }
+
+        if let (RustEnum::Algebraic { shared, .. }, Some(catch_all)) =
+            (e, e.shared().catch_all_variant())
+        {
+            let name = format!("{}{}", self.prefix, shared.id.renamed);
+            writeln!(
+                w,
+                "/// Decodes tags added after this client was built as `{name}.{variant}`. Add it to the\n\
+                 /// `serializersModule` of your `Json`.\n\
+                 @OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)\n\
+                 val {name}SerializersModule = SerializersModule {{\n\
+                 \tpolymorphicDefaultDeserializer({name}::class) {{ {name}.{variant}.serializer() }}\n\
+                 }}\n",
+                variant = variant_class_name(&catch_all.id.original),
+            )?;
+        }
+        Ok(())
     }
 
     fn write_imports(
@@ -298,7 +406,12 @@ impl Language for Kotlin {
     ) -> std::io::Result<()> {
         for (path, ty) in imports {
             for t in ty {
-                writeln!(w, "import {}.{path}.{t}", self.package)?;
+                writeln!(
+                    w,
+                    "import {}.{}.{t}",
+                    self.package,
+                    path.segments().join(".")
+                )?;
             }
         }
         writeln!(w)
@@ -315,12 +428,75 @@ enum Visibility {
 }
 
 impl Kotlin {
+    /// Write the serializer of a `serde_repr` enum, which encodes each variant as its
+    /// discriminant instead of its name.
+    fn write_repr_serializer(
+        &mut self,
+        w: &mut dyn Write,
+        shared: &RustEnumShared,
+        repr: &SpecialRustType,
+    ) -> std::io::Result<()> {
+        let name = format!("{}{}", self.prefix, shared.id.renamed);
+        // Unsigned 32 bit values don't fit in an `Int`
+        let (encoding, kind) = match repr {
+            SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::ISize
+            | SpecialRustType::U8
+            | SpecialRustType::U16 => ("Int", "INT"),
+            _ => ("Long", "LONG"),
+        };
+        writeln!(
+            w,
+            r#"object {name}Serializer : KSerializer<{name}> {{
+	override val descriptor = PrimitiveSerialDescriptor("{name}", PrimitiveKind.{kind})
+
+	override fun serialize(encoder: Encoder, value: {name}) {{
+		encoder.encode{encoding}(value.value.to{encoding}())
+	}}
+
+	override fun deserialize(decoder: Decoder): {name} {{
+		val value = decoder.decode{encoding}()
+		return {name}.entries.firstOrNull {{ it.value.to{encoding}() == value }}
+			?: throw SerializationException("Unknown {name}: $value")
+	}}
+}}
+"#
+        )
+    }
+
+    /// Remember the `#[serde(other)]` variants of the unit enums of a file.
+    fn collect_catch_all_variants(&mut self, parsed_data: &ParsedData) {
+        self.catch_all_variants
+            .extend(parsed_data.enums.iter().filter_map(|e| {
+                match e {
+                    RustEnum::Unit(shared) => shared
+                        .catch_all_variant()
+                        .map(|variant| (shared.id.renamed.clone(), variant.id.original.clone())),
+                    RustEnum::Algebraic { .. } => None,
+                }
+            }));
+    }
+
     fn write_enum_variants(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
         match e {
             RustEnum::Unit(shared) => {
                 for v in &shared.variants {
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    if let Some((repr, discriminant)) =
+                        shared.repr.as_ref().zip(v.shared().discriminant)
+                    {
+                        writeln!(
+                            w,
+                            "\t{}({discriminant}{}),",
+                            &v.shared().id.original,
+                            integer_literal_suffix(repr)
+                        )?;
+                        continue;
+                    }
                     writeln!(w, "\t@SerialName({:?})", &v.shared().id.renamed)?;
+                    write_json_names(w, &v.shared().aliases)?;
                     writeln!(
                         w,
                         "\t{}({:?}),",
@@ -340,22 +516,7 @@ impl Kotlin {
                     writeln!(w, "\t@Serializable")?;
                     writeln!(w, "\t@SerialName({printed_value})")?;
 
-                    let variant_name = {
-                        let mut variant_name = v.shared().id.original.to_pascal_case();
-
-                        if variant_name
-                            .chars()
-                            .next()
-                            .map(|c| c.is_ascii_digit())
-                            .unwrap_or(false)
-                        {
-                            // If the name starts with a digit just add an underscore
-                            // to the front and make it valid
-                            variant_name = format!("_{variant_name}");
-                        }
-
-                        variant_name
-                    };
+                    let variant_name = variant_class_name(&v.shared().id.original);
 
                     match v {
                         RustEnumVariant::Unit(_) => {
@@ -453,6 +614,7 @@ impl Kotlin {
         if requires_serial_name {
             writeln!(w, "\t@SerialName({:?})", &f.id.renamed)?;
         }
+        write_json_names(w, &f.aliases)?;
         let ty = match f.type_override(SupportedLanguage::Kotlin) {
             Some(type_override) => type_override.to_owned(),
             None => self
@@ -460,26 +622,33 @@ impl Kotlin {
                 .map_err(std::io::Error::other)?,
         };
 
+        let default = if let Some(value) = &f.default_value {
+            format!(" = {}", default_literal(value, &f.ty))
+        } else if f.has_default && !f.ty.is_optional() {
+            "? = null".to_string()
+        } else if f.ty.is_optional() {
+            " = null".to_string()
+        } else if let Some(catch_all) = self.catch_all_variants.get(f.ty.unwrap_pointers().id()) {
+            // Lets `coerceInputValues` decode unknown values as the `#[serde(other)]` variant
+            format!(" = {ty}.{catch_all}")
+        } else {
+            String::new()
+        };
+
         match visibility {
             Visibility::Public => write!(
                 w,
                 "\tval {}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 ty,
-                (f.has_default && !f.ty.is_optional())
-                    .then_some("? = null")
-                    .or_else(|| f.ty.is_optional().then_some(" = null"))
-                    .unwrap_or_default()
+                default
             ),
             Visibility::Private => write!(
                 w,
                 "\tprivate val {}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 ty,
-                (f.has_default && !f.ty.is_optional())
-                    .then_some("? = null")
-                    .or_else(|| f.ty.is_optional().then_some(" = null"))
-                    .unwrap_or_default()
+                default
             ),
         }
     }
@@ -512,3 +681,155 @@ impl Kotlin {
         }
     }
 }
+
+/// The name of the class of an algebraic enum variant.
+fn variant_class_name(original: &str) -> String {
+    let variant_name = original.to_string().to_pascal_case();
+    if variant_name
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_digit())
+        .unwrap_or(false)
+    {
+        // If the name starts with a digit just add an underscore
+        // to the front and make it valid
+        format!("_{variant_name}")
+    } else {
+        variant_name
+    }
+}
+
+/// Whether unknown tags of an enum decode as a `#[serde(other)]` variant of a sealed class,
+/// which needs a `SerializersModule`.
+fn is_algebraic_with_catch_all(e: &RustEnum) -> bool {
+    matches!(e, RustEnum::Algebraic { shared, .. } if shared.catch_all_variant().is_some())
+}
+
+/// The suffix Kotlin needs on an integer literal of the given type, e.g. `u` for `UByte`.
+fn integer_literal_suffix(ty: &SpecialRustType) -> &'static str {
+    match ty {
+        SpecialRustType::I54 | SpecialRustType::I64 => "L",
+        SpecialRustType::U8
+        | SpecialRustType::U16
+        | SpecialRustType::U32
+        | SpecialRustType::USize => "u",
+        SpecialRustType::U53 | SpecialRustType::U64 => "uL",
+        _ => "",
+    }
+}
+
+/// A Kotlin literal for a field's default value.
+fn default_literal(value: &RustDefaultValue, ty: &RustType) -> String {
+    match (value, ty) {
+        (RustDefaultValue::Bool(b), _) => b.to_string(),
+        (RustDefaultValue::Int(int), RustType::Special(special)) => {
+            format!("{int}{}", integer_literal_suffix(special))
+        }
+        (RustDefaultValue::Int(int), _) => int.to_string(),
+        (RustDefaultValue::Float(float), RustType::Special(SpecialRustType::F32)) => {
+            format!("{float:?}f")
+        }
+        (RustDefaultValue::Float(float), _) => format!("{float:?}"),
+        // `$` starts a template expression in Kotlin strings
+        (RustDefaultValue::String(s), _) => format!("{s:?}").replace('$', "\\$"),
+    }
+}
+
+/// Write `@JsonNames` for any serde aliases so they are accepted when decoding.
+fn write_json_names(w: &mut dyn Write, aliases: &[String]) -> std::io::Result<()> {
+    if aliases.is_empty() {
+        return Ok(());
+    }
+    writeln!(
+        w,
+        "\t@JsonNames({})",
+        aliases.iter().map(|alias| format!("{alias:?}")).join(", ")
+    )
+}
+
+/// Whether any field or unit enum variant has serde aliases, which need the `JsonNames` import.
+fn uses_aliases(parsed_data: &ParsedData) -> bool {
+    let struct_fields = parsed_data.structs.iter().flat_map(|s| &s.fields);
+    let variant_fields = parsed_data
+        .enums
+        .iter()
+        .flat_map(|e| &e.shared().variants)
+        .flat_map(|v| match v {
+            RustEnumVariant::AnonymousStruct { fields, .. } => fields.as_slice(),
+            _ => &[],
+        });
+    let mut unit_variants = parsed_data
+        .enums
+        .iter()
+        .filter_map(|e| match e {
+            RustEnum::Unit(shared) => Some(&shared.variants),
+            RustEnum::Algebraic { .. } => None,
+        })
+        .flatten();
+
+    struct_fields
+        .chain(variant_fields)
+        .any(|f| !f.aliases.is_empty())
+        || unit_variants.any(|v| !v.shared().aliases.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        context::ParseContext,
+        crate_names::CrateNames,
+        sources::{parse_sources, source_walker},
+        writer::{write_generated, Output, WriteMode, WriteOptions},
+    };
+    use std::{fs, path::PathBuf};
+
+    #[test]
+    fn packages_per_module() {
+        let input: PathBuf = [env!("CARGO_MANIFEST_DIR"), "data", "workspace"]
+            .iter()
+            .collect();
+        let output = std::env::temp_dir().join("typeshare_kotlin_per_module");
+        let _ = fs::remove_dir_all(&output);
+
+        let parse_context = ParseContext {
+            multi_file: true,
+            per_module: true,
+            ..Default::default()
+        };
+        let crate_parsed_data = parse_sources(
+            &parse_context,
+            &CrateNames::default(),
+            source_walker(&[input], false).unwrap(),
+        )
+        .unwrap();
+        write_generated(
+            Output::Folder(&output),
+            &mut Kotlin {
+                package: "com.example".into(),
+                ..Default::default()
+            },
+            SupportedLanguage::Kotlin,
+            crate_parsed_data,
+            WriteOptions {
+                mode: WriteMode::Write,
+                prune: true,
+                owner: "kt",
+                crates: None,
+            },
+        )
+        .unwrap();
+
+        let user_file = SupportedLanguage::Kotlin.output_file_name(&"shared::ids::user".into());
+        let user = fs::read_to_string(output.join(user_file)).unwrap();
+        fs::remove_dir_all(&output).unwrap();
+        assert!(
+            user.contains("package com.example.shared.ids.user\n"),
+            "{user}"
+        );
+        assert!(
+            user.contains("import com.example.shared.ids.AccountId\n"),
+            "{user}"
+        );
+    }
+}
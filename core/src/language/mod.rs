@@ -7,6 +7,7 @@ use crate::{
     },
     topsort::topsort,
     visitors::ImportedType,
+    RenameExt,
 };
 use itertools::Itertools;
 use log::warn;
@@ -15,6 +16,7 @@ use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::Display,
     io::Write,
+    mem,
     path::Path,
     str::FromStr,
 };
@@ -29,12 +31,15 @@ mod typescript;
 pub use go::Go;
 pub use kotlin::Kotlin;
 pub use python::Python;
+pub use python::PythonStyle;
 pub use scala::Scala;
+pub use scala::ScalaDialect;
 pub use swift::GenericConstraints;
 pub use swift::Swift;
 pub use typescript::TypeScript;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A crate name.
 pub struct CrateName(String);
 
@@ -53,12 +58,32 @@ impl From<String> for CrateName {
 /// When using single file output we put all types into a single virtual name space.
 pub const SINGLE_FILE_CRATE_NAME: CrateName = CrateName(String::new());
 
+/// Separates the crate and its modules in the name of a module.
+pub const MODULE_SEPARATOR: &str = "::";
+
 impl CrateName {
     /// View this crate name as a string slice.
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 
+    /// The name of a module of this crate, which takes the place of the crate's
+    /// name when each module is written to its own file. The crate's root module
+    /// has the crate's name.
+    pub fn module<S: AsRef<str>>(&self, path: impl IntoIterator<Item = S>) -> Self {
+        let mut name = self.0.clone();
+        for segment in path {
+            name.push_str(MODULE_SEPARATOR);
+            name.push_str(segment.as_ref());
+        }
+        CrateName(name)
+    }
+
+    /// The crate followed by the modules making up this name.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split(MODULE_SEPARATOR)
+    }
+
     /// Extract the crate name from a give path.
     pub fn find_crate_name(path: &Path) -> Option<Self> {
         let file_name_to_crate_name = |file_name: &str| file_name.replace('-', "_");
@@ -96,6 +121,7 @@ pub type ScopedCrateTypes<'a> = BTreeMap<SortedCrateNames<'a>, SortedTypeNames<'
 /// All supported programming languages.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SupportedLanguage {
     Go,
     Kotlin,
@@ -123,6 +149,45 @@ impl SupportedLanguage {
             SupportedLanguage::Python => "py",
         }
     }
+
+    /// The name of the file the types of a crate are written to, when each
+    /// crate is written to its own file. The files of a crate's modules are in
+    /// a folder named like the crate's file, mirroring the module tree.
+    pub fn output_file_name(&self, crate_name: &CrateName) -> String {
+        let extension = self.language_extension();
+
+        let snake_case = |name: &str| name.to_owned();
+        let pascal_case = |name: &str| name.to_string().to_pascal_case();
+        let case = match self {
+            SupportedLanguage::Go => snake_case,
+            SupportedLanguage::Kotlin => snake_case,
+            SupportedLanguage::Scala => snake_case,
+            SupportedLanguage::Swift => pascal_case,
+            SupportedLanguage::TypeScript => snake_case,
+            SupportedLanguage::Python => snake_case,
+        };
+
+        let path = crate_name.segments().map(case).join("/");
+        format!("{path}.{extension}")
+    }
+}
+
+/// Collect all the typeshared types into a mapping of crate names to typeshared types. This
+/// mapping is used to lookup and generated import statements for generated files.
+pub fn all_types(file_mappings: &mut BTreeMap<CrateName, ParsedData>) -> CrateTypes {
+    file_mappings
+        .iter_mut()
+        .map(|(crate_name, parsed_data)| (crate_name, mem::take(&mut parsed_data.type_names)))
+        .fold(
+            HashMap::new(),
+            |mut import_map: CrateTypes, (crate_name, type_names)| {
+                import_map
+                    .entry(crate_name.clone())
+                    .or_default()
+                    .extend(type_names);
+                import_map
+            },
+        )
 }
 
 impl FromStr for SupportedLanguage {
@@ -211,9 +276,21 @@ pub trait Language {
     ) -> Result<String, RustTypeFormatError> {
         match ty {
             RustType::Simple { id } => self.format_simple_type(id, generic_types),
+            RustType::Special(special @ SpecialRustType::Pointer { .. }) => {
+                self.format_transparent_type(special, generic_types)
+            }
             RustType::Generic { id, parameters } => {
                 self.format_generic_type(id, parameters.as_slice(), generic_types)
             }
+            // Mapped well-known types are formatted like any other mapped type
+            RustType::Special(special)
+                if special.is_well_known() && self.type_map().contains_key(special.id()) =>
+            {
+                self.format_simple_type(&special.id().to_owned(), generic_types)
+            }
+            RustType::Special(special @ SpecialRustType::Bytes) => {
+                self.format_transparent_type(special, generic_types)
+            }
             RustType::Special(special) => self.format_special_type(special, generic_types),
         }
     }
@@ -272,12 +349,33 @@ pub trait Language {
     }
 
     /// Format a base type that is classified as a SpecialRustType.
+    ///
+    /// Smart pointers and byte buffers can be passed on to
+    /// [`Language::format_transparent_type`].
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError>;
 
+    /// Format a special type as the type it serializes as: a smart pointer as the
+    /// type it points to and a byte buffer as `Vec<u8>`. Every other type is passed
+    /// on to [`Language::format_special_type`].
+    fn format_transparent_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        match special_ty {
+            SpecialRustType::Pointer { inner, .. } => self.format_type(inner, generic_types),
+            SpecialRustType::Bytes => self.format_special_type(
+                &SpecialRustType::Vec(RustType::Special(SpecialRustType::U8).into()),
+                generic_types,
+            ),
+            _ => self.format_special_type(special_ty, generic_types),
+        }
+    }
+
     /// Implementors can use this function to write a header for typeshared code
     fn begin_file(&mut self, _w: &mut dyn Write, _parsed_data: &ParsedData) -> std::io::Result<()> {
         Ok(())
@@ -408,6 +506,8 @@ pub trait Language {
                         &e.shared().id.original,
                     )],
                     decorators: e.shared().decorators.clone(),
+                    is_recursive: false,
+                    recursive_types: Default::default(),
                     is_redacted: e.shared().is_redacted,
                 },
             )?;
@@ -421,6 +521,10 @@ pub trait Language {
         Vec::new()
     }
 
+    /// Any steps before the files are generated, given the parsed data of every crate. For
+    /// example collecting information about types that other crates refer to.
+    fn pre_generation(&mut self, _crate_parsed_data: &BTreeMap<CrateName, ParsedData>) {}
+
     /// Any other final steps after modules have been generated. For example creating a new
     /// module with special types.
     fn post_generation(&self, _output_folder: &str) -> Result<(), GenerationError> {
@@ -497,7 +601,10 @@ fn used_imports<'a, 'b: 'a>(
 
 #[cfg(test)]
 mod test {
-    use crate::language::CrateName;
+    use crate::language::{
+        CrateName, Go, Kotlin, Language, Python, Scala, SupportedLanguage, Swift, TypeScript,
+    };
+    use crate::rust_types::{PointerKind, RustType, SpecialRustType};
     use std::path::Path;
 
     #[test]
@@ -505,4 +612,61 @@ mod test {
         let path = Path::new("/some/path/to/projects/core/foundation/op-proxy/src/android.rs");
         assert_eq!(Some("op_proxy".into()), CrateName::find_crate_name(path));
     }
+
+    #[test]
+    fn test_module_file_names() {
+        let crate_name = CrateName::from("my_crate");
+        let module = crate_name.module(["user_types", "account"]);
+
+        assert_eq!(module, "my_crate::user_types::account".into());
+        assert_eq!(
+            SupportedLanguage::TypeScript.output_file_name(&crate_name),
+            "my_crate.ts"
+        );
+        assert_eq!(
+            SupportedLanguage::TypeScript.output_file_name(&module),
+            "my_crate/user_types/account.ts"
+        );
+        assert_eq!(
+            SupportedLanguage::Swift.output_file_name(&module),
+            "MyCrate/UserTypes/Account.swift"
+        );
+    }
+
+    #[test]
+    fn special_types_format_transparent_types() {
+        let pointer = SpecialRustType::Pointer {
+            kind: PointerKind::Box,
+            inner: RustType::Special(SpecialRustType::String).into(),
+        };
+        let bytes_as_list = |language: &mut dyn Language| {
+            language.format_special_type(
+                &SpecialRustType::Vec(RustType::Special(SpecialRustType::U8).into()),
+                &[],
+            )
+        };
+        let languages: [Box<dyn Language>; 6] = [
+            Box::<TypeScript>::default(),
+            Box::<Swift>::default(),
+            Box::<Kotlin>::default(),
+            Box::<Scala>::default(),
+            Box::<Go>::default(),
+            Box::<Python>::default(),
+        ];
+
+        for mut language in languages {
+            assert_eq!(
+                language.format_special_type(&pointer, &[]).unwrap(),
+                language
+                    .format_special_type(&SpecialRustType::String, &[])
+                    .unwrap()
+            );
+            assert_eq!(
+                language
+                    .format_special_type(&SpecialRustType::Bytes, &[])
+                    .unwrap(),
+                bytes_as_list(language.as_mut()).unwrap()
+            );
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use crate::language::SupportedLanguage;
 use crate::parser::ParsedData;
-use crate::rust_types::{RustEnumShared, RustItem, RustType, RustTypeFormatError, SpecialRustType};
+use crate::rust_types::{
+    RustDefaultValue, RustEnumShared, RustItem, RustType, RustTypeFormatError, SpecialRustType,
+};
 use crate::topsort::topsort;
 use crate::RenameExt;
 use crate::{
@@ -19,14 +21,11 @@ use super::CrateTypes;
 use convert_case::{Case, Casing};
 use itertools::Itertools;
 
-// Utility function from the original author of supporting Python
-// Since we won't be supporting generics right now, this function is unused and is left here for future reference
 // Collect unique type vars from an enum field
 // Since we explode enums into unions of types, we need to extract all of the generics
 // used by each individual field
 // We do this by exploring each field's type and comparing against the generics used by the enum
 // itself
-#[allow(dead_code)]
 fn collect_generics_for_variant(variant_type: &RustType, generics: &[String]) -> Vec<String> {
     let mut all = vec![];
     match variant_type {
@@ -49,10 +48,16 @@ fn collect_generics_for_variant(variant_type: &RustType, generics: &[String]) ->
                 all.extend(collect_generics_for_variant(key_type, generics));
                 all.extend(collect_generics_for_variant(value_type, generics));
             }
-            SpecialRustType::Option(some_type) => {
+            SpecialRustType::Option(some_type)
+            | SpecialRustType::Pointer {
+                inner: some_type, ..
+            } => {
                 all.extend(collect_generics_for_variant(some_type, generics));
             }
-            SpecialRustType::Vec(value_type) => {
+            SpecialRustType::Vec(value_type)
+            | SpecialRustType::Array(value_type, _)
+            | SpecialRustType::ConstLenArray(value_type, _)
+            | SpecialRustType::Slice(value_type) => {
                 all.extend(collect_generics_for_variant(value_type, generics));
             }
             _ => {}
@@ -64,6 +69,62 @@ fn collect_generics_for_variant(variant_type: &RustType, generics: &[String]) ->
     all
 }
 
+/// Collect the type vars used by an enum variant, in the same order that
+/// the generated variant classes declare them.
+fn generics_for_variant(variant: &RustEnumVariant, generics: &[String]) -> Vec<String> {
+    match variant {
+        RustEnumVariant::Unit(_) => Vec::new(),
+        RustEnumVariant::Tuple { ty, .. } => collect_generics_for_variant(ty, generics),
+        // Mirrors the ordering used by `write_types_for_anonymous_structs`
+        RustEnumVariant::AnonymousStruct { fields, .. } => fields
+            .iter()
+            .flat_map(|field| generics.iter().filter(|g| field.ty.contains_type(g)))
+            .unique()
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Whether the key of a field can be missing from a dictionary. serde writes
+/// `None` as `null` unless the field is skipped, but a missing field with a
+/// default is still accepted.
+fn can_be_missing(field: &RustField) -> bool {
+    field.skip_serializing_if.is_some() || (field.has_default && !field.ty.is_optional())
+}
+
+/// Format a list of type vars as subscript parameters, e.g. `[T, U]`.
+fn format_type_vars(type_vars: &[String]) -> String {
+    if type_vars.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", type_vars.join(", "))
+    }
+}
+
+/// The python literal a unit enum variant is serialized as: its discriminant for
+/// `serde_repr` enums, otherwise its quoted name.
+fn enum_value(shared: &RustEnumShared, variant: &RustEnumVariant) -> String {
+    match variant
+        .shared()
+        .discriminant
+        .filter(|_| shared.is_numeric())
+    {
+        Some(discriminant) => discriminant.to_string(),
+        None => format!("\"{}\"", variant.shared().id.renamed.replace('"', "\\\"")),
+    }
+}
+
+/// A Python literal for a field's default value.
+fn default_literal(value: &RustDefaultValue) -> String {
+    match value {
+        RustDefaultValue::Bool(true) => "True".to_string(),
+        RustDefaultValue::Bool(false) => "False".to_string(),
+        RustDefaultValue::Int(int) => int.to_string(),
+        RustDefaultValue::Float(float) => format!("{float:?}"),
+        RustDefaultValue::String(s) => format!("{s:?}"),
+    }
+}
+
 fn dedup<T: Eq + Hash + Clone>(v: &mut Vec<T>) {
     // note the Copy constraint
     let mut uniques = HashSet::new();
@@ -78,6 +139,26 @@ struct CustomJsonTranslationFunctions {
     deserialization_content: String,
 }
 
+/// The kind of Python classes generated for Rust types.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum), value(rename_all = "lower"))]
+pub enum PythonStyle {
+    /// `pydantic.BaseModel` subclasses.
+    #[default]
+    Pydantic,
+    /// Standard library `dataclasses.dataclass` classes.
+    Dataclass,
+    /// Standard library `typing.TypedDict` definitions keyed by the serialized names.
+    TypedDict,
+    /// `msgspec.Struct` subclasses.
+    Msgspec,
+}
+
 /// All information needed to generate Python type-code
 #[derive(Default)]
 pub struct Python {
@@ -95,6 +176,8 @@ pub struct Python {
     pub no_version_header: bool,
     /// Carries the unique set of types for custom json translation
     pub types_for_custom_json_translation: HashSet<String>,
+    /// The kind of classes to generate.
+    pub style: PythonStyle,
 }
 
 impl Language for Python {
@@ -216,7 +299,8 @@ impl Language for Python {
         match special_ty {
             SpecialRustType::Array(rtype, _)
             | SpecialRustType::Slice(rtype)
-            | SpecialRustType::Vec(rtype) => {
+            | SpecialRustType::Vec(rtype)
+            | SpecialRustType::ConstLenArray(rtype, _) => {
                 self.add_import("typing".to_string(), "List".to_string());
                 Ok(format!("List[{}]", self.format_type(rtype, generic_types)?))
             }
@@ -228,6 +312,9 @@ impl Language for Python {
                     self.format_type(rtype, generic_types)?
                 ))
             }
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => {
                 self.add_import("typing".to_string(), "Dict".to_string());
                 Ok(format!(
@@ -241,10 +328,36 @@ impl Language for Python {
                     self.format_type(rtype2, generic_types)?
                 ))
             }
-            SpecialRustType::DateTime => {
+            SpecialRustType::DateTime | SpecialRustType::ChronoDateTime => {
                 self.add_import("datetime".to_string(), "datetime".to_string());
                 Ok("datetime".into())
             }
+            SpecialRustType::NaiveDate => {
+                self.add_import("datetime".to_string(), "date".to_string());
+                Ok("date".into())
+            }
+            SpecialRustType::Uuid => {
+                self.add_import("uuid".to_string(), "UUID".to_string());
+                Ok("UUID".into())
+            }
+            // Only pydantic validates URLs, the other styles keep the string
+            SpecialRustType::Url if self.style == PythonStyle::Pydantic => {
+                self.add_import("pydantic.networks".to_string(), "AnyUrl".to_string());
+                Ok("AnyUrl".into())
+            }
+            SpecialRustType::Url => Ok("str".into()),
+            SpecialRustType::Decimal => {
+                self.add_import("decimal".to_string(), "Decimal".to_string());
+                Ok("Decimal".into())
+            }
+            // `{ "secs": u64, "nanos": u32 }`
+            SpecialRustType::Duration => self.format_special_type(
+                &SpecialRustType::HashMap(
+                    RustType::Special(SpecialRustType::String).into(),
+                    RustType::Special(SpecialRustType::U64).into(),
+                ),
+                generic_types,
+            ),
             SpecialRustType::Unit => Ok("None".into()),
             SpecialRustType::String | SpecialRustType::Char => Ok("str".into()),
             SpecialRustType::I8
@@ -274,21 +387,17 @@ impl Language for Python {
     }
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
+        // A module level alias that references type vars is itself generic,
+        // so it only needs the type vars to be declared.
+        ty.generic_types
+            .iter()
+            .cloned()
+            .for_each(|v| self.add_type_var(v));
         let r#type = self
             .format_type(&ty.r#type, ty.generic_types.as_slice())
             .map_err(std::io::Error::other)?;
 
-        writeln!(
-            w,
-            "{}{} = {}\n",
-            ty.id.renamed,
-            if !ty.generic_types.is_empty() {
-                format!("[{}]", ty.generic_types.join(", "))
-            } else {
-                Default::default()
-            },
-            r#type,
-        )?;
+        writeln!(w, "{} = {}\n", ty.id.renamed, r#type)?;
 
         self.write_comments(w, true, &ty.comments, 0)?;
 
@@ -313,32 +422,38 @@ impl Language for Python {
     }
 
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
-        self.add_import("pydantic".to_string(), "BaseModel".to_string());
         {
             rs.generic_types
                 .iter()
                 .cloned()
                 .for_each(|v| self.add_type_var(v))
         }
-        let bases = match rs.generic_types.is_empty() {
-            true => "BaseModel".to_string(),
-            false => {
-                self.add_import("typing".to_string(), "Generic".to_string());
-                format!("BaseModel, Generic[{}]", rs.generic_types.join(", "))
-            }
-        };
-        writeln!(w, "class {}({}):", rs.id.renamed, bases,)?;
+
+        // TypedDict keys are the serialized names, which can only use the class
+        // syntax when they are valid identifiers.
+        if self.style == PythonStyle::TypedDict
+            && !rs
+                .fields
+                .iter()
+                .all(|f| is_python_identifier(&f.id.renamed))
+        {
+            return self.write_functional_typed_dict(w, rs);
+        }
+
+        self.write_class_header(w, &rs.id.renamed, &rs.generic_types, None)?;
 
         self.write_comments(w, true, &rs.comments, 1)?;
 
-        handle_model_config(w, self, &rs.fields);
+        if self.style == PythonStyle::Pydantic {
+            handle_model_config(w, self, &rs.fields);
+        }
 
         rs.fields
             .iter()
             .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
 
         if rs.fields.is_empty() {
-            write!(w, "    pass")?
+            writeln!(w, "    pass")?
         }
         writeln!(w)
     }
@@ -353,9 +468,27 @@ impl Language for Python {
         match e {
             // Write all the unit variants out (there can only be unit variants in
             // this case)
+            // Dictionaries only ever hold the raw strings
+            RustEnum::Unit(shared)
+                if self.style == PythonStyle::TypedDict && !shared.variants.is_empty() =>
+            {
+                self.add_import("typing".to_string(), "Literal".to_string());
+                self.write_comments(w, false, &shared.comments, 0)?;
+                writeln!(
+                    w,
+                    "{} = Literal[{}]\n",
+                    shared.id.renamed,
+                    shared
+                        .variants
+                        .iter()
+                        .map(|v| enum_value(shared, v))
+                        .join(", ")
+                )?;
+            }
             RustEnum::Unit(shared) => {
                 self.add_import("enum".to_string(), "Enum".to_string());
-                writeln!(w, "class {}(str, Enum):", shared.id.renamed)?;
+                let base = if shared.is_numeric() { "int" } else { "str" };
+                writeln!(w, "class {}({base}, Enum):", shared.id.renamed)?;
                 // let comment = shared.comments.join("\n");
                 self.write_comments(w, true, &shared.comments, 1)?;
                 if shared.variants.is_empty() {
@@ -364,18 +497,54 @@ impl Language for Python {
                     shared.variants.iter().try_for_each(|v| {
                         writeln!(
                             w,
-                            "    {} = \"{}\"",
+                            "    {} = {}",
                             v.shared().id.original.to_uppercase(),
                             match v {
-                                RustEnumVariant::Unit(v) => {
-                                    v.id.renamed.replace("\"", "\\\"")
-                                }
+                                RustEnumVariant::Unit(_) => enum_value(shared, v),
                                 _ => unreachable!("Only unit variants are allowed here"),
                             }
                         )?;
                         self.write_comments(w, true, &v.shared().comments, 1)
                     })?
                 };
+                // Serde aliases only apply to the names of string enums
+                let aliased_variants = shared
+                    .variants
+                    .iter()
+                    .map(|v| v.shared())
+                    .filter(|v| !shared.is_numeric() && !v.aliases.is_empty())
+                    .collect::<Vec<_>>();
+                let catch_all = shared.catch_all_variant();
+                if catch_all.is_some() || !aliased_variants.is_empty() {
+                    writeln!(w)?;
+                    writeln!(w, "    @classmethod")?;
+                    writeln!(w, "    def _missing_(cls, value):")?;
+                    for variant in aliased_variants {
+                        let condition = match variant.aliases.as_slice() {
+                            [alias] => format!("value == {alias:?}"),
+                            aliases => format!(
+                                "value in ({})",
+                                aliases.iter().map(|a| format!("{a:?}")).join(", ")
+                            ),
+                        };
+                        writeln!(w, "        if {condition}:")?;
+                        writeln!(
+                            w,
+                            "            return cls.{}",
+                            variant.id.original.to_uppercase()
+                        )?;
+                    }
+                    // Values added by newer versions decode as the `#[serde(other)]` variant
+                    match catch_all {
+                        Some(catch_all) => writeln!(
+                            w,
+                            "        return cls.{}",
+                            catch_all.id.original.to_uppercase()
+                        )?,
+                        None => writeln!(w, "        return None")?,
+                    }
+                }
+                writeln!(w)?;
             }
             // Write all the algebraic variants out (all three variant types are possible
             // here)
@@ -409,12 +578,13 @@ impl Language for Python {
 }
 
 impl Python {
+    /// Import the Python types that a Rust type is mapped to.
     fn add_imports(&mut self, tp: &str) {
-        match tp {
-            "Url" => {
+        match self.type_mappings.get(tp).map(String::as_str) {
+            Some("AnyUrl") => {
                 self.add_import("pydantic.networks".to_string(), "AnyUrl".to_string());
             }
-            "DateTime" => {
+            Some("datetime") => {
                 self.add_import("datetime".to_string(), "datetime".to_string());
             }
             _ => {}
@@ -446,48 +616,113 @@ impl Python {
         field: &RustField,
         generic_types: &[String],
     ) -> std::io::Result<()> {
-        let is_optional = field.ty.is_optional() || field.has_default;
-        // currently, if a field has a serde default value, it must be an Option
-        let not_optional_but_default = !field.ty.is_optional() && field.has_default;
+        let default_value = field.default_value.as_ref().map(default_literal);
+        // a serde default without a known value is written as an Option
+        let not_optional_but_default =
+            !field.ty.is_optional() && field.has_default && default_value.is_none();
+        let is_optional = field.ty.is_optional() || not_optional_but_default;
+        let default = default_value.unwrap_or_else(|| "None".to_string());
         let python_type: String = match field.type_override(SupportedLanguage::Python) {
             Some(type_override) => type_override.to_owned(),
             None => self
                 .format_type(&field.ty, generic_types)
                 .map_err(std::io::Error::other)?,
         };
+
+        if self.style == PythonStyle::TypedDict {
+            // A missing key is not the same as `None` for a dictionary, so only
+            // wrap the type when the field can be left out.
+            let field_type = if can_be_missing(field) {
+                self.add_import("typing".to_string(), "NotRequired".to_string());
+                format!("NotRequired[{python_type}]")
+            } else {
+                python_type
+            };
+            writeln!(w, "    {}: {field_type}", field.id.renamed)?;
+            return self.write_comments(w, true, &field.comments, 1);
+        }
+
         let python_field_name = python_property_aware_rename(&field.id.original);
         let is_aliased = python_field_name != field.id.renamed;
-        let custom_translations = json_translation_for_type(&python_type);
-        // Adds all the required imports needed based off whether its optional ,aliased, or needs a byte translation
-        self.add_common_imports(is_optional, custom_translations.is_some(), is_aliased);
+        let custom_translations = match self.style {
+            PythonStyle::Pydantic => json_translation_for_type(&python_type),
+            _ => None,
+        };
+        if is_optional {
+            self.add_import("typing".to_string(), "Optional".to_string());
+        }
 
         let mut field_type = python_type;
 
         if not_optional_but_default {
             field_type = format!("Optional[{field_type}]");
         }
-        if let Some(custom_translation) = custom_translations {
-            self.types_for_custom_json_translation
-                .insert(field_type.clone());
-            field_type = format!(
-                "Annotated[{field_type}, BeforeValidator({}), PlainSerializer({})]",
-                custom_translation.deserialization_name, custom_translation.serialization_name
-            );
-        }
 
-        let mut decorators: Vec<String> = Vec::new();
-        if is_aliased {
-            decorators.push(format!("alias=\"{}\"", field.id.renamed));
-        }
+        let python_return_value = match self.style {
+            PythonStyle::Pydantic => {
+                // Adds all the required imports needed based off whether its optional ,aliased, or needs a byte translation
+                self.add_common_imports(
+                    is_optional,
+                    custom_translations.is_some(),
+                    is_aliased || !field.aliases.is_empty(),
+                );
+
+                if let Some(custom_translation) = custom_translations {
+                    self.types_for_custom_json_translation
+                        .insert(field_type.clone());
+                    field_type = format!(
+                        "Annotated[{field_type}, BeforeValidator({}), PlainSerializer({})]",
+                        custom_translation.deserialization_name,
+                        custom_translation.serialization_name
+                    );
+                }
 
-        if is_optional || not_optional_but_default {
-            decorators.push("default=None".to_string());
-        }
+                let mut decorators: Vec<String> = Vec::new();
+                if is_aliased {
+                    decorators.push(format!("alias=\"{}\"", field.id.renamed));
+                }
 
-        let python_return_value = if !decorators.is_empty() {
-            format!(" = Field({})", decorators.join(", "))
-        } else {
-            String::new()
+                if !field.aliases.is_empty() {
+                    // The serialized name is still accepted alongside any serde aliases
+                    self.add_import("pydantic".to_string(), "AliasChoices".to_string());
+                    let choices = std::iter::once(&field.id.renamed)
+                        .chain(&field.aliases)
+                        .map(|name| format!("\"{name}\""))
+                        .join(", ");
+                    decorators.push(format!("validation_alias=AliasChoices({choices})"));
+                }
+
+                if is_optional || field.default_value.is_some() {
+                    self.add_import("pydantic".to_string(), "Field".to_string());
+                    decorators.push(format!("default={default}"));
+                }
+
+                if !decorators.is_empty() {
+                    format!(" = Field({})", decorators.join(", "))
+                } else {
+                    String::new()
+                }
+            }
+            PythonStyle::Dataclass | PythonStyle::Msgspec => {
+                let (field_function, rename) = if self.style == PythonStyle::Dataclass {
+                    // Dataclasses have no notion of wire names, so record it
+                    // in the field metadata for serialization libraries to use.
+                    (
+                        "dataclasses.field",
+                        format!("metadata={{\"alias\": \"{}\"}}", field.id.renamed),
+                    )
+                } else {
+                    ("msgspec.field", format!("name=\"{}\"", field.id.renamed))
+                };
+                let has_default = is_optional || field.default_value.is_some();
+                match (is_aliased, has_default) {
+                    (true, true) => format!(" = {field_function}(default={default}, {rename})"),
+                    (true, false) => format!(" = {field_function}({rename})"),
+                    (false, true) => format!(" = {default}"),
+                    (false, false) => String::new(),
+                }
+            }
+            PythonStyle::TypedDict => unreachable!("TypedDict fields are written above"),
         };
 
         writeln!(
@@ -499,6 +734,102 @@ impl Python {
         Ok(())
     }
 
+    /// Write the `class` statement opening a definition, including any
+    /// decorator or base classes the configured style requires.
+    ///
+    /// `tag` is the `(tag_field, tag)` pair for a variant of a tagged `msgspec` union.
+    fn write_class_header(
+        &mut self,
+        w: &mut dyn Write,
+        class_name: &str,
+        generic_types: &[String],
+        tag: Option<(&str, &str)>,
+    ) -> std::io::Result<()> {
+        let mut bases = Vec::new();
+        match self.style {
+            PythonStyle::Pydantic => {
+                self.add_import("pydantic".to_string(), "BaseModel".to_string());
+                bases.push("BaseModel".to_string());
+            }
+            PythonStyle::Dataclass => {
+                self.add_module_import("dataclasses".to_string());
+                // Keyword only fields let defaulted fields come before required ones.
+                writeln!(w, "@dataclasses.dataclass(kw_only=True)")?;
+            }
+            PythonStyle::TypedDict => {
+                self.add_import("typing".to_string(), "TypedDict".to_string());
+                bases.push("TypedDict".to_string());
+            }
+            PythonStyle::Msgspec => {
+                self.add_module_import("msgspec".to_string());
+                bases.push("msgspec.Struct".to_string());
+            }
+        }
+        if !generic_types.is_empty() {
+            self.add_import("typing".to_string(), "Generic".to_string());
+            bases.push(format!("Generic[{}]", generic_types.join(", ")));
+        }
+        if self.style == PythonStyle::Msgspec {
+            match tag {
+                Some((tag_field, tag)) => {
+                    bases.push(format!("tag_field=\"{tag_field}\""));
+                    bases.push(format!("tag=\"{tag}\""));
+                }
+                None => bases.push("kw_only=True".to_string()),
+            }
+        }
+
+        if bases.is_empty() {
+            writeln!(w, "class {class_name}:")
+        } else {
+            writeln!(w, "class {class_name}({}):", bases.join(", "))
+        }
+    }
+
+    /// Write a `TypedDict` using the functional syntax, which allows keys that
+    /// are not valid Python identifiers.
+    fn write_functional_typed_dict(
+        &mut self,
+        w: &mut dyn Write,
+        rs: &RustStruct,
+    ) -> std::io::Result<()> {
+        self.add_import("typing".to_string(), "TypedDict".to_string());
+        self.write_comments(w, false, &rs.comments, 0)?;
+
+        let mut entries = Vec::with_capacity(rs.fields.len());
+        for field in &rs.fields {
+            let mut field_type = match field.type_override(SupportedLanguage::Python) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&field.ty, &rs.generic_types)
+                    .map_err(std::io::Error::other)?,
+            };
+            if can_be_missing(field) {
+                self.add_import("typing".to_string(), "NotRequired".to_string());
+                field_type = format!("NotRequired[{field_type}]");
+            }
+            entries.push(format!("\"{}\": {field_type}", field.id.renamed));
+        }
+
+        let name = &rs.id.renamed;
+        if rs.fields.iter().all(|field| field.comments.is_empty()) {
+            return writeln!(
+                w,
+                "{name} = TypedDict(\"{name}\", {{{}}})\n",
+                entries.join(", ")
+            );
+        }
+
+        // Field docstrings can't go inside of the dictionary, so the entries
+        // are written on their own lines below their comments.
+        writeln!(w, "{name} = TypedDict(\"{name}\", {{")?;
+        for (field, entry) in rs.fields.iter().zip(entries) {
+            self.write_comments(w, false, &field.comments, 1)?;
+            writeln!(w, "    {entry},")?;
+        }
+        writeln!(w, "}})\n")
+    }
+
     fn write_comments(
         &self,
         w: &mut dyn Write,
@@ -538,6 +869,12 @@ impl Python {
         self.imports.entry(module).or_default().insert(identifier);
     }
 
+    // Idempotently insert a plain `import module`, used where qualified names
+    // avoid clashing with generated field names.
+    fn add_module_import(&mut self, module: String) {
+        self.imports.entry(module).or_default();
+    }
+
     fn add_type_var(&mut self, name: String) {
         self.add_import("typing".to_string(), "TypeVar".to_string());
         self.type_variables.insert(name);
@@ -552,6 +889,10 @@ impl Python {
             .collect();
         let mut imports = vec![];
         for (import_module, identifiers) in &self.imports {
+            if identifiers.is_empty() {
+                imports.push(format!("import {import_module}"));
+                continue;
+            }
             let mut identifier_vec = identifiers.iter().cloned().collect::<Vec<String>>();
             identifier_vec.sort();
             imports.push(format!(
@@ -576,38 +917,58 @@ impl Python {
     fn write_variant_class(
         &mut self,
         class_name: &str,
+        generic_types: &[String],
         tag_key: &str,
         tag_value: &str,
+        tag_member: &str,
         content_key: &str,
         content_type: Option<&str>,
-        content_value: Option<&str>,
         comments: &[String],
         w: &mut dyn Write,
     ) -> std::io::Result<()> {
-        self.add_import("typing".to_string(), "Literal".to_string());
-        writeln!(w, "class {class_name}(BaseModel):")?;
-        self.write_comments(w, true, comments, 1)?;
-
-        writeln!(w, "    {tag_key}: Literal[{tag_value}] = {tag_value}",)?;
-        if content_type.is_none() && content_value.is_none() {
-            return Ok(());
-        }
-        writeln!(
-            w,
-            "    {content_key}{}{}",
-            if let Some(content_type) = content_type {
-                format!(": {content_type}")
-            } else {
-                "".to_string()
-            },
-            if let Some(content_value) = content_value {
-                format!(" = {content_value}")
-            } else {
-                "".to_string()
+        let tag_value = tag_value.replace('"', "\\\"");
+        match self.style {
+            PythonStyle::Pydantic | PythonStyle::Dataclass => {
+                self.add_import("typing".to_string(), "Literal".to_string());
+                self.write_class_header(w, class_name, generic_types, None)?;
+                self.write_comments(w, true, comments, 1)?;
+                writeln!(w, "    {tag_key}: Literal[{tag_member}] = {tag_member}",)?;
             }
-        )?;
+            PythonStyle::TypedDict
+                if !is_python_identifier(tag_key) || !is_python_identifier(content_key) =>
+            {
+                self.add_import("typing".to_string(), "Literal".to_string());
+                self.add_import("typing".to_string(), "TypedDict".to_string());
+                self.write_comments(w, false, comments, 0)?;
+                let content = content_type
+                    .map(|content_type| format!(", \"{content_key}\": {content_type}"))
+                    .unwrap_or_default();
+                return writeln!(
+                    w,
+                    "{class_name} = TypedDict(\"{class_name}\", {{\"{tag_key}\": Literal[\"{tag_value}\"]{content}}})"
+                );
+            }
+            PythonStyle::TypedDict => {
+                self.add_import("typing".to_string(), "Literal".to_string());
+                self.write_class_header(w, class_name, generic_types, None)?;
+                self.write_comments(w, true, comments, 1)?;
+                writeln!(w, "    {tag_key}: Literal[\"{tag_value}\"]")?;
+            }
+            // msgspec writes the tag itself when the union members are tagged.
+            PythonStyle::Msgspec => {
+                self.write_class_header(w, class_name, generic_types, Some((tag_key, &tag_value)))?;
+                self.write_comments(w, true, comments, 1)?;
+                if content_type.is_none() && comments.is_empty() {
+                    writeln!(w, "    pass")?;
+                }
+            }
+        }
+        if let Some(content_type) = content_type {
+            writeln!(w, "    {content_key}: {content_type}")?;
+        }
         Ok(())
     }
+
     fn write_algebraic_enum(
         &mut self,
         tag_key: &str,
@@ -622,7 +983,9 @@ impl Python {
             .iter()
             .cloned()
             .for_each(|v| self.add_type_var(v));
-        self.add_import("pydantic".to_string(), "BaseModel".to_string());
+        if self.style == PythonStyle::Pydantic {
+            self.add_import("pydantic".to_string(), "BaseModel".to_string());
+        }
         // all the types and class names for the enum variants in tuple
         // (type_name, class_name)
         let all_enum_variants_name = shared
@@ -636,38 +999,46 @@ impl Python {
             .map(|name| (name.to_case(Case::Snake).to_uppercase(), name))
             .collect::<Vec<(String, String)>>();
         let enum_type_class_name = format!("{}Types", shared.id.renamed);
-        self.add_import("enum".to_string(), "Enum".to_string());
-        // write "types" class: a union of all the enum variants
-        writeln!(w, "class {enum_type_class_name}(str, Enum):")?;
-        writeln!(
-            w,
-            "{}",
-            all_enum_variants_name
-                .iter()
-                .map(|(type_key_name, type_string)| format!(
-                    "    {type_key_name} = \"{type_string}\""
-                ))
-                .collect::<Vec<String>>()
-                .join("\n")
-        )?;
-        writeln!(w)?;
+        // Only class based styles compare the tag against enum members.
+        if matches!(self.style, PythonStyle::Pydantic | PythonStyle::Dataclass) {
+            self.add_import("enum".to_string(), "Enum".to_string());
+            // write "types" class: a union of all the enum variants
+            writeln!(w, "class {enum_type_class_name}(str, Enum):")?;
+            writeln!(
+                w,
+                "{}",
+                all_enum_variants_name
+                    .iter()
+                    .map(|(type_key_name, type_string)| format!(
+                        "    {type_key_name} = \"{type_string}\""
+                    ))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            )?;
+            writeln!(w)?;
+        }
 
         let mut union_members = Vec::new();
         // write each of the enum variant as a class:
-        for (variant, (type_key_name, ..)) in
+        for (variant, (type_key_name, type_string)) in
             shared.variants.iter().zip(all_enum_variants_name.iter())
         {
             let variant_class_name = format!("{enum_name}{}", &variant.shared().id.original);
-            union_members.push(variant_class_name.clone());
+            let variant_generics = generics_for_variant(variant, &shared.generic_types);
+            union_members.push(format!(
+                "{variant_class_name}{}",
+                format_type_vars(&variant_generics)
+            ));
             match variant {
                 RustEnumVariant::Unit(variant_shared) => {
                     self.write_variant_class(
                         &variant_class_name,
+                        &variant_generics,
                         tag_key,
+                        type_string,
                         format!("{enum_type_class_name}.{type_key_name}",).as_str(),
                         content_key,
                         None,
-                        None,
                         &variant_shared.comments,
                         w,
                     )?;
@@ -682,11 +1053,12 @@ impl Python {
                         .map_err(std::io::Error::other)?;
                     self.write_variant_class(
                         &variant_class_name,
+                        &variant_generics,
                         tag_key,
+                        type_string,
                         format!("{enum_type_class_name}.{type_key_name}",).as_str(),
                         content_key,
                         Some(&tuple_name),
-                        None,
                         &variant_shared.comments,
                         w,
                     )?;
@@ -697,15 +1069,20 @@ impl Python {
                     ..
                 } => {
                     // writing is taken care of by write_types_for_anonymous_structs in write_enum
-                    let variant_class_inner_name = make_struct_name(&variant_shared.id.original);
+                    let variant_class_inner_name = format!(
+                        "{}{}",
+                        make_struct_name(&variant_shared.id.original),
+                        format_type_vars(&variant_generics)
+                    );
 
                     self.write_variant_class(
                         &variant_class_name,
+                        &variant_generics,
                         tag_key,
+                        type_string,
                         format!("{enum_type_class_name}.{type_key_name}",).as_str(),
                         content_key,
                         Some(&variant_class_inner_name),
-                        None,
                         &variant_shared.comments,
                         w,
                     )?;
@@ -715,13 +1092,28 @@ impl Python {
         }
 
         self.write_comments(w, false, &shared.comments, 0)?;
-        if union_members.len() == 1 {
-            writeln!(w, "{enum_name} = {}", union_members[0])?;
+        let union = if union_members.len() == 1 {
+            union_members.remove(0)
         } else {
             self.add_import("typing".to_string(), "Union".to_string());
-            writeln!(w, "{enum_name} = Union[{}]", union_members.join(", "))?;
+            format!("Union[{}]", union_members.join(", "))
+        };
+        if shared.generic_types.is_empty() {
+            writeln!(w, "{enum_name} = {union}\n")
+        } else {
+            // A plain `Union` takes its parameters in the order they first appear
+            // in it and can't be subscripted by pydantic, so declare the alias
+            // with the parameters in the order of the Rust enum.
+            self.add_import("typing_extensions".to_string(), "TypeAliasType".to_string());
+            let type_params = match shared.generic_types.as_slice() {
+                [type_param] => format!("({type_param},)"),
+                type_params => format!("({})", type_params.join(", ")),
+            };
+            writeln!(
+                w,
+                "{enum_name} = TypeAliasType(\"{enum_name}\", {union}, type_params={type_params})\n"
+            )
         }
-        Ok(())
     }
 }
 
@@ -742,6 +1134,14 @@ fn get_python_keywords() -> &'static HashSet<String> {
     })
 }
 
+/// Whether `name` can be used as an attribute name in generated classes.
+fn is_python_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+        && !get_python_keywords().contains(name)
+}
+
 fn python_property_aware_rename(name: &str) -> String {
     let snake_name = name.to_case(Case::Snake);
     match get_python_keywords().contains(&snake_name) {
@@ -818,7 +1218,9 @@ fn json_translation_for_type(python_type: &str) -> Option<CustomJsonTranslationF
 
 #[cfg(test)]
 mod test {
+    use crate::context::{ParseContext, ParseFileContext};
     use crate::rust_types::Id;
+    use std::process::Command;
 
     use super::*;
     #[test]
@@ -843,6 +1245,9 @@ mod test {
             has_default: true,
             comments: Default::default(),
             decorators: Default::default(),
+            aliases: Default::default(),
+            skip_serializing_if: Default::default(),
+            default_value: Default::default(),
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -867,6 +1272,9 @@ mod test {
             has_default: false,
             comments: Default::default(),
             decorators: Default::default(),
+            aliases: Default::default(),
+            skip_serializing_if: Default::default(),
+            default_value: Default::default(),
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -893,6 +1301,9 @@ mod test {
             has_default: true,
             comments: Default::default(),
             decorators: Default::default(),
+            aliases: Default::default(),
+            skip_serializing_if: Default::default(),
+            default_value: Default::default(),
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -917,8 +1328,85 @@ mod test {
             has_default: false,
             comments: Default::default(),
             decorators: Default::default(),
+            aliases: Default::default(),
+            skip_serializing_if: Default::default(),
+            default_value: Default::default(),
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(String::from_utf8_lossy(mock_writer), "    field: str\n");
     }
+
+    #[test]
+    fn generic_enums_subscript_in_declared_order() {
+        let source_code = r#"
+            #[typeshare]
+            #[serde(tag = "type", content = "content")]
+            pub enum Either<A, B> {
+                Right(B),
+                Left(A),
+            }
+
+            #[typeshare]
+            pub struct Holder {
+                either: Either<String, i32>,
+            }
+        "#;
+        let parsed_data = crate::parser::parse(
+            &ParseContext::default(),
+            ParseFileContext {
+                source_code: source_code.to_string(),
+                crate_name: "default_crate".into(),
+                file_name: "input.rs".to_string(),
+                file_path: "input.rs".into(),
+            },
+        )
+        .unwrap()
+        .unwrap();
+        let mut output = Vec::new();
+        Python::default()
+            .generate_types(&mut output, &HashMap::new(), parsed_data)
+            .unwrap();
+
+        let dir = std::env::temp_dir().join("typeshare_python_generic_enum");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("generic_enum.py"), output).unwrap();
+
+        // `Right` holds the second parameter even though it comes first in the union.
+        let script = r#"
+import sys
+try:
+    import pydantic
+except ImportError:
+    sys.exit(77)
+import generic_enum
+assert generic_enum.Either[str, int] is not None
+generic_enum.Holder.model_validate({"either": {"type": "Right", "content": 1}})
+generic_enum.Holder.model_validate({"either": {"type": "Left", "content": "a"}})
+try:
+    generic_enum.Holder.model_validate({"either": {"type": "Right", "content": "a"}})
+except pydantic.ValidationError:
+    pass
+else:
+    sys.exit("Right accepted the first parameter")
+"#;
+        let result = Command::new("python3")
+            .arg("-c")
+            .arg(script)
+            .current_dir(&dir)
+            .output();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let Ok(result) = result else {
+            eprintln!("Skipping: python3 isn't installed");
+            return;
+        };
+        if result.status.code() == Some(77) {
+            eprintln!("Skipping: pydantic isn't installed");
+            return;
+        }
+        assert!(
+            result.status.success(),
+            "{}",
+            String::from_utf8_lossy(&result.stderr)
+        );
+    }
 }
@@ -11,6 +11,22 @@ use lazy_format::lazy_format;
 use std::ops::Deref;
 use std::{collections::HashMap, io::Write};
 
+/// The Scala language version to generate code for.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum), value(rename_all = "lower"))]
+pub enum ScalaDialect {
+    /// Case classes and sealed traits inside package objects.
+    #[default]
+    Scala2,
+    /// Native `enum` definitions and top level type aliases.
+    Scala3,
+}
+
 /// All information needed for Scala type-code
 #[derive(Default)]
 pub struct Scala {
@@ -23,6 +39,10 @@ pub struct Scala {
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// The Scala language version to target.
+    pub dialect: ScalaDialect,
+    /// Whether to generate circe `Encoder` and `Decoder` instances matching the serde representation.
+    pub circe: bool,
 }
 
 impl Language for Scala {
@@ -34,8 +54,34 @@ impl Language for Scala {
     ) -> std::io::Result<()> {
         self.begin_file(writable, &data)?;
 
-        // Package object to hold type aliases: aliases must be in class or object in Scala 2)
         let unsigned_used = self.unsigned_integer_used(&data);
+
+        // Scala 3 allows type aliases at the top level of a package.
+        if self.dialect == ScalaDialect::Scala3 {
+            if unsigned_used
+                || !data.aliases.is_empty()
+                || !data.structs.is_empty()
+                || !data.enums.is_empty()
+            {
+                self.begin_package(writable)?;
+                if unsigned_used {
+                    self.write_unsigned_aliases(writable)?;
+                }
+                for a in data.aliases.iter() {
+                    self.write_type_alias(writable, a)?;
+                }
+                for s in data.structs.iter() {
+                    self.write_struct(writable, s)?;
+                }
+                for e in data.enums.iter() {
+                    self.write_enum(writable, e)?;
+                }
+                self.end_package(writable)?;
+            }
+            return self.end_file(writable);
+        }
+
+        // Package object to hold type aliases: aliases must be in class or object in Scala 2)
         if unsigned_used || !data.aliases.is_empty() {
             self.begin_package_object(writable)?;
             if unsigned_used {
@@ -77,7 +123,7 @@ impl Language for Scala {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
-            SpecialRustType::Vec(rtype) => {
+            SpecialRustType::Vec(rtype) | SpecialRustType::ConstLenArray(rtype, _) => {
                 format!("Vector[{}]", self.format_type(rtype, generic_types)?)
             }
             SpecialRustType::Array(rtype, _) => {
@@ -89,6 +135,9 @@ impl Language for Scala {
             SpecialRustType::Option(rtype) => {
                 format!("Option[{}]", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                return self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => {
                 format!(
                     "Map[{}, {}]",
@@ -112,12 +161,21 @@ impl Language for Scala {
             SpecialRustType::Bool => "Boolean".into(),
             SpecialRustType::F32 => "Float".into(),
             SpecialRustType::F64 => "Double".into(),
-            // TODO: https://github.com/1Password/typeshare/issues/237
-            SpecialRustType::DateTime => {
-                return Err(RustTypeFormatError::UnsupportedSpecialType(
-                    special_ty.to_string(),
-                ))
-            }
+            // Dates and times are kept as the RFC3339 or ISO8601 strings they serialize as
+            SpecialRustType::DateTime
+            | SpecialRustType::ChronoDateTime
+            | SpecialRustType::NaiveDate
+            | SpecialRustType::Uuid
+            | SpecialRustType::Url
+            | SpecialRustType::Decimal => "String".into(),
+            // `{ "secs": u64, "nanos": u32 }`
+            SpecialRustType::Duration => self.format_special_type(
+                &SpecialRustType::HashMap(
+                    RustType::Special(SpecialRustType::String).into(),
+                    RustType::Special(SpecialRustType::U64).into(),
+                ),
+                generic_types,
+            )?,
         })
     }
 
@@ -137,6 +195,17 @@ impl Language for Scala {
                 writeln!(w)?;
             }
         };
+        if self.circe {
+            writeln!(
+                w,
+                "import io.circe.{{Decoder, DecodingFailure, Encoder, Json}}"
+            )?;
+            match self.dialect {
+                ScalaDialect::Scala2 => writeln!(w, "import io.circe.syntax._")?,
+                ScalaDialect::Scala3 => writeln!(w, "import io.circe.syntax.*")?,
+            }
+            writeln!(w)?;
+        }
         Ok(())
     }
 
@@ -190,6 +259,12 @@ impl Language for Scala {
         } else {
             writeln!(w, "class {} extends Serializable\n", rs.id.renamed)?;
         }
+
+        if self.circe {
+            writeln!(w, "object {} {{", rs.id.renamed)?;
+            self.write_struct_codecs(w, rs)?;
+            writeln!(w, "}}\n")?;
+        }
         Ok(())
     }
 
@@ -201,6 +276,11 @@ impl Language for Scala {
 
         self.write_comments(w, 0, &e.shared().comments)?;
 
+        // Scala 3 enums need at least one case
+        if self.dialect == ScalaDialect::Scala3 && !e.shared().variants.is_empty() {
+            return self.write_scala3_enum(w, e);
+        }
+
         let generic_parameters = if !e.shared().generic_types.is_empty() {
             format!("[{}]", e.shared().generic_types.join(", "))
         } else {
@@ -228,6 +308,9 @@ impl Language for Scala {
 
         writeln!(w, "object {} {{", &e.shared().id.renamed)?;
         self.write_enum_variants(w, e)?;
+        if self.circe {
+            self.write_enum_codecs(w, e)?;
+        }
         writeln!(w, "}}\n")
     }
 
@@ -241,6 +324,281 @@ impl Language for Scala {
 }
 
 impl Scala {
+    fn write_scala3_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        let shared = e.shared();
+        let generics = generic_parameters(&shared.generic_types);
+
+        writeln!(
+            w,
+            "enum {}{generics}(val serialName: String) {{",
+            shared.id.renamed
+        )?;
+        for v in shared.variants.iter() {
+            self.write_comments(w, 1, &v.shared().comments)?;
+            let variant_name = variant_name(v);
+            let serial_name = format!("{:?}", v.shared().id.renamed);
+            let parameters = match (v, e) {
+                (RustEnumVariant::Unit(_), _) if shared.generic_types.is_empty() => {
+                    writeln!(
+                        w,
+                        "\tcase {variant_name} extends {}({serial_name})",
+                        shared.id.renamed
+                    )?;
+                    continue;
+                }
+                // Singleton cases can't refer to the enum's type parameters, so
+                // generic enums use an empty parameter list instead.
+                (RustEnumVariant::Unit(_), _) => String::new(),
+                (RustEnumVariant::Tuple { ty, .. }, RustEnum::Algebraic { content_key, .. }) => {
+                    let variant_type = self
+                        .format_type(ty, shared.generic_types.as_slice())
+                        .map_err(std::io::Error::other)?;
+                    format!("{content_key}: {variant_type}")
+                }
+                (
+                    RustEnumVariant::AnonymousStruct {
+                        shared: variant_shared,
+                        fields,
+                    },
+                    RustEnum::Algebraic { content_key, .. },
+                ) => {
+                    let inner_generics = anonymous_struct_generics(e, fields);
+                    format!(
+                        "{content_key}: {}{}Inner{}",
+                        shared.id.original,
+                        variant_shared.id.original,
+                        if inner_generics.is_empty() {
+                            String::new()
+                        } else {
+                            format!("[{}]", inner_generics.iter().join(", "))
+                        }
+                    )
+                }
+                (_, RustEnum::Unit(_)) => unreachable!("Only unit variants are allowed here"),
+            };
+            writeln!(
+                w,
+                "\tcase {variant_name}{generics}({parameters}) extends {}{generics}({serial_name})",
+                shared.id.renamed
+            )?;
+        }
+        writeln!(w, "}}\n")?;
+
+        if self.circe {
+            writeln!(w, "object {} {{", shared.id.renamed)?;
+            self.write_enum_codecs(w, e)?;
+            writeln!(w, "}}\n")?;
+        }
+        Ok(())
+    }
+
+    /// The declaration of an implicit codec instance, up to and including the `=`.
+    fn codec_declaration(
+        &self,
+        codec: &str,
+        name: &str,
+        type_name: &str,
+        generic_types: &[String],
+    ) -> String {
+        let full_type = format!("{codec}[{type_name}{}]", generic_parameters(generic_types));
+        let bounds = generic_types
+            .iter()
+            .map(|g| format!("{g}: {codec}"))
+            .join(", ");
+        match (self.dialect, generic_types.is_empty()) {
+            (ScalaDialect::Scala2, true) => format!("implicit val {name}: {full_type} ="),
+            (ScalaDialect::Scala2, false) => {
+                format!("implicit def {name}[{bounds}]: {full_type} =")
+            }
+            (ScalaDialect::Scala3, true) => format!("given {name}: {full_type} ="),
+            (ScalaDialect::Scala3, false) => format!("given {name}[{bounds}]: {full_type} ="),
+        }
+    }
+
+    /// Write circe codecs for a struct, encoding each field under its serialized name.
+    fn write_struct_codecs(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        let generic_types = rs.generic_types.as_slice();
+        let encoder = self.codec_declaration("Encoder", "encoder", &rs.id.renamed, generic_types);
+        let decoder = self.codec_declaration("Decoder", "decoder", &rs.id.renamed, generic_types);
+
+        if rs.fields.is_empty() {
+            writeln!(w, "\t{encoder} Encoder.instance(_ => Json.obj())")?;
+            writeln!(w, "\t{decoder} Decoder.const(new {})", rs.id.renamed)?;
+            return Ok(());
+        }
+
+        let names = rs
+            .fields
+            .iter()
+            .map(|f| remove_dash_from_identifier(&f.id.renamed))
+            .collect_vec();
+        // Don't let a field binding shadow the cursor
+        let mut cursor = String::from("c");
+        while names.contains(&cursor) {
+            cursor.push('_');
+        }
+
+        writeln!(w, "\t{encoder} Encoder.instance {{ value =>")?;
+        writeln!(w, "\t\tJson.obj(")?;
+        writeln!(
+            w,
+            "{}",
+            rs.fields
+                .iter()
+                .zip(&names)
+                .map(|(f, name)| format!("\t\t\t{:?} -> value.{name}.asJson", f.id.renamed))
+                .join(",\n")
+        )?;
+        writeln!(w, "\t\t)")?;
+        writeln!(w, "\t}}")?;
+
+        writeln!(w, "\t{decoder} Decoder.instance {{ {cursor} =>")?;
+        writeln!(w, "\t\tfor {{")?;
+        for (f, name) in rs.fields.iter().zip(&names) {
+            let ty = match f.type_override(SupportedLanguage::Scala) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&f.ty, generic_types)
+                    .map_err(std::io::Error::other)?,
+            };
+            writeln!(
+                w,
+                "\t\t\t{name} <- {cursor}.downField({:?}).as[{ty}]",
+                f.id.renamed
+            )?;
+        }
+        writeln!(w, "\t\t}} yield {}({})", rs.id.renamed, names.join(", "))?;
+        writeln!(w, "\t}}")
+    }
+
+    /// Write circe codecs for an enum. Unit enums are encoded as their serialized
+    /// name, and algebraic enums use the serde tag and content keys.
+    fn write_enum_codecs(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        let shared = e.shared();
+        let enum_name = &shared.id.renamed;
+        let generic_types = shared.generic_types.as_slice();
+        let generics = generic_parameters(generic_types);
+        let encoder = self.codec_declaration("Encoder", "encoder", enum_name, generic_types);
+        let decoder = self.codec_declaration("Decoder", "decoder", enum_name, generic_types);
+
+        // Scala 3 cases of generic enums take an empty parameter list
+        let dialect = self.dialect;
+        let is_unit_case_object = |v: &RustEnumVariant| {
+            matches!(v, RustEnumVariant::Unit(_))
+                && (dialect == ScalaDialect::Scala2 || generic_types.is_empty())
+        };
+
+        match e {
+            RustEnum::Unit(shared) => {
+                writeln!(
+                    w,
+                    "\t{encoder} Encoder.encodeString.contramap(_.serialName)"
+                )?;
+                writeln!(w, "\t{decoder} Decoder.decodeString.emap {{")?;
+                for v in shared.variants.iter() {
+                    writeln!(
+                        w,
+                        "\t\tcase {:?} => Right({enum_name}.{})",
+                        v.shared().id.renamed,
+                        variant_name(v)
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "\t\tcase other => Left(s\"Unknown {enum_name} variant: $other\")"
+                )?;
+                writeln!(w, "\t}}")?;
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                writeln!(w, "\t{encoder} Encoder.instance {{")?;
+                for v in shared.variants.iter() {
+                    let variant_name = variant_name(v);
+                    let tag = format!(
+                        "{tag_key:?} -> Json.fromString({:?})",
+                        v.shared().id.renamed
+                    );
+                    match v {
+                        RustEnumVariant::Unit(_) if is_unit_case_object(v) => writeln!(
+                            w,
+                            "\t\tcase {enum_name}.{variant_name} => Json.obj({tag})"
+                        )?,
+                        RustEnumVariant::Unit(_) => writeln!(
+                            w,
+                            "\t\tcase {enum_name}.{variant_name}() => Json.obj({tag})"
+                        )?,
+                        RustEnumVariant::Tuple { .. } | RustEnumVariant::AnonymousStruct { .. } => {
+                            writeln!(
+                                w,
+                                "\t\tcase {enum_name}.{variant_name}(content) => Json.obj({tag}, {content_key:?} -> content.asJson)"
+                            )?
+                        }
+                    }
+                }
+                writeln!(w, "\t}}")?;
+
+                writeln!(w, "\t{decoder} Decoder.instance {{ c =>")?;
+                writeln!(
+                    w,
+                    "\t\tc.downField({tag_key:?}).as(Decoder.decodeString).flatMap {{"
+                )?;
+                for v in shared.variants.iter() {
+                    let variant_name = variant_name(v);
+                    let serial_name = format!("{:?}", v.shared().id.renamed);
+                    let content_type = match v {
+                        RustEnumVariant::Unit(_) if is_unit_case_object(v) => {
+                            writeln!(
+                                w,
+                                "\t\t\tcase {serial_name} => Right({enum_name}.{variant_name})"
+                            )?;
+                            continue;
+                        }
+                        RustEnumVariant::Unit(_) => {
+                            writeln!(
+                                w,
+                                "\t\t\tcase {serial_name} => Right({enum_name}.{variant_name}{generics}())"
+                            )?;
+                            continue;
+                        }
+                        RustEnumVariant::Tuple { ty, .. } => self
+                            .format_type(ty, generic_types)
+                            .map_err(std::io::Error::other)?,
+                        RustEnumVariant::AnonymousStruct {
+                            shared: variant_shared,
+                            fields,
+                        } => {
+                            let inner_generics = anonymous_struct_generics(e, fields);
+                            format!(
+                                "{}{}Inner{}",
+                                shared.id.original,
+                                variant_shared.id.original,
+                                if inner_generics.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("[{}]", inner_generics.iter().join(", "))
+                                }
+                            )
+                        }
+                    };
+                    writeln!(
+                        w,
+                        "\t\t\tcase {serial_name} => c.downField({content_key:?}).as[{content_type}].map({enum_name}.{variant_name}{generics}(_))"
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "\t\t\tcase other => Left(DecodingFailure(s\"Unknown {enum_name} variant: $other\", c.history))"
+                )?;
+                writeln!(w, "\t\t}}")?;
+                writeln!(w, "\t}}")?;
+            }
+        }
+        Ok(())
+    }
+
     fn write_enum_variants(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
         match e {
             RustEnum::Unit(shared) => {
@@ -269,22 +627,7 @@ impl Scala {
                     let printed_value = format!(r##"{:?}"##, &v.shared().id.renamed);
                     self.write_comments(w, 1, &v.shared().comments)?;
 
-                    let variant_name = {
-                        let mut variant_name = v.shared().id.original.to_string();
-
-                        if variant_name
-                            .chars()
-                            .next()
-                            .map(|c| c.is_ascii_digit())
-                            .unwrap_or(false)
-                        {
-                            // If the name starts with a digit just add an underscore
-                            // to the front and make it valid
-                            variant_name = format!("_{variant_name}");
-                        }
-
-                        variant_name
-                    };
+                    let variant_name = variant_name(v);
 
                     match v {
                         RustEnumVariant::Unit(_) => {
@@ -320,19 +663,7 @@ impl Scala {
                                 }
                             )?;
 
-                            // Builds the list of generic types (e.g [T, U, V]), by digging
-                            // through the fields recursively and comparing against the
-                            // enclosing enum's list of generic parameters.
-                            let generics = fields
-                                .iter()
-                                .flat_map(|field| {
-                                    e.shared()
-                                        .generic_types
-                                        .iter()
-                                        .filter(|g| field.ty.contains_type(g))
-                                })
-                                .unique()
-                                .collect_vec();
+                            let generics = anonymous_struct_generics(e, fields);
 
                             // Sadly the parenthesis are required because of macro limitations
                             let generics = lazy_format!(match (generics.is_empty()) {
@@ -483,15 +814,15 @@ impl Scala {
             .collect_vec();
         itertools::concat(vec![types_in_aliases, types_in_structs, types_in_enum])
             .iter()
-            .flat_map(|ty| match ty {
+            .flat_map(|ty| match ty.unwrap_pointers() {
                 RustType::Generic { id: _, parameters } => parameters.clone(),
                 RustType::Special(SpecialRustType::Option(ty) | SpecialRustType::Vec(ty)) => {
-                    vec![ty.deref().clone()]
+                    vec![ty.unwrap_pointers().clone()]
                 }
                 RustType::Special(SpecialRustType::HashMap(kty, vty)) => {
                     vec![kty.deref().clone(), vty.deref().clone()]
                 }
-                RustType::Special(_) => vec![ty.clone()],
+                RustType::Special(_) => vec![ty.unwrap_pointers().clone()],
                 RustType::Simple { .. } => vec![],
             })
             .any(|ty| {
@@ -509,3 +840,46 @@ impl Scala {
             })
     }
 }
+
+/// The Scala identifier for an enum variant.
+fn variant_name(v: &RustEnumVariant) -> String {
+    let variant_name = v.shared().id.original.to_string();
+
+    if variant_name
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_digit())
+        .unwrap_or(false)
+    {
+        // If the name starts with a digit just add an underscore
+        // to the front and make it valid
+        format!("_{variant_name}")
+    } else {
+        variant_name
+    }
+}
+
+/// Builds the list of generic types (e.g [T, U, V]) used by an anonymous struct
+/// variant, by digging through the fields recursively and comparing against the
+/// enclosing enum's list of generic parameters.
+fn anonymous_struct_generics<'a>(e: &'a RustEnum, fields: &[RustField]) -> Vec<&'a String> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            e.shared()
+                .generic_types
+                .iter()
+                .filter(|g| field.ty.contains_type(g))
+        })
+        .unique()
+        .collect_vec()
+}
+
+/// Format generic parameters as `[A, B]`, or nothing when there are none.
+fn generic_parameters(generic_types: &[String]) -> String {
+    if generic_types.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", generic_types.join(", "))
+    }
+}
@@ -4,8 +4,8 @@ use crate::{
     parser::{remove_dash_from_identifier, DecoratorKind, ParsedData},
     rename::RenameExt,
     rust_types::{
-        DecoratorMap, RustConst, RustEnum, RustEnumVariant, RustStruct, RustTypeAlias,
-        RustTypeFormatError, SpecialRustType,
+        DecoratorMap, RustConst, RustDefaultValue, RustEnum, RustEnumVariant, RustStruct,
+        RustTypeAlias, RustTypeFormatError, SpecialRustType,
     },
 };
 use itertools::{Either, Itertools};
@@ -87,6 +87,8 @@ struct CodingKeysInfo {
     decoding_cases: Vec<String>,
     encoding_cases: Vec<String>,
     coding_keys: Vec<String>,
+    /// The case unrecognized raw values or tags decode to, from `#[serde(other)]`.
+    catch_all: Option<String>,
 }
 
 /// A container for generic constraints.
@@ -147,6 +149,14 @@ pub struct Swift {
     /// This will add a definition of a `CodableVoid` type to the generated Swift code and
     /// use `CodableVoid` to replace `()`.
     pub should_emit_codable_void: AtomicBool,
+    /// Will be set to true if one of your typeshared Rust structs references itself.
+    /// This will add a definition of an `Indirect` property wrapper to the generated Swift code
+    /// and use it to box the recursive fields.
+    pub should_emit_indirect: AtomicBool,
+    /// Will be set to true if one of your typeshared Rust types contains a `Duration`.
+    /// This will add a definition of a `RustDuration` struct with its `secs` and `nanos`
+    /// to the generated Swift code.
+    pub should_emit_duration: AtomicBool,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -181,7 +191,9 @@ impl Language for Swift {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
-            SpecialRustType::Vec(rtype) => format!("[{}]", self.format_type(rtype, generic_types)?),
+            SpecialRustType::Vec(rtype) | SpecialRustType::ConstLenArray(rtype, _) => {
+                format!("[{}]", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Array(rtype, _) => {
                 format!("[{}]", self.format_type(rtype, generic_types)?)
             }
@@ -191,6 +203,9 @@ impl Language for Swift {
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                return self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => format!(
                 "[{}: {}]",
                 self.format_type(rtype1, generic_types)?,
@@ -215,11 +230,18 @@ impl Language for Swift {
             SpecialRustType::Bool => "Bool".into(),
             SpecialRustType::F32 => "Float".into(),
             SpecialRustType::F64 => "Double".into(),
-            // TODO: https://github.com/1Password/typeshare/issues/237
-            SpecialRustType::DateTime => {
-                return Err(RustTypeFormatError::UnsupportedSpecialType(
-                    special_ty.to_string(),
-                ))
+            SpecialRustType::Uuid => "UUID".into(),
+            SpecialRustType::Url => "URL".into(),
+            // Dates and times are kept as the RFC3339 or ISO8601 strings they serialize as,
+            // since `JSONDecoder` doesn't decode them as a `Date` by default. Swift's
+            // `Decimal` is decoded from a number, not a string
+            SpecialRustType::DateTime
+            | SpecialRustType::ChronoDateTime
+            | SpecialRustType::NaiveDate
+            | SpecialRustType::Decimal => "String".into(),
+            SpecialRustType::Duration => {
+                self.should_emit_duration.store(true, Ordering::SeqCst);
+                "RustDuration".into()
             }
         })
     }
@@ -236,7 +258,7 @@ impl Language for Swift {
     }
 
     fn end_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
-        if self.should_emit_codable_void.load(Ordering::SeqCst) && !self.multi_file {
+        if self.should_emit_support_types() && !self.multi_file {
             self.write_codable(w, &self.get_codable_contents())?;
         }
 
@@ -335,19 +357,34 @@ impl Language for Swift {
                 ));
             }
 
-            let case_type: String = match f.type_override(SupportedLanguage::Swift) {
+            let type_override = f.type_override(SupportedLanguage::Swift);
+            let case_type: String = match type_override {
                 Some(type_override) => type_override.to_owned(),
                 None => self
                     .format_type(&f.ty, rs.generic_types.as_slice())
                     .map_err(io::Error::other)?,
             };
 
+            // Structs are value types in Swift, so a field that would store the struct
+            // inline has to be boxed to give the type a finite size.
+            let declaration = if type_override.is_none()
+                && rs
+                    .recursive_types
+                    .iter()
+                    .any(|name| f.ty.stores_inline(name))
+            {
+                self.should_emit_indirect.store(true, Ordering::SeqCst);
+                "@Indirect public var"
+            } else {
+                "public let"
+            };
+
             writeln!(
                 w,
-                "\tpublic let {}: {}{}",
+                "\t{declaration} {}: {}{}",
                 remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref()),
                 case_type,
-                if f.has_default && !f.ty.is_optional() {
+                if f.has_default && f.default_value.is_none() && !f.ty.is_optional() {
                     "?"
                 } else {
                     Default::default()
@@ -355,7 +392,12 @@ impl Language for Swift {
             )?;
         }
 
-        if should_write_coding_keys {
+        // Decoding serde aliases or default values needs a custom initializer which refers
+        // to the coding keys
+        let has_aliases = rs.fields.iter().any(|f| !f.aliases.is_empty());
+        let has_default_values = rs.fields.iter().any(|f| f.default_value.is_some());
+
+        if should_write_coding_keys || has_aliases || has_default_values {
             writeln!(
                 w,
                 r#"
@@ -366,6 +408,24 @@ impl Language for Swift {
             )?;
         }
 
+        if has_aliases {
+            let alias_keys = rs
+                .fields
+                .iter()
+                .flat_map(|f| &f.aliases)
+                .unique()
+                .map(|alias| format!(r##"{} = "{alias}""##, alias_key_name(alias)))
+                .collect_vec();
+            writeln!(
+                w,
+                r#"
+	enum AliasCodingKeys: String, CodingKey {{
+		case {}
+	}}"#,
+                alias_keys.join(",\n\t\t\t"),
+            )?;
+        }
+
         if !rs.fields.is_empty() {
             writeln!(w)?;
         }
@@ -383,10 +443,10 @@ impl Language for Swift {
                 "{}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 swift_ty,
-                if f.has_default && !f.ty.is_optional() {
-                    "?"
-                } else {
-                    Default::default()
+                match &f.default_value {
+                    Some(value) => format!(" = {}", default_literal(value)),
+                    None if f.has_default && !f.ty.is_optional() => "?".to_string(),
+                    None => String::new(),
                 }
             ));
         }
@@ -404,6 +464,11 @@ impl Language for Swift {
             write!(w, "\n\t")?;
         }
         writeln!(w, "}}")?;
+
+        if has_aliases || has_default_values {
+            self.write_decoder(w, rs)?;
+        }
+
         writeln!(w, "}}")?;
 
         Ok(())
@@ -435,8 +500,15 @@ impl Language for Swift {
 
         let shared = e.shared();
         let enum_name = swift_keyword_aware_rename(format!("{}{}", self.prefix, shared.id.renamed));
+        // Numeric enums are backed by their `repr` integer type rather than a string
+        let raw_type = match &shared.repr {
+            Some(repr) => self
+                .format_special_type(repr, &[])
+                .map_err(std::io::Error::other)?,
+            None => "String".to_string(),
+        };
         let always_present = match e {
-            RustEnum::Unit(_) => ["String"]
+            RustEnum::Unit(_) => [raw_type.as_str()]
                 .into_iter()
                 .chain(self.get_default_decorators())
                 .collect::<Vec<_>>(),
@@ -483,12 +555,79 @@ impl Language for Swift {
             )?;
         }
 
+        if let RustEnum::Unit(shared) = e {
+            // Serde aliases only apply to the names of string enums
+            let alias_cases = shared
+                .variants
+                .iter()
+                .filter(|v| !shared.is_numeric() && !v.shared().aliases.is_empty())
+                .map(|v| {
+                    format!(
+                        "\n\t\tcase {}:\n\t\t\tself = .{}",
+                        v.shared()
+                            .aliases
+                            .iter()
+                            .map(|a| format!("{a:?}"))
+                            .join(", "),
+                        swift_keyword_aware_rename(v.shared().id.original.to_camel_case())
+                    )
+                })
+                .collect::<String>();
+            match (&coding_keys_info.catch_all, alias_cases.is_empty()) {
+                (Some(catch_all), true) => writeln!(
+                    w,
+                    r#"
+	public init(from decoder: Decoder) throws {{
+		let rawValue = try decoder.singleValueContainer().decode(RawValue.self)
+		self = Self(rawValue: rawValue) ?? .{catch_all}
+	}}"#
+                )?,
+                (Some(catch_all), false) => writeln!(
+                    w,
+                    r#"
+	public init(from decoder: Decoder) throws {{
+		let rawValue = try decoder.singleValueContainer().decode(RawValue.self)
+		switch rawValue {{{alias_cases}
+		default:
+			self = Self(rawValue: rawValue) ?? .{catch_all}
+		}}
+	}}"#
+                )?,
+                (None, false) => writeln!(
+                    w,
+                    r#"
+	public init(from decoder: Decoder) throws {{
+		let container = try decoder.singleValueContainer()
+		let rawValue = try container.decode(RawValue.self)
+		switch rawValue {{{alias_cases}
+		default:
+			guard let value = Self(rawValue: rawValue) else {{
+				throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown value for {enum_name}: \(rawValue)")
+			}}
+			self = value
+		}}
+	}}"#
+                )?,
+                (None, true) => (),
+            }
+        }
+
         if let RustEnum::Algebraic {
             tag_key,
             content_key,
             ..
         } = e
         {
+            // Tags that aren't known cases decode to the `#[serde(other)]` case
+            let unknown_tag = match &coding_keys_info.catch_all {
+                Some(catch_all) => format!(
+                    r#" else if (try? container.decode(String.self, forKey: .{tag_key})) != nil {{
+			self = .{catch_all}
+			return
+		}}"#
+                ),
+                None => String::new(),
+            };
             writeln!(
                 w,
                 r#"
@@ -501,7 +640,7 @@ impl Language for Swift {
 		if let type = try? container.decode(CodingKeys.self, forKey: .{tag_key}) {{
 			switch type {{{decoding_switch}
 			}}
-		}}
+		}}{unknown_tag}
 		throw DecodingError.typeMismatch({type_name}.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for {type_name}"))
 	}}
 
@@ -544,7 +683,7 @@ impl Language for Swift {
 
     fn post_generation(&self, output_folder: &str) -> Result<(), GenerationError> {
         //
-        if self.should_emit_codable_void.load(Ordering::SeqCst) && self.multi_file {
+        if self.should_emit_support_types() && self.multi_file {
             self.write_codable_file(output_folder)
                 .map_err(|e| GenerationError::PostGeneration(e.to_string()))?;
         }
@@ -562,14 +701,27 @@ impl Swift {
         let mut decoding_cases = Vec::new();
         let mut encoding_cases = Vec::new();
         let mut coding_keys = Vec::new();
+        let mut catch_all = None;
 
         match e {
             RustEnum::Unit(shared) => {
                 for v in &shared.variants {
                     let variant_name = v.shared().id.original.to_camel_case();
+                    if v.shared().is_catch_all {
+                        catch_all = Some(swift_keyword_aware_rename(&variant_name).into_owned());
+                    }
 
                     self.write_comments(w, 1, &v.shared().comments)?;
-                    if v.shared().id.renamed == variant_name {
+                    if let Some(discriminant) =
+                        v.shared().discriminant.filter(|_| shared.is_numeric())
+                    {
+                        writeln!(
+                            w,
+                            "\tcase {} = {}",
+                            swift_keyword_aware_rename(&variant_name),
+                            discriminant
+                        )?;
+                    } else if v.shared().id.renamed == variant_name {
                         // We don't need to handle any renaming
                         writeln!(w, "\tcase {}", &swift_keyword_aware_rename(&variant_name))?;
                     } else {
@@ -620,6 +772,9 @@ impl Swift {
                     });
 
                     write!(w, "\tcase {}", swift_keyword_aware_rename(&variant_name))?;
+                    if v.shared().is_catch_all {
+                        catch_all = Some(swift_keyword_aware_rename(&variant_name).into_owned());
+                    }
 
                     match v {
                         RustEnumVariant::Unit(_) => {
@@ -746,9 +901,79 @@ impl Swift {
             decoding_cases,
             encoding_cases,
             coding_keys,
+            catch_all,
         })
     }
 
+    /// Write an `init(from:)` that falls back to the serde aliases of each field
+    /// when the serialized name is missing, and then to the field's default value.
+    fn write_decoder(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        writeln!(w)?;
+        writeln!(w, "\tpublic init(from decoder: Decoder) throws {{")?;
+        writeln!(
+            w,
+            "\t\tlet container = try decoder.container(keyedBy: CodingKeys.self)"
+        )?;
+        if rs.fields.iter().any(|f| !f.aliases.is_empty()) {
+            writeln!(
+                w,
+                "\t\tlet aliasContainer = try decoder.container(keyedBy: AliasCodingKeys.self)"
+            )?;
+        }
+
+        for f in &rs.fields {
+            let swift_ty = match f.type_override(SupportedLanguage::Swift) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&f.ty, rs.generic_types.as_slice())
+                    .map_err(io::Error::other)?,
+            };
+            let is_optional = f.ty.is_optional() || f.has_default;
+            let default = f
+                .default_value
+                .as_ref()
+                .map(|value| format!(" ?? {}", default_literal(value)))
+                .unwrap_or_default();
+            // `decodeIfPresent` takes the wrapped type of an optional
+            let decoded_ty = match swift_ty.strip_suffix('?') {
+                Some(wrapped) if f.ty.is_optional() => wrapped.to_owned(),
+                _ => swift_ty,
+            };
+
+            let key =
+                remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref());
+            let lookups = std::iter::once(("container", key))
+                .chain(
+                    f.aliases
+                        .iter()
+                        .map(|alias| ("aliasContainer", alias_key_name(alias))),
+                )
+                .collect_vec();
+
+            let decode = lookups
+                .iter()
+                .enumerate()
+                .map(|(i, (container, key))| {
+                    // A required field must be present under at least one of its names
+                    let method = if is_optional || i + 1 < lookups.len() {
+                        "decodeIfPresent"
+                    } else {
+                        "decode"
+                    };
+                    format!("{container}.{method}({decoded_ty}.self, forKey: .{key})")
+                })
+                .join(" ?? ");
+
+            writeln!(
+                w,
+                "\t\tself.{} = try {decode}{default}",
+                remove_dash_from_identifier(&f.id.renamed),
+            )?;
+        }
+
+        writeln!(w, "\t}}")
+    }
+
     fn write_comment(&mut self, w: &mut dyn Write, indent: usize, comment: &str) -> io::Result<()> {
         writeln!(w, "{}/// {}", "\t".repeat(indent), comment.trim_end())?;
         Ok(())
@@ -789,7 +1014,47 @@ impl Swift {
         self.write_codable(&mut w, &output_string)
     }
 
+    /// Whether any of the support types (`CodableVoid`, `Indirect`, `RustDuration`) are needed.
+    fn should_emit_support_types(&self) -> bool {
+        self.should_emit_codable_void.load(Ordering::SeqCst)
+            || self.should_emit_indirect.load(Ordering::SeqCst)
+            || self.should_emit_duration.load(Ordering::SeqCst)
+    }
+
     fn get_codable_contents(&self) -> String {
+        let mut contents = String::new();
+        if self.should_emit_codable_void.load(Ordering::SeqCst) {
+            contents.push_str(&self.get_codable_void_contents());
+        }
+        if self.should_emit_indirect.load(Ordering::SeqCst) {
+            contents.push_str(INDIRECT_CONTENTS);
+        }
+        if self.should_emit_duration.load(Ordering::SeqCst) {
+            contents.push_str(&self.get_duration_contents());
+        }
+        contents
+    }
+
+    fn get_duration_contents(&self) -> String {
+        let decs = self.get_default_decorators().collect::<Vec<_>>();
+
+        format!(
+            r#"
+/// Rust's `Duration`, which serde writes as whole seconds and the nanoseconds past them
+public struct RustDuration: {} {{
+	public let secs: UInt64
+	public let nanos: UInt32
+
+	public init(secs: UInt64, nanos: UInt32) {{
+		self.secs = secs
+		self.nanos = nanos
+	}}
+}}"#,
+            decs.join(", ")
+        )
+    }
+
+    fn get_codable_void_contents(&self) -> String {
         let mut decs = self
             .get_default_decorators()
             .chain(self.codablevoid_constraints.iter().map(|s| s.as_str()))
@@ -803,7 +1068,7 @@ impl Swift {
         format!("\n/// () isn't codable, so we use this instead to represent Rust's unit type\npublic struct CodableVoid: {} {{}}", decs.join(", "))
     }
 
-    /// Write the `CodableVoid` type.
+    /// Write the `CodableVoid`, `Indirect` and `RustDuration` types.
     fn write_codable(&self, w: &mut dyn Write, output_string: &str) -> io::Result<()> {
         writeln!(w, "{output_string}")
     }
@@ -867,3 +1132,81 @@ where
         name
     }
 }
+
+/// A Swift literal for a field's default value.
+fn default_literal(value: &RustDefaultValue) -> String {
+    match value {
+        RustDefaultValue::Bool(b) => b.to_string(),
+        RustDefaultValue::Int(int) => int.to_string(),
+        RustDefaultValue::Float(float) => format!("{float:?}"),
+        RustDefaultValue::String(s) => format!("{s:?}"),
+    }
+}
+
+/// The case name used for a serde alias in `AliasCodingKeys`.
+fn alias_key_name(alias: &str) -> String {
+    remove_dash_from_identifier(swift_keyword_aware_rename(alias).as_ref())
+}
+
+const INDIRECT_CONTENTS: &str = r#"
+/// Stores its value on the heap so that structs can reference themselves
+@propertyWrapper
+public struct Indirect<T> {
+	private final class Box {
+		var value: T
+		init(_ value: T) { self.value = value }
+	}
+
+	private var box: Box
+
+	public init(wrappedValue: T) {
+		box = Box(wrappedValue)
+	}
+
+	public var wrappedValue: T {
+		get { box.value }
+		set {
+			if isKnownUniquelyReferenced(&box) {
+				box.value = newValue
+			} else {
+				box = Box(newValue)
+			}
+		}
+	}
+}
+
+extension Indirect: Codable where T: Codable {
+	public init(from decoder: Decoder) throws {
+		self.init(wrappedValue: try T(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		try wrappedValue.encode(to: encoder)
+	}
+}
+
+extension Indirect: Equatable where T: Equatable {
+	public static func == (lhs: Indirect<T>, rhs: Indirect<T>) -> Bool {
+		lhs.wrappedValue == rhs.wrappedValue
+	}
+}
+
+extension Indirect: Hashable where T: Hashable {
+	public func hash(into hasher: inout Hasher) {
+		hasher.combine(wrappedValue)
+	}
+}
+
+extension Indirect: @unchecked Sendable where T: Sendable {}
+
+extension KeyedDecodingContainer {
+	public func decode<T: Decodable>(_ type: Indirect<T?>.Type, forKey key: Key) throws -> Indirect<T?> {
+		Indirect(wrappedValue: try decodeIfPresent(T.self, forKey: key))
+	}
+}
+
+extension KeyedEncodingContainer {
+	public mutating func encode<T: Encodable>(_ value: Indirect<T?>, forKey key: Key) throws {
+		try encodeIfPresent(value.wrappedValue, forKey: key)
+	}
+}"#;
@@ -1,6 +1,6 @@
 use crate::RenameExt;
 use crate::{
-    language::{Language, SupportedLanguage},
+    language::{CrateName, Language, SupportedLanguage},
     parser::ParsedData,
     rust_types::{
         RustConst, RustConstExpr, RustEnum, RustEnumVariant, RustField, RustStruct, RustType,
@@ -27,6 +27,8 @@ pub struct TypeScript {
     pub no_version_header: bool,
     /// Carries the unique set of types for custom json translation
     pub types_for_custom_json_translation: BTreeMap<String, BTreeSet<String>>,
+    /// The crate or module of the file being generated, which imports are relative to.
+    pub current_module: CrateName,
 }
 
 #[derive(Clone)]
@@ -88,7 +90,7 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
             return Ok(mapped.to_owned());
         }
         match special_ty {
-            SpecialRustType::Vec(rtype) => {
+            SpecialRustType::Vec(rtype) | SpecialRustType::ConstLenArray(rtype, _) => {
                 Ok(format!("{}[]", self.format_type(rtype, generic_types)?))
             }
             SpecialRustType::Array(rtype, len) => {
@@ -103,6 +105,9 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
             }
             // We add optionality above the type formatting level
             SpecialRustType::Option(rtype) => self.format_type(rtype, generic_types),
+            SpecialRustType::Pointer { .. } | SpecialRustType::Bytes => {
+                self.format_transparent_type(special_ty, generic_types)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => Ok(format!(
                 "Record<{}, {}>",
                 match rtype1.as_ref() {
@@ -114,7 +119,12 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
                 self.format_type(rtype2, generic_types)?
             )),
             SpecialRustType::Unit => Ok("undefined".into()),
-            SpecialRustType::DateTime => Ok("Date".into()),
+            SpecialRustType::DateTime | SpecialRustType::ChronoDateTime => Ok("Date".into()),
+            SpecialRustType::NaiveDate
+            | SpecialRustType::Uuid
+            | SpecialRustType::Url
+            | SpecialRustType::Decimal => Ok("string".into()),
+            SpecialRustType::Duration => Ok("{ secs: number, nanos: number }".into()),
             SpecialRustType::String => Ok("string".into()),
             SpecialRustType::Char => Ok("string".into()),
             SpecialRustType::I8
@@ -137,7 +147,8 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
         }
     }
 
-    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> io::Result<()> {
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> io::Result<()> {
+        self.current_module = parsed_data.crate_name.clone();
         if !self.no_version_header {
             writeln!(w, "/*")?;
             writeln!(w, " Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
@@ -221,6 +232,32 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
         };
 
         match e {
+            // An enum can't take values it doesn't list, so one with a `#[serde(other)]`
+            // variant is written as an object of its values and a type widened to values
+            // added by newer versions.
+            RustEnum::Unit(shared) if shared.catch_all_variant().is_some() => {
+                write!(w, "export const {} = {{", shared.id.renamed)?;
+                for v in &shared.variants {
+                    let v = v.shared();
+                    writeln!(w)?;
+                    self.write_comments(w, 1, &v.comments)?;
+                    match v.discriminant.filter(|_| shared.is_numeric()) {
+                        Some(discriminant) => write!(w, "\t{}: {},", v.id.original, discriminant)?,
+                        None => write!(w, "\t{}: {:?},", v.id.original, v.id.renamed)?,
+                    }
+                }
+                writeln!(w, "\n}} as const;\n")?;
+                writeln!(
+                    w,
+                    "export type {name} = (typeof {name})[keyof typeof {name}] | {};\n",
+                    if shared.is_numeric() {
+                        "number"
+                    } else {
+                        "(string & {})"
+                    },
+                    name = shared.id.renamed,
+                )
+            }
             RustEnum::Unit(shared) => {
                 write!(
                     w,
@@ -257,7 +294,11 @@ export const ReplacerFunc = (key: string, value: unknown): unknown => {{
             write!(w, "import {{ ")?;
             let ty_list = ty.iter().join(", ");
             write!(w, "{ty_list}")?;
-            writeln!(w, " }} from \"./{path}\";")?;
+            writeln!(
+                w,
+                " }} from \"{}\";",
+                import_path(&self.current_module, path)
+            )?;
         }
         writeln!(w)
     }
@@ -272,11 +313,16 @@ impl TypeScript {
         match e {
             // Write all the unit variants out (there can only be unit variants in
             // this case)
-            RustEnum::Unit(shared) => shared.variants.iter().try_for_each(|v| match v {
+            RustEnum::Unit(enum_shared) => enum_shared.variants.iter().try_for_each(|v| match v {
                 RustEnumVariant::Unit(shared) => {
                     writeln!(w)?;
                     self.write_comments(w, 1, &shared.comments)?;
-                    write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed)
+                    match shared.discriminant.filter(|_| enum_shared.is_numeric()) {
+                        Some(discriminant) => {
+                            write!(w, "\t{} = {},", shared.id.original, discriminant)
+                        }
+                        None => write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed),
+                    }
                 }
                 _ => unreachable!(),
             }),
@@ -291,6 +337,13 @@ impl TypeScript {
                 writeln!(w)?;
                 self.write_comments(w, 1, &v.shared().comments)?;
                 match v {
+                    // Widen the `#[serde(other)]` variant so tags added by newer
+                    // versions still type check
+                    RustEnumVariant::Unit(shared) if shared.is_catch_all => write!(
+                        w,
+                        "\t| {{ {}: {:?} | (string & {{}}), {}?: undefined }}",
+                        tag_key, shared.id.renamed, content_key
+                    ),
                     RustEnumVariant::Unit(shared) => write!(
                         w,
                         "\t| {{ {}: {:?}, {}?: undefined }}",
@@ -355,8 +408,24 @@ impl TypeScript {
                 .or_default()
                 .insert(field.id.renamed.clone());
         }
-        let optional = field.ty.is_optional() || field.has_default;
+        // serde writes `None` as `null` unless the field is skipped, in which case it's absent.
+        // `Option<Option<T>>` is the pattern for telling the two apart, so it keeps both.
+        let skippable = field.skip_serializing_if.is_some();
         let double_optional = field.ty.is_double_optional();
+        // An optional unit, such as a named `PhantomData` field, is only ever `null`
+        let optional_unit = field.type_override(SupportedLanguage::TypeScript).is_none()
+            && matches!(
+                field.ty.unwrap_pointers(),
+                RustType::Special(SpecialRustType::Option(inner))
+                    if **inner == RustType::Special(SpecialRustType::Unit)
+            );
+        let optional = optional_unit
+            || skippable
+            || double_optional
+            || (field.has_default && !field.ty.is_optional());
+        let nullable =
+            !optional_unit && ((field.ty.is_optional() && !skippable) || double_optional);
+        let ts_ty = if optional_unit { "null".into() } else { ts_ty };
         let is_readonly = field
             .decorators
             .get(&SupportedLanguage::TypeScript)
@@ -373,7 +442,7 @@ impl TypeScript {
             typescript_property_aware_rename(&field.id.renamed),
             if optional { "?" } else { Default::default() },
             ts_ty,
-            if double_optional {
+            if nullable {
                 " | null"
             } else {
                 Default::default()
@@ -458,3 +527,47 @@ fn typescript_property_aware_rename(name: &str) -> String {
     }
     name.to_string()
 }
+
+/// The path to import the file of a crate or module from, relative to the file
+/// of another. The files of a crate's modules are in a folder named after it.
+fn import_path(from: &CrateName, to: &CrateName) -> String {
+    let mut from_folder = from.segments().collect::<Vec<_>>();
+    from_folder.pop();
+    let to = to.segments().collect::<Vec<_>>();
+
+    let common = from_folder
+        .iter()
+        .zip(&to[..to.len() - 1])
+        .take_while(|(from, to)| from == to)
+        .count();
+    let parents = from_folder.len() - common;
+    let path = to[common..].join("/");
+    if parents == 0 {
+        format!("./{path}")
+    } else {
+        format!("{}{path}", "../".repeat(parents))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::import_path;
+
+    #[test]
+    fn import_paths() {
+        let import = |from: &str, to: &str| import_path(&from.into(), &to.into());
+
+        assert_eq!(import("app", "shared"), "./shared");
+        assert_eq!(import("app", "app::models"), "./app/models");
+        assert_eq!(import("app::models", "app"), "../app");
+        assert_eq!(import("app::models::user", "app::models"), "../models");
+        assert_eq!(
+            import("app::models::user", "app::models::account"),
+            "./account"
+        );
+        assert_eq!(
+            import("app::models::user", "shared::ids"),
+            "../../shared/ids"
+        );
+    }
+}
@@ -1,17 +1,27 @@
 //! The core library for typeshare.
 //! Contains the parser and language converters.
+mod const_generics;
 pub mod context;
+pub mod crate_names;
+mod default_values;
 pub mod error;
+mod generator;
+#[cfg(feature = "serde")]
+pub mod ir;
 /// Implementations for each language converter
 pub mod language;
 /// Parsing Rust code into a format the `language` modules can understand
 pub mod parser;
+mod phantom_data;
 pub mod reconcile;
 mod rename;
 /// Codifying Rust types and how they convert to various languages.
 pub mod rust_types;
+pub mod sources;
 mod target_os_check;
 mod topsort;
 mod visitors;
+pub mod writer;
 
+pub use generator::{BuiltinLanguage, Generator};
 pub use rename::RenameExt;
@@ -1,18 +1,21 @@
 use crate::{
+    const_generics::resolve_const_generics,
     context::{ParseContext, ParseFileContext},
+    default_values::DefaultValues,
     error::{ParseError, ParseErrorWithSpan, WithSpan as _},
     language::{CrateName, SupportedLanguage},
+    phantom_data::is_phantom_data,
     rename::RenameExt,
     rust_types::{
-        DecoratorMap, FieldDecorator, Id, RustConst, RustConstExpr, RustEnum, RustEnumShared,
-        RustEnumVariant, RustEnumVariantShared, RustField, RustItem, RustStruct, RustType,
-        RustTypeAlias, SpecialRustType,
+        DecoratorMap, FieldDecorator, Id, RustConst, RustConstExpr, RustDefaultValue, RustEnum,
+        RustEnumShared, RustEnumVariant, RustEnumVariantShared, RustField, RustItem, RustStruct,
+        RustType, RustTypeAlias, SpecialRustType,
     },
     target_os_check::accept_target_os,
     visitors::{ImportedType, TypeShareVisitor},
 };
-use log::debug;
-use proc_macro2::Ident;
+use log::{debug, warn};
+use proc_macro2::{Ident, LineColumn};
 use std::{
     collections::{BTreeSet, HashMap, HashSet},
     convert::TryFrom,
@@ -20,8 +23,8 @@ use std::{
 };
 use syn::{
     ext::IdentExt, parse::ParseBuffer, punctuated::Punctuated, spanned::Spanned as _, visit::Visit,
-    Attribute, Expr, ExprLit, Fields, GenericParam, ItemConst, ItemEnum, ItemStruct, ItemType, Lit,
-    LitStr, Meta, MetaList, MetaNameValue, Token,
+    Attribute, Expr, ExprLit, ExprUnary, Fields, GenericParam, ItemConst, ItemEnum, ItemStruct,
+    ItemType, Lit, LitStr, Meta, MetaList, MetaNameValue, Token, UnOp,
 };
 
 const TYPESHARE: &str = "typeshare";
@@ -29,6 +32,7 @@ const SERDE: &str = "serde";
 
 /// Supported typeshare type level decorator attributes.
 #[derive(PartialEq, Eq, Debug, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DecoratorKind {
     /// The typeshare attribute for swift type constraints "swift"
     Swift,
@@ -50,16 +54,36 @@ impl DecoratorKind {
 }
 
 /// Error with it's related data.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ErrorInfo {
     /// The file name being parsed.
     pub file_name: String,
     /// The parse error.
     pub error: String,
+    /// The parse error without its location.
+    pub message: String,
+    /// Where the error starts. Lines start at 1 and columns at 0.
+    pub start: LineColumn,
+    /// Where the error ends.
+    pub end: LineColumn,
+}
+
+impl ErrorInfo {
+    /// Create the error info for a parse error in the given file.
+    pub fn new(file_name: String, error: &ParseErrorWithSpan) -> Self {
+        Self {
+            file_name,
+            error: error.to_string(),
+            message: error.error().to_string(),
+            start: error.start(),
+            end: error.end(),
+        }
+    }
 }
 
 /// The results of parsing Rust source input.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParsedData {
     /// Structs defined in the source
     pub structs: Vec<RustStruct>,
@@ -77,7 +101,11 @@ pub struct ParsedData {
     pub file_name: String,
     /// All type names
     pub type_names: HashSet<String>,
+    /// The generic types defined here that have const generic parameters, with one entry
+    /// per type or const parameter that is `true` for the const ones.
+    pub const_generics: HashMap<String, Vec<bool>>,
     /// Failures during parsing.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub errors: Vec<ErrorInfo>,
     /// Using multi file support.
     pub multi_file: bool,
@@ -93,6 +121,7 @@ impl AddAssign<ParsedData> for ParsedData {
         self.consts.append(&mut rhs.consts);
         self.import_types.extend(rhs.import_types);
         self.type_names.extend(rhs.type_names);
+        self.const_generics.extend(rhs.const_generics);
         self.errors.append(&mut rhs.errors);
 
         self.file_name = rhs.file_name;
@@ -133,6 +162,51 @@ impl ParsedData {
         }
     }
 
+    /// The names of the types defined here, as the Rust source refers to them.
+    pub fn local_type_names(&self) -> impl Iterator<Item = &str> {
+        self.structs
+            .iter()
+            .map(|s| &s.id)
+            .chain(self.enums.iter().map(|e| &e.shared().id))
+            .chain(self.aliases.iter().map(|a| &a.id))
+            .map(|id| id.original.as_str())
+    }
+
+    /// The types of every field, variant and alias defined here.
+    pub(crate) fn types_mut(&mut self) -> impl Iterator<Item = &mut RustType> {
+        fn variant_types(e: &mut RustEnum) -> Vec<&mut RustType> {
+            match e {
+                RustEnum::Unit(_) => Vec::new(),
+                RustEnum::Algebraic { shared, .. } => shared
+                    .variants
+                    .iter_mut()
+                    .flat_map(|v| match v {
+                        RustEnumVariant::Unit(_) => Vec::new(),
+                        RustEnumVariant::Tuple { ty, .. } => vec![ty],
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            fields.iter_mut().map(|f| &mut f.ty).collect()
+                        }
+                    })
+                    .collect(),
+            }
+        }
+        self.structs
+            .iter_mut()
+            .flat_map(|s| s.fields.iter_mut().map(|f| &mut f.ty))
+            .chain(self.enums.iter_mut().flat_map(variant_types))
+            .chain(self.aliases.iter_mut().map(|a| &mut a.r#type))
+    }
+
+    /// Resolve well-known types like `Uuid` to the user types of the same name in
+    /// `local_types`, which glob imports bring into scope.
+    pub fn resolve_well_known_types(&mut self, local_types: &HashSet<String>) {
+        if local_types.is_empty() {
+            return;
+        }
+        self.types_mut()
+            .for_each(|ty| ty.resolve_well_known_types(local_types));
+    }
+
     /// If this file was skipped by the visitor.
     pub fn is_empty(&self) -> bool {
         self.structs.is_empty()
@@ -164,11 +238,15 @@ pub fn parse(
     debug!("parsing {file_path:?}");
     // Parse and process the input, ensuring we parse only items marked with
     // `#[typeshare]`
-    let mut import_visitor = TypeShareVisitor::new(parse_context, crate_name, file_name, file_path);
-    import_visitor.visit_file(&syn::parse_file(&source_code).map_err(|err| {
+    let mut file = syn::parse_file(&source_code).map_err(|err| {
         let span = err.span();
         ParseError::from(err).with_span(span)
-    })?);
+    })?;
+    resolve_const_generics(&mut file);
+
+    let mut import_visitor = TypeShareVisitor::new(parse_context, crate_name, file_name, file_path)
+        .with_default_values(DefaultValues::collect(&file));
+    import_visitor.visit_file(&file);
 
     Ok(import_visitor.parsed_data())
 }
@@ -181,8 +259,11 @@ pub fn parse(
 pub(crate) fn parse_struct(
     s: &ItemStruct,
     target_os: &[String],
+    default_values: &DefaultValues,
 ) -> Result<RustItem, ParseErrorWithSpan> {
     let serde_rename_all = serde_rename_all(&s.attrs);
+    // `#[serde(default)]` on the struct fills in missing fields from its `Default` impl
+    let container_default = serde_default(&s.attrs);
 
     let generic_types = s
         .generics
@@ -194,13 +275,23 @@ pub(crate) fn parse_struct(
         })
         .collect();
 
+    // Structs that serde serializes as another type are written as an alias to that type
+    let serialized_type =
+        match get_serialized_type(&s.ident, &s.attrs).map_err(|e| e.with_span(s.span()))? {
+            Some(ty) => Some(ty.parse()?),
+            None if serde_attr(&s.attrs, "transparent") => transparent_field(&s.fields)
+                .map(parse_field_type)
+                .transpose()?,
+            None => None,
+        };
+
     // Check if this struct should be parsed as a type alias.
     // TODO: we shouldn't lie and return a type alias when parsing a struct. this
     // is a temporary hack
-    if let Some(ty) = get_serialized_as_type(&s.attrs) {
+    if let Some(ty) = serialized_type {
         return Ok(RustItem::Alias(RustTypeAlias {
-            id: get_ident(Some(&s.ident), &s.attrs, &None),
-            r#type: ty.parse()?,
+            id: get_ident(Some(&s.ident), &s.attrs, &SerdeRename::default())?,
+            r#type: ty,
             comments: parse_comment_attrs(&s.attrs),
             generic_types,
             decorators: get_decorators(&s.attrs),
@@ -218,34 +309,60 @@ pub(crate) fn parse_struct(
                 .filter(|field| !is_skipped(&field.attrs, target_os))
                 .inspect(|field| debug!("\t\tAccepted field {:?}", field.ident))
                 .map(|f| {
-                    let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
-                        ty.parse()?
-                    } else {
-                        RustType::try_from(&f.ty)?
-                    };
+                    let ty = parse_field_type(f)?;
 
                     if serde_flatten(&f.attrs) {
                         return Err(ParseError::SerdeFlattenNotAllowed.with_span(f.span()));
                     }
-                    let has_default = serde_default(&f.attrs);
+                    let default_path = serde_default_path(&f.attrs);
+                    let field_default = serde_default(&f.attrs) || default_path.is_some();
+                    let default_value = match default_path {
+                        Some(path) => default_values
+                            .function(&path, &s.ident.to_string())
+                            .cloned(),
+                        None if serde_default(&f.attrs) => type_default_value(&ty),
+                        None if container_default => match f.ident.as_ref() {
+                            Some(_) if derives(&s.attrs, "Default") => type_default_value(&ty),
+                            Some(ident) => default_values
+                                .field(&s.ident.to_string(), &ident.to_string())
+                                .cloned(),
+                            None => None,
+                        },
+                        None => None,
+                    }
+                    .and_then(|value| default_value_for_type(value, &ty));
+                    // Fields only defaulted by the container are only treated as such when their
+                    // value is known, otherwise every field of the struct would become optional
+                    let has_default = field_default || default_value.is_some();
                     let decorators = get_field_decorators(&f.attrs);
 
                     Ok(RustField {
-                        id: get_ident(f.ident.as_ref(), &f.attrs, &serde_rename_all),
+                        id: get_ident(f.ident.as_ref(), &f.attrs, &serde_rename_all)?,
                         ty,
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
                         decorators,
+                        aliases: serde_aliases(&f.attrs),
+                        skip_serializing_if: serde_skip_serializing_if(&f.attrs),
+                        default_value,
                     })
                 })
-                .collect::<Result<_, ParseErrorWithSpan>>()?;
+                .collect::<Result<Vec<_>, ParseErrorWithSpan>>()?;
+
+            // Check if the struct references itself recursively in any of its fields
+            let original_struct_ident = s.ident.to_string();
+            let is_recursive = fields
+                .iter()
+                .any(|f| f.ty.contains_type(&original_struct_ident));
 
             RustItem::Struct(RustStruct {
-                id: get_ident(Some(&s.ident), &s.attrs, &None),
+                id: get_ident(Some(&s.ident), &s.attrs, &SerdeRename::default())?,
                 generic_types,
                 fields,
                 comments: parse_comment_attrs(&s.attrs),
                 decorators: get_decorators(&s.attrs),
+                is_recursive,
+                recursive_types: BTreeSet::new(),
                 is_redacted: is_redacted(&s.attrs),
             })
         }
@@ -256,14 +373,10 @@ pub(crate) fn parse_struct(
             }
             let f = &f.unnamed[0];
 
-            let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
-                ty.parse()?
-            } else {
-                RustType::try_from(&f.ty)?
-            };
+            let ty = parse_field_type(f)?;
 
             RustItem::Alias(RustTypeAlias {
-                id: get_ident(Some(&s.ident), &s.attrs, &None),
+                id: get_ident(Some(&s.ident), &s.attrs, &SerdeRename::default())?,
                 r#type: ty,
                 comments: parse_comment_attrs(&s.attrs),
                 generic_types,
@@ -273,11 +386,13 @@ pub(crate) fn parse_struct(
         }
         // Unit structs or `None`
         Fields::Unit => RustItem::Struct(RustStruct {
-            id: get_ident(Some(&s.ident), &s.attrs, &None),
+            id: get_ident(Some(&s.ident), &s.attrs, &SerdeRename::default())?,
             generic_types,
             fields: vec![],
             comments: parse_comment_attrs(&s.attrs),
             decorators: get_decorators(&s.attrs),
+            is_recursive: false,
+            recursive_types: BTreeSet::new(),
             is_redacted: is_redacted(&s.attrs),
         }),
     })
@@ -303,12 +418,15 @@ pub(crate) fn parse_enum(
         .collect();
 
     let serde_rename_all = serde_rename_all(&e.attrs);
+    let serde_rename_all_fields = serde_rename_all_fields(&e.attrs);
 
     // TODO: we shouldn't lie and return a type alias when parsing an enum. this
     // is a temporary hack
-    if let Some(ty) = get_serialized_as_type(&e.attrs) {
+    if let Some(ty) =
+        get_serialized_type(&e.ident, &e.attrs).map_err(|err| err.with_span(e.span()))?
+    {
         return Ok(RustItem::Alias(RustTypeAlias {
-            id: get_ident(Some(&e.ident), &e.attrs, &None),
+            id: get_ident(Some(&e.ident), &e.attrs, &SerdeRename::default())?,
             r#type: ty.parse()?,
             comments: parse_comment_attrs(&e.attrs),
             generic_types,
@@ -323,15 +441,28 @@ pub(crate) fn parse_enum(
     let maybe_tag_key = get_tag_key(&e.attrs);
     let maybe_content_key = get_content_key(&e.attrs);
 
+    let repr = serde_repr(&e.attrs);
+    // Skipped variants still count towards the implicit discriminants of later ones
+    let discriminants = enum_discriminants(e, repr.is_some())?;
+
     // Parse all of the enum's variants
     let variants = e
         .variants
         .iter()
-        .inspect(|v| debug!("\t\tChecking variant {}", v.ident))
+        .zip(discriminants)
+        .inspect(|(v, _)| debug!("\t\tChecking variant {}", v.ident))
         // Filter out variants we've been told to skip
-        .filter(|v| !is_skipped(&v.attrs, target_os))
-        .inspect(|v| debug!("\t\taccepted variant {}", v.ident))
-        .map(|v| parse_enum_variant(v, &serde_rename_all, target_os))
+        .filter(|(v, _)| !is_skipped(&v.attrs, target_os))
+        .inspect(|(v, _)| debug!("\t\taccepted variant {}", v.ident))
+        .map(|(v, discriminant)| {
+            parse_enum_variant(
+                v,
+                discriminant,
+                &serde_rename_all,
+                &serde_rename_all_fields,
+                target_os,
+            )
+        })
         .collect::<Result<Vec<_>, _>>()?;
 
     // Check if the enum references itself recursively in any of its variants
@@ -344,13 +475,14 @@ pub(crate) fn parse_enum(
     });
 
     let shared = RustEnumShared {
-        id: get_ident(Some(&e.ident), &e.attrs, &None),
+        id: get_ident(Some(&e.ident), &e.attrs, &SerdeRename::default())?,
         comments: parse_comment_attrs(&e.attrs),
         variants,
         decorators: get_decorators(&e.attrs),
         generic_types,
         is_recursive,
         is_redacted: is_redacted(&e.attrs),
+        repr,
     };
 
     // Figure out if we're dealing with a unit enum or an algebraic enum
@@ -401,20 +533,26 @@ pub(crate) fn parse_enum(
 /// Parse an enum variant.
 fn parse_enum_variant(
     v: &syn::Variant,
-    enum_serde_rename_all: &Option<String>,
+    discriminant: Option<i64>,
+    enum_serde_rename_all: &SerdeRename,
+    enum_serde_rename_all_fields: &SerdeRename,
     target_os: &[String],
 ) -> Result<RustEnumVariant, ParseErrorWithSpan> {
     let shared = RustEnumVariantShared {
-        id: get_ident(Some(&v.ident), &v.attrs, enum_serde_rename_all),
+        id: get_ident(Some(&v.ident), &v.attrs, enum_serde_rename_all)?,
         comments: parse_comment_attrs(&v.attrs),
+        aliases: serde_aliases(&v.attrs),
+        discriminant,
+        is_catch_all: serde_attr(&v.attrs, "other"),
     };
 
     // Get the value of `#[serde(rename_all)]` for this specific variant rather
     // than the overall enum
     //
     // The value of the attribute for the enum overall does not apply to enum
-    // variant fields.
-    let variant_serde_rename_all = serde_rename_all(&v.attrs);
+    // variant fields, but `#[serde(rename_all_fields)]` on the enum does unless
+    // the variant overrides it.
+    let variant_serde_rename_all = serde_rename_all(&v.attrs).or(enum_serde_rename_all_fields);
 
     match &v.fields {
         syn::Fields::Unit => Ok(RustEnumVariant::Unit(shared)),
@@ -427,11 +565,7 @@ fn parse_enum_variant(
 
             let first_field = associated_type.unnamed.first().unwrap();
 
-            let ty = if let Some(ty) = get_field_type_override(&first_field.attrs) {
-                ty.parse()?
-            } else {
-                RustType::try_from(&first_field.ty)?
-            };
+            let ty = parse_field_type(first_field)?;
 
             Ok(RustEnumVariant::Tuple { ty, shared })
         }
@@ -441,21 +575,21 @@ fn parse_enum_variant(
                 .iter()
                 .filter(|f| !is_skipped(&f.attrs, target_os))
                 .map(|f| {
-                    let field_type = if let Some(ty) = get_field_type_override(&f.attrs) {
-                        ty.parse()?
-                    } else {
-                        RustType::try_from(&f.ty)?
-                    };
+                    let field_type = parse_field_type(f)?;
 
-                    let has_default = serde_default(&f.attrs);
+                    let has_default =
+                        serde_default(&f.attrs) || serde_default_path(&f.attrs).is_some();
                     let decorators = get_field_decorators(&f.attrs);
 
                     Ok(RustField {
-                        id: get_ident(f.ident.as_ref(), &f.attrs, &variant_serde_rename_all),
+                        id: get_ident(f.ident.as_ref(), &f.attrs, &variant_serde_rename_all)?,
                         ty: field_type,
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
                         decorators,
+                        aliases: serde_aliases(&f.attrs),
+                        skip_serializing_if: serde_skip_serializing_if(&f.attrs),
+                        default_value: None,
                     })
                 })
                 .collect::<Result<Vec<_>, ParseErrorWithSpan>>()?,
@@ -464,6 +598,50 @@ fn parse_enum_variant(
     }
 }
 
+/// The discriminant of each variant, following Rust's rule that a variant
+/// without an explicit value is one more than the previous variant.
+///
+/// Discriminants that aren't integer literals can only be resolved by the compiler,
+/// which is an error for enums serialized as numbers.
+fn enum_discriminants(
+    e: &ItemEnum,
+    is_numeric: bool,
+) -> Result<Vec<Option<i64>>, ParseErrorWithSpan> {
+    let mut next = Some(0);
+    e.variants
+        .iter()
+        .map(|v| {
+            let discriminant = match &v.discriminant {
+                Some((_, expr)) => discriminant_value(expr),
+                None => next,
+            };
+            if discriminant.is_none() && is_numeric {
+                return Err(
+                    ParseError::UnsupportedDiscriminant(v.ident.to_string()).with_span(v.span())
+                );
+            }
+            next = discriminant.and_then(|d| d.checked_add(1));
+            Ok(discriminant)
+        })
+        .collect()
+}
+
+fn discriminant_value(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).map(|value| -value),
+        Expr::Group(group) => discriminant_value(&group.expr),
+        Expr::Paren(paren) => discriminant_value(&paren.expr),
+        _ => None,
+    }
+}
+
 /// Parses a type alias into a definition that more succinctly represents what
 /// typeshare needs to generate code for other languages.
 pub(crate) fn parse_type_alias(t: &ItemType) -> Result<RustItem, ParseErrorWithSpan> {
@@ -484,7 +662,7 @@ pub(crate) fn parse_type_alias(t: &ItemType) -> Result<RustItem, ParseErrorWithS
         .collect();
 
     Ok(RustItem::Alias(RustTypeAlias {
-        id: get_ident(Some(&t.ident), &t.attrs, &None),
+        id: get_ident(Some(&t.ident), &t.attrs, &SerdeRename::default())?,
         r#type: ty,
         comments: parse_comment_attrs(&t.attrs),
         generic_types,
@@ -519,7 +697,7 @@ pub(crate) fn parse_const(c: &ItemConst) -> Result<RustItem, ParseErrorWithSpan>
     };
 
     Ok(RustItem::Const(RustConst {
-        id: get_ident(Some(&c.ident), &c.attrs, &None),
+        id: get_ident(Some(&c.ident), &c.attrs, &SerdeRename::default())?,
         r#type: ty,
         expr,
     }))
@@ -580,14 +758,151 @@ pub(crate) fn has_typeshare_annotation(attrs: &[syn::Attribute]) -> bool {
     })
 }
 
-pub(crate) fn serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
-    get_name_value_meta_items(attrs, "rename_all", SERDE).next()
+/// A serde rename, which may differ between serialization and deserialization.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct SerdeRename {
+    serialize: Option<String>,
+    deserialize: Option<String>,
+}
+
+impl SerdeRename {
+    fn is_empty(&self) -> bool {
+        self.serialize.is_none() && self.deserialize.is_none()
+    }
+
+    /// Use `other` if no rename is given in either direction.
+    fn or(self, other: &SerdeRename) -> SerdeRename {
+        if self.is_empty() {
+            other.clone()
+        } else {
+            self
+        }
+    }
+}
+
+pub(crate) fn serde_rename_all(attrs: &[syn::Attribute]) -> SerdeRename {
+    get_serde_rename_items(attrs, "rename_all")
+}
+
+fn serde_rename_all_fields(attrs: &[syn::Attribute]) -> SerdeRename {
+    get_serde_rename_items(attrs, "rename_all_fields")
+}
+
+/// Finds `#[serde(name = "...")]` or `#[serde(name(serialize = "...", deserialize = "..."))]`.
+fn get_serde_rename_items(attrs: &[syn::Attribute], name: &str) -> SerdeRename {
+    let mut rename = SerdeRename::default();
+
+    for meta in attrs.iter().flat_map(|attr| get_meta_items(attr, SERDE)) {
+        match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident(name) => {
+                let value = expr_to_string(&name_value.value);
+                rename.serialize = value.clone();
+                rename.deserialize = value;
+            }
+            Meta::List(list) if list.path.is_ident(name) => {
+                let directions = list
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                    .into_iter()
+                    .flatten();
+                for direction in directions {
+                    let Meta::NameValue(name_value) = direction else {
+                        continue;
+                    };
+                    if name_value.path.is_ident("serialize") {
+                        rename.serialize = expr_to_string(&name_value.value);
+                    } else if name_value.path.is_ident("deserialize") {
+                        rename.deserialize = expr_to_string(&name_value.value);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    rename
 }
 
 pub(crate) fn get_serialized_as_type(attrs: &[syn::Attribute]) -> Option<String> {
     get_name_value_meta_items(attrs, "serialized_as", TYPESHARE).next()
 }
 
+/// The type to serialize a struct or enum as, either from `#[typeshare(serialized_as = "...")]`
+/// or from serde's `into` container attribute. `from` and `try_from` only change how the
+/// type is read, so `into` needs a matching one of them to describe both directions.
+/// With only one direction the type is generated as written.
+fn get_serialized_type(
+    ident: &Ident,
+    attrs: &[syn::Attribute],
+) -> Result<Option<String>, ParseError> {
+    if let Some(ty) = get_serialized_as_type(attrs) {
+        return Ok(Some(ty));
+    }
+
+    let into = get_name_value_meta_items(attrs, "into", SERDE).next();
+    let from = ["from", "try_from"].into_iter().find_map(|attribute| {
+        get_name_value_meta_items(attrs, attribute, SERDE)
+            .next()
+            .map(|ty| (attribute, ty))
+    });
+
+    match (into, from) {
+        (Some(into), Some((_, from))) if into.replace(' ', "") != from.replace(' ', "") => {
+            Err(ParseError::SerdeConversionMismatch { into, from })
+        }
+        (Some(into), Some(_)) => Ok(Some(into)),
+        (Some(ty), None) | (None, Some((_, ty))) => {
+            warn!(
+                "`{ident}` is only converted to or from `{ty}` in one direction, so it will be generated as written. Use `#[typeshare(serialized_as = \"...\")]` to pick the type to generate."
+            );
+            Ok(None)
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// The single field a `#[serde(transparent)]` struct serializes as. Zero sized
+/// `PhantomData` fields and skipped fields don't take part in serialization.
+fn transparent_field(fields: &Fields) -> Option<&syn::Field> {
+    let mut fields = fields
+        .iter()
+        .filter(|f| !is_phantom_data(&f.ty) && !serde_attr(&f.attrs, "skip"));
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => Some(field),
+        _ => None,
+    }
+}
+
+/// Parse the type of a field, honouring `#[typeshare(serialized_as)]` and
+/// `#[serde(with = "serde_bytes")]`. Named `PhantomData` fields are always
+/// written as `null`, so they become an optional unit.
+fn parse_field_type(field: &syn::Field) -> Result<RustType, ParseErrorWithSpan> {
+    let ty = match get_field_type_override(&field.attrs) {
+        Some(ty) => ty.parse()?,
+        None if field.ident.is_some() && is_phantom_data(&field.ty) => RustType::Special(
+            SpecialRustType::Option(RustType::Special(SpecialRustType::Unit).into()),
+        ),
+        None => RustType::try_from(&field.ty)?,
+    };
+    if !get_name_value_meta_items(&field.attrs, "with", SERDE).any(|with| with == "serde_bytes") {
+        return Ok(ty);
+    }
+
+    fn bytes(ty: RustType) -> RustType {
+        match ty {
+            RustType::Special(SpecialRustType::Vec(inner))
+                if *inner == RustType::Special(SpecialRustType::U8) =>
+            {
+                RustType::Special(SpecialRustType::Bytes)
+            }
+            RustType::Special(SpecialRustType::Option(inner)) => {
+                RustType::Special(SpecialRustType::Option(bytes(*inner).into()))
+            }
+            ty => ty,
+        }
+    }
+    Ok(bytes(ty))
+}
+
 pub(crate) fn get_field_type_override(attrs: &[syn::Attribute]) -> Option<String> {
     get_name_value_meta_items(attrs, "serialized_as", TYPESHARE).next()
 }
@@ -653,23 +968,35 @@ pub(crate) fn get_meta_items(attr: &syn::Attribute, ident: &str) -> impl Iterato
 fn get_ident(
     ident: Option<&proc_macro2::Ident>,
     attrs: &[syn::Attribute],
-    rename_all: &Option<String>,
-) -> Id {
+    rename_all: &SerdeRename,
+) -> Result<Id, ParseErrorWithSpan> {
     let original = ident.map_or("???".to_string(), |id| id.to_string().replace("r#", ""));
 
-    let mut renamed = rename_all_to_case(original.clone(), rename_all);
-
-    let mut renamed_via_serde_rename = false;
-    if let Some(s) = serde_rename(attrs) {
-        renamed = s;
-        renamed_via_serde_rename = true;
+    let rename = serde_rename(attrs);
+    let renamed_via_serde_rename = !rename.is_empty();
+
+    let serialize = rename
+        .serialize
+        .unwrap_or_else(|| rename_all_to_case(original.clone(), &rename_all.serialize));
+    let deserialize = rename
+        .deserialize
+        .unwrap_or_else(|| rename_all_to_case(original.clone(), &rename_all.deserialize));
+
+    // Generated types only have a single name for each field, so both directions must agree.
+    if serialize != deserialize {
+        return Err(ParseError::AsymmetricSerdeRename {
+            ident: original,
+            serialize,
+            deserialize,
+        }
+        .with_span(ident.map_or_else(proc_macro2::Span::call_site, |id| id.span())));
     }
 
-    Id {
+    Ok(Id {
         original,
-        renamed,
+        renamed: serialize,
         serde_rename: renamed_via_serde_rename,
-    }
+    })
 }
 
 fn rename_all_to_case(original: String, case: &Option<String>) -> String {
@@ -689,8 +1016,64 @@ fn rename_all_to_case(original: String, case: &Option<String>) -> String {
     }
 }
 
-fn serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
-    get_name_value_meta_items(attrs, "rename", SERDE).next()
+/// True if `#[derive(...)]` includes `name`.
+fn derives(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .flat_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .into_iter()
+                .flatten()
+        })
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == name)
+        })
+}
+
+/// The integer type an enum is serialized as when it derives `Serialize_repr` or
+/// `Deserialize_repr` from the `serde_repr` crate.
+fn serde_repr(attrs: &[syn::Attribute]) -> Option<SpecialRustType> {
+    if !derives(attrs, "Serialize_repr") && !derives(attrs, "Deserialize_repr") {
+        return None;
+    }
+
+    let repr = attrs
+        .iter()
+        .flat_map(|attr| get_meta_items(attr, "repr"))
+        .find_map(|meta| match meta {
+            Meta::Path(path) => Some(match path.get_ident()?.to_string().as_str() {
+                "u8" => SpecialRustType::U8,
+                "u16" => SpecialRustType::U16,
+                "u32" => SpecialRustType::U32,
+                "u64" => SpecialRustType::U64,
+                "usize" => SpecialRustType::USize,
+                "i8" => SpecialRustType::I8,
+                "i16" => SpecialRustType::I16,
+                "i32" => SpecialRustType::I32,
+                "i64" => SpecialRustType::I64,
+                "isize" => SpecialRustType::ISize,
+                _ => return None,
+            }),
+            _ => None,
+        });
+
+    // Without an explicit `repr`, Rust picks `isize` for the discriminant
+    Some(repr.unwrap_or(SpecialRustType::ISize))
+}
+
+fn serde_skip_serializing_if(attrs: &[syn::Attribute]) -> Option<String> {
+    get_name_value_meta_items(attrs, "skip_serializing_if", SERDE).next()
+}
+
+fn serde_aliases(attrs: &[syn::Attribute]) -> Vec<String> {
+    get_name_value_meta_items(attrs, "alias", SERDE).collect()
+}
+
+fn serde_rename(attrs: &[syn::Attribute]) -> SerdeRename {
+    get_serde_rename_items(attrs, "rename")
 }
 
 /// Parses any comment out of the given slice of attributes
@@ -755,6 +1138,61 @@ fn serde_default(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, "default")
 }
 
+/// The function named by `#[serde(default = "...")]`.
+fn serde_default_path(attrs: &[syn::Attribute]) -> Option<String> {
+    get_name_value_meta_items(attrs, "default", SERDE).next()
+}
+
+/// The value of `Default::default()` for types that have a literal default.
+fn type_default_value(ty: &RustType) -> Option<RustDefaultValue> {
+    match ty {
+        RustType::Special(SpecialRustType::Bool) => Some(RustDefaultValue::Bool(false)),
+        RustType::Special(SpecialRustType::String) => Some(RustDefaultValue::String(String::new())),
+        RustType::Special(SpecialRustType::F32 | SpecialRustType::F64) => {
+            Some(RustDefaultValue::Float(0.0))
+        }
+        RustType::Special(special) if is_integer(special) => Some(RustDefaultValue::Int(0)),
+        _ => None,
+    }
+}
+
+/// Keep a default value only if it can be written as a literal of the field's type.
+fn default_value_for_type(value: RustDefaultValue, ty: &RustType) -> Option<RustDefaultValue> {
+    let RustType::Special(special) = ty else {
+        return None;
+    };
+    match (value, special) {
+        (value @ RustDefaultValue::Bool(_), SpecialRustType::Bool)
+        | (value @ RustDefaultValue::String(_), SpecialRustType::String)
+        | (value @ RustDefaultValue::Float(_), SpecialRustType::F32 | SpecialRustType::F64) => {
+            Some(value)
+        }
+        (RustDefaultValue::Int(int), SpecialRustType::F32 | SpecialRustType::F64) => {
+            Some(RustDefaultValue::Float(int as f64))
+        }
+        (value @ RustDefaultValue::Int(_), special) if is_integer(special) => Some(value),
+        _ => None,
+    }
+}
+
+fn is_integer(ty: &SpecialRustType) -> bool {
+    matches!(
+        ty,
+        SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::I64
+            | SpecialRustType::I54
+            | SpecialRustType::ISize
+            | SpecialRustType::U8
+            | SpecialRustType::U16
+            | SpecialRustType::U32
+            | SpecialRustType::U64
+            | SpecialRustType::U53
+            | SpecialRustType::USize
+    )
+}
+
 fn serde_flatten(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, "flatten")
 }
@@ -907,14 +1345,15 @@ pub(crate) fn remove_dash_from_identifier(name: &str) -> String {
 #[cfg(test)]
 mod test {
     use crate::{
+        default_values::DefaultValues,
         parser::{
-            get_decorators, has_typeshare_annotation, is_redacted, parse_struct,
+            get_decorators, has_typeshare_annotation, is_redacted, parse_enum, parse_struct,
             rename_all_to_case, DecoratorKind,
         },
-        rust_types::RustItem,
+        rust_types::{PointerKind, RustItem, RustType, SpecialRustType},
     };
     use std::collections::BTreeSet;
-    use syn::{Attribute, ItemStruct};
+    use syn::{Attribute, ItemEnum, ItemStruct};
 
     #[test]
     fn test_rename_all_to_case() {
@@ -1003,7 +1442,8 @@ mod test {
         };
 
         let RustItem::Alias(rust_struct) =
-            parse_struct(&item_struct, &[]).expect("Failed to parse struct")
+            parse_struct(&item_struct, &[], &DefaultValues::default())
+                .expect("Failed to parse struct")
         else {
             panic!("Not a struct");
         };
@@ -1039,4 +1479,68 @@ mod test {
 
         assert!(has_typeshare_annotation(&item_struct.attrs));
     }
+
+    #[test]
+    fn test_smart_pointers_are_preserved() {
+        let item_struct: ItemStruct = syn::parse_quote! {
+            #[typeshare]
+            pub struct Node {
+                parent: Option<Box<Node>>,
+                shared: Arc<String>,
+            }
+        };
+
+        let RustItem::Struct(rust_struct) =
+            parse_struct(&item_struct, &[], &DefaultValues::default())
+                .expect("Failed to parse struct")
+        else {
+            panic!("Not a struct");
+        };
+
+        assert!(rust_struct.is_recursive);
+        assert!(rust_struct.fields[0].ty.is_optional());
+        assert!(rust_struct.fields[1].ty.contains_type("String"));
+        assert!(!rust_struct.fields[1].ty.contains_type("Arc"));
+        assert_eq!(
+            rust_struct.fields[1].ty,
+            RustType::Special(SpecialRustType::Pointer {
+                kind: PointerKind::Arc,
+                inner: Box::new(RustType::Special(SpecialRustType::String)),
+            })
+        );
+        assert_eq!(
+            rust_struct.fields[1].ty.unwrap_pointers(),
+            &RustType::Special(SpecialRustType::String)
+        );
+    }
+
+    #[test]
+    fn test_one_way_serde_conversions_are_generated_as_written() {
+        let into_only: ItemStruct = syn::parse_quote! {
+            #[typeshare]
+            #[serde(into = "String")]
+            pub struct Color {
+                red: u8,
+            }
+        };
+        let from_only: ItemEnum = syn::parse_quote! {
+            #[typeshare]
+            #[serde(from = "u32")]
+            pub enum Priority {
+                Low,
+                High,
+            }
+        };
+
+        let RustItem::Struct(color) =
+            parse_struct(&into_only, &[], &DefaultValues::default()).unwrap()
+        else {
+            panic!("`Color` should be generated as a struct");
+        };
+        assert_eq!(color.fields.len(), 1);
+        let RustItem::Enum(priority) = parse_enum(&from_only, &[]).unwrap() else {
+            panic!("`Priority` should be generated as an enum");
+        };
+        assert_eq!(priority.shared().variants.len(), 2);
+    }
 }
@@ -0,0 +1,173 @@
+//! Leave the type parameters of `PhantomData` out of the generated types.
+//!
+//! Serde serializes `PhantomData` as unit, so the parser turns named `PhantomData` fields
+//! into an optional unit, while tuple elements keep their `null` slot as unit. Once every
+//! crate is parsed, type parameters that no serialized data uses anymore are dropped, along with the matching
+//! arguments wherever their type is referenced. This follows references to other generic
+//! types, so a parameter only passed on to a dropped parameter is dropped as well.
+use crate::{
+    language::CrateName,
+    parser::ParsedData,
+    rust_types::{RustEnum, RustEnumVariant, RustType},
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// True for `PhantomData<T>`, however it is imported.
+pub(crate) fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Drop the type parameters that no field uses from every generic type in
+/// `crate_parsed_data`, and their arguments from every reference to these types.
+pub(crate) fn remove_unused_generics(crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>) {
+    let live = live_parameters(crate_parsed_data);
+    if live.values().all(|live| !live.contains(&false)) {
+        return;
+    }
+
+    let retain_live = |name: &str, generic_types: &mut Vec<String>| {
+        if let Some(live) = live.get(name) {
+            let mut live = live.iter();
+            generic_types.retain(|_| *live.next().unwrap());
+        }
+    };
+    for parsed_data in crate_parsed_data.values_mut() {
+        for s in &mut parsed_data.structs {
+            retain_live(&s.id.original, &mut s.generic_types);
+        }
+        for e in &mut parsed_data.enums {
+            let shared = e.shared_mut();
+            retain_live(&shared.id.original, &mut shared.generic_types);
+        }
+        for a in &mut parsed_data.aliases {
+            retain_live(&a.id.original, &mut a.generic_types);
+        }
+        for ty in parsed_data.types_mut() {
+            remove_dead_arguments(ty, &live);
+        }
+    }
+}
+
+/// Find which type parameters of each generic type are used by its fields. A parameter
+/// only counts as used in an argument to another generic type if that type uses the
+/// parameter it is passed to, which is repeated until nothing changes.
+fn live_parameters(
+    crate_parsed_data: &BTreeMap<CrateName, ParsedData>,
+) -> HashMap<String, Vec<bool>> {
+    let definitions = crate_parsed_data
+        .values()
+        .flat_map(generic_definitions)
+        .collect::<Vec<_>>();
+    let mut live = definitions
+        .iter()
+        .map(|(name, parameters, _)| (name.to_string(), vec![false; parameters.len()]))
+        .collect::<HashMap<_, _>>();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, parameters, types) in &definitions {
+            let mut used = Vec::new();
+            for ty in types {
+                used_names(ty, &live, &mut used);
+            }
+            let live = live.get_mut(*name).unwrap();
+            for (parameter, live) in parameters.iter().zip(live.iter_mut()) {
+                if !*live && used.contains(&parameter.as_str()) {
+                    *live = true;
+                    changed = true;
+                }
+            }
+        }
+    }
+    live
+}
+
+/// The generic types defined in `parsed_data`, with their type parameters and the types
+/// of their fields.
+fn generic_definitions(
+    parsed_data: &ParsedData,
+) -> impl Iterator<Item = (&str, &[String], Vec<&RustType>)> {
+    let structs = parsed_data.structs.iter().map(|s| {
+        let types = s.fields.iter().map(|f| &f.ty).collect();
+        (s.id.original.as_str(), s.generic_types.as_slice(), types)
+    });
+    let enums = parsed_data.enums.iter().map(|e| {
+        let types = match e {
+            RustEnum::Unit(_) => Vec::new(),
+            RustEnum::Algebraic { shared, .. } => shared
+                .variants
+                .iter()
+                .flat_map(|v| match v {
+                    RustEnumVariant::Unit(_) => Vec::new(),
+                    RustEnumVariant::Tuple { ty, .. } => vec![ty],
+                    RustEnumVariant::AnonymousStruct { fields, .. } => {
+                        fields.iter().map(|f| &f.ty).collect()
+                    }
+                })
+                .collect(),
+        };
+        let shared = e.shared();
+        (
+            shared.id.original.as_str(),
+            shared.generic_types.as_slice(),
+            types,
+        )
+    });
+    let aliases = parsed_data.aliases.iter().map(|a| {
+        (
+            a.id.original.as_str(),
+            a.generic_types.as_slice(),
+            vec![&a.r#type],
+        )
+    });
+    structs
+        .chain(enums)
+        .chain(aliases)
+        .filter(|(_, parameters, _)| !parameters.is_empty())
+}
+
+/// Collect the names `ty` refers to, skipping the arguments of unused parameters.
+fn used_names<'a>(ty: &'a RustType, live: &HashMap<String, Vec<bool>>, used: &mut Vec<&'a str>) {
+    match ty {
+        RustType::Simple { id } => used.push(id),
+        RustType::Generic { id, parameters } => match live.get(id) {
+            Some(is_live) if is_live.len() == parameters.len() => parameters
+                .iter()
+                .zip(is_live)
+                .filter(|(_, is_live)| **is_live)
+                .for_each(|(ty, _)| used_names(ty, live, used)),
+            _ => parameters.iter().for_each(|ty| used_names(ty, live, used)),
+        },
+        RustType::Special(special) => special
+            .parameters()
+            .for_each(|ty| used_names(ty, live, used)),
+    }
+}
+
+/// Remove the arguments of unused parameters from `ty` and the types nested in it.
+fn remove_dead_arguments(ty: &mut RustType, live: &HashMap<String, Vec<bool>>) {
+    if let RustType::Generic { id, parameters } = ty {
+        if let Some(is_live) = live.get(id.as_str()) {
+            if is_live.len() == parameters.len() {
+                let mut is_live = is_live.iter();
+                parameters.retain(|_| *is_live.next().unwrap());
+            }
+        }
+        if parameters.is_empty() {
+            *ty = RustType::Simple {
+                id: std::mem::take(id),
+            };
+        }
+    }
+    for ty in ty.parameters_mut() {
+        remove_dead_arguments(ty, live);
+    }
+}
@@ -4,8 +4,10 @@
 //! name however we still need to see if we have any other types that reference the renamed type
 //! and update those references accordingly.
 use crate::{
+    const_generics::resolve_const_arguments,
     language::CrateName,
     parser::ParsedData,
+    phantom_data::remove_unused_generics,
     rust_types::{RustEnum, RustEnumVariant, RustType, SpecialRustType},
     visitors::ImportedType,
 };
@@ -20,9 +22,33 @@ type RenamedTypes = HashMap<String, HashMap<CrateName, String>>;
 
 /// Update any type references that have the refenced type renamed via `serde(rename)`.
 pub fn reconcile_aliases(crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>) {
+    resolve_const_arguments(crate_parsed_data);
+    remove_unused_generics(crate_parsed_data);
     let serde_renamed = collect_serde_renames(crate_parsed_data);
+    let local_type_names = crate_parsed_data
+        .iter()
+        .map(|(crate_name, parsed_data)| {
+            let names = parsed_data
+                .local_type_names()
+                .map(ToOwned::to_owned)
+                .collect::<Vec<_>>();
+            (crate_name.clone(), names)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for (crate_name, parsed_data) in crate_parsed_data.iter_mut() {
+        // Types glob imported from this or other crates, as with `use super::*`, shadow
+        // well-known types of the same name
+        let glob_imported_types = parsed_data
+            .import_types
+            .iter()
+            .filter(|imported| imported.type_name == "*")
+            .filter_map(|imported| local_type_names.get(&imported.base_crate))
+            .flatten()
+            .cloned()
+            .collect();
+        parsed_data.resolve_well_known_types(&glob_imported_types);
 
-    for (crate_name, parsed_data) in crate_parsed_data {
         let import_types = mem::take(&mut parsed_data.import_types);
 
         // update references to renamed ids in product types.
@@ -65,6 +91,59 @@ pub fn reconcile_aliases(crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>
         // put back our import types for file generation.
         parsed_data.import_types = import_types;
     }
+
+    mark_recursive_structs(crate_parsed_data);
+}
+
+/// Find the structs that store each other inline in a cycle of fields, which
+/// can span files and crates, and record each cycle on its structs.
+fn mark_recursive_structs(crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>) {
+    let structs = || {
+        crate_parsed_data
+            .values()
+            .flat_map(|parsed_data| &parsed_data.structs)
+    };
+    // The structs each struct stores inline, by name.
+    let stored: HashMap<String, Vec<String>> = structs()
+        .map(|s| {
+            let names = structs()
+                .map(|other| other.id.renamed.clone())
+                .filter(|other| s.fields.iter().any(|f| f.ty.stores_inline(other)))
+                .collect();
+            (s.id.renamed.clone(), names)
+        })
+        .collect();
+
+    let reachable = stored
+        .keys()
+        .map(|name| {
+            let mut seen = HashSet::new();
+            let mut stack = vec![name];
+            while let Some(current) = stack.pop() {
+                for next in stored.get(current).into_iter().flatten() {
+                    if seen.insert(next) {
+                        stack.push(next);
+                    }
+                }
+            }
+            (name, seen)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for s in crate_parsed_data
+        .values_mut()
+        .flat_map(|parsed_data| &mut parsed_data.structs)
+    {
+        let Some(from) = reachable.get(&s.id.renamed) else {
+            continue;
+        };
+        s.recursive_types = from
+            .iter()
+            .filter(|other| reachable[*other].contains(&s.id.renamed))
+            .map(|other| other.to_string())
+            .collect();
+        s.is_recursive |= !s.recursive_types.is_empty();
+    }
 }
 
 /// Traverse all the parsed typeshare data and collect all types that have been renamed
@@ -140,7 +219,7 @@ fn check_type(
             SpecialRustType::Vec(ty) => {
                 check_type(crate_name, serde_renamed, import_types, ty);
             }
-            SpecialRustType::Array(ty, _) => {
+            SpecialRustType::Array(ty, _) | SpecialRustType::ConstLenArray(ty, _) => {
                 check_type(crate_name, serde_renamed, import_types, ty);
             }
             SpecialRustType::Slice(ty) => {
@@ -153,6 +232,9 @@ fn check_type(
             SpecialRustType::Option(ty) => {
                 check_type(crate_name, serde_renamed, import_types, ty);
             }
+            SpecialRustType::Pointer { inner, .. } => {
+                check_type(crate_name, serde_renamed, import_types, inner);
+            }
             _ => (),
         },
         RustType::Simple { id } => {
@@ -1,5 +1,5 @@
 use quote::ToTokens;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt::Display;
 use std::str::FromStr;
 use std::{collections::HashMap, convert::TryFrom};
@@ -17,6 +17,7 @@ pub type DecoratorMap = HashMap<DecoratorKind, BTreeSet<String>>;
 
 /// Identifier used in Rust structs, enums, and fields. It includes the `original` name and the `renamed` value after the transformation based on `serde` attributes.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Id {
     /// The original identifier name
     pub original: String,
@@ -40,6 +41,7 @@ impl std::fmt::Display for Id {
 
 /// Rust struct.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustStruct {
     /// The identifier for the struct.
     pub id: Id,
@@ -53,6 +55,14 @@ pub struct RustStruct {
     pub comments: Vec<String>,
     /// Attributes that exist for this struct.
     pub decorators: DecoratorMap,
+    /// True if this struct references itself in any of its fields, directly or
+    /// through other structs.
+    pub is_recursive: bool,
+    /// The structs, this one included, that store each other inline in a cycle
+    /// of fields. Swift needs to box the fields referring to them since structs
+    /// there are value types. Filled in by [`reconcile_aliases`](crate::reconcile::reconcile_aliases)
+    /// once every crate is parsed.
+    pub recursive_types: BTreeSet<String>,
     /// True if this struct contains data that needs to be redacted
     pub is_redacted: bool,
 }
@@ -84,6 +94,7 @@ impl Ord for RustStruct {
 /// pub const MY_CONST: &str = "constant value";
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustConst {
     /// The identifier for the constant.
     pub id: Id,
@@ -102,6 +113,7 @@ impl PartialEq for RustConst {
 /// A constant expression that can be shared via a constant variable across the typeshare
 /// boundary.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustConstExpr {
     /// Expression represents an integer.
     Int(i128),
@@ -112,6 +124,7 @@ pub enum RustConstExpr {
 /// pub struct MasterPassword(String);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustTypeAlias {
     /// The identifier for the alias.
     pub id: Id,
@@ -149,6 +162,7 @@ impl PartialOrd for RustTypeAlias {
 
 /// Rust field definition.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustField {
     /// Identifier for the field.
     pub id: Id,
@@ -163,10 +177,33 @@ pub struct RustField {
     /// Language-specific decorators assigned to a given field.
     /// The keys are language names (e.g. SupportedLanguage::TypeScript), the values are field decorators (e.g. readonly)
     pub decorators: HashMap<SupportedLanguage, BTreeSet<FieldDecorator>>,
+    /// Additional names accepted when deserializing, from `#[serde(alias = "...")]`.
+    pub aliases: Vec<String>,
+    /// The predicate from `#[serde(skip_serializing_if = "...")]`. When set, the field
+    /// may be left out of the serialized output rather than written as `null`.
+    pub skip_serializing_if: Option<String>,
+    /// The value the field takes when it is missing, if its `serde(default)` could be
+    /// resolved to a literal.
+    pub default_value: Option<RustDefaultValue>,
+}
+
+/// A literal default value for a field.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RustDefaultValue {
+    /// A boolean literal
+    Bool(bool),
+    /// An integer literal
+    Int(i128),
+    /// A floating point literal
+    Float(f64),
+    /// A string literal
+    String(String),
 }
 
 /// A single decorator on a field in Rust code.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FieldDecorator {
     /// A boolean flag enabled by its existence as a decorator: for example, `readonly`.
     Word(String),
@@ -185,6 +222,7 @@ impl FieldDecorator {
 
 /// A Rust type.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustType {
     /// A type with generic parameters. Consists of a type ID + parameters that come
     /// after in angled brackets. Examples include:
@@ -238,25 +276,70 @@ impl Display for RustType {
     }
 }
 
+/// The paths of the types from other crates that have default conversions,
+/// which are matched in order.
+const WELL_KNOWN_TYPES: &[(&str, SpecialRustType)] = &[
+    ("time::OffsetDateTime", SpecialRustType::DateTime),
+    ("chrono::DateTime", SpecialRustType::ChronoDateTime),
+    ("chrono::NaiveDate", SpecialRustType::NaiveDate),
+    ("chrono::naive::NaiveDate", SpecialRustType::NaiveDate),
+    ("std::time::Duration", SpecialRustType::Duration),
+    ("core::time::Duration", SpecialRustType::Duration),
+    ("uuid::Uuid", SpecialRustType::Uuid),
+    ("url::Url", SpecialRustType::Url),
+    ("rust_decimal::Decimal", SpecialRustType::Decimal),
+    ("serde_bytes::ByteBuf", SpecialRustType::Bytes),
+    ("bytes::Bytes", SpecialRustType::Bytes),
+];
+
 /// A special rust type that needs a manual type conversion
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpecialRustType {
     /// Represents `Vec<T>` from the standard library
     Vec(Box<RustType>),
     /// Represents `[T; N]` from the standard library
     Array(Box<RustType>, usize),
+    /// Represents `[T; N]` where `N` names a constant defined in another file. Reconciling
+    /// turns it into an `Array` when `N` is a typeshared constant, otherwise it is
+    /// generated like `Vec<T>`.
+    ConstLenArray(Box<RustType>, String),
     /// Represents `&[T]` from the standard library
     Slice(Box<RustType>),
     /// Represents `HashMap<K, V>` from the standard library
     HashMap(Box<RustType>, Box<RustType>),
     /// Represents `Option<T>` from the standard library
     Option(Box<RustType>),
+    /// Represents a smart pointer or interior mutability wrapper such as `Box<T>` or `Arc<T>`.
+    /// Serde serializes these as their inner type, so most languages can ignore the wrapper.
+    Pointer {
+        /// Which wrapper this is
+        kind: PointerKind,
+        /// The wrapped type
+        inner: Box<RustType>,
+    },
     /// Represents time::OffsetDateTime from time
     /// We serialize/deserialize this to an UTC time specifically
     /// encoded in the RFC3339 or ISO8601 format.
     /// This should be used with serde's with tag when serializing/deserializing
     /// like so #[serde(with = "time::serde::rfc3339")]
     DateTime,
+    /// Represents `chrono::DateTime<Tz>`, serialized as an RFC3339 string
+    ChronoDateTime,
+    /// Represents `chrono::NaiveDate`, serialized as an ISO8601 date string such as `2024-01-31`
+    NaiveDate,
+    /// Represents `std::time::Duration`, serialized as `{ "secs": u64, "nanos": u32 }`
+    Duration,
+    /// Represents `uuid::Uuid`, serialized as a hyphenated string
+    Uuid,
+    /// Represents `url::Url`, serialized as a string
+    Url,
+    /// Represents `rust_decimal::Decimal`, serialized as a string to keep its precision
+    Decimal,
+    /// Represents `serde_bytes::ByteBuf`, `bytes::Bytes` or a `Vec<u8>` field with
+    /// `#[serde(with = "serde_bytes")]`. These serialize as a list of bytes with serde_json,
+    /// so they are generated like `Vec<u8>`.
+    Bytes,
     /// Represents `()`
     Unit,
     /// Represents `String` from the standard library
@@ -295,11 +378,85 @@ pub enum SpecialRustType {
     U53,
 }
 
+/// The kind of wrapper a [`SpecialRustType::Pointer`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerKind {
+    /// `Box<T>`
+    Box,
+    /// `Rc<T>`
+    Rc,
+    /// `Arc<T>`
+    Arc,
+    /// `Weak<T>`
+    Weak,
+    /// `RcWeak<T>`
+    RcWeak,
+    /// `ArcWeak<T>`
+    ArcWeak,
+    /// `Cow<T>`
+    Cow,
+    /// `Cell<T>`
+    Cell,
+    /// `RefCell<T>`
+    RefCell,
+    /// `Mutex<T>`
+    Mutex,
+    /// `RwLock<T>`
+    RwLock,
+}
+
+impl PointerKind {
+    /// Returns the Rust identifier for this wrapper.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Box => "Box",
+            Self::Rc => "Rc",
+            Self::Arc => "Arc",
+            Self::Weak => "Weak",
+            Self::RcWeak => "RcWeak",
+            Self::ArcWeak => "ArcWeak",
+            Self::Cow => "Cow",
+            Self::Cell => "Cell",
+            Self::RefCell => "RefCell",
+            Self::Mutex => "Mutex",
+            Self::RwLock => "RwLock",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        Some(match id {
+            "Box" => Self::Box,
+            "Rc" => Self::Rc,
+            "Arc" => Self::Arc,
+            "Weak" => Self::Weak,
+            "RcWeak" => Self::RcWeak,
+            "ArcWeak" => Self::ArcWeak,
+            "Cow" => Self::Cow,
+            "Cell" => Self::Cell,
+            "RefCell" => Self::RefCell,
+            "Mutex" => Self::Mutex,
+            "RwLock" => Self::RwLock,
+            _ => return None,
+        })
+    }
+
+    /// Whether the wrapped value is heap allocated.
+    pub fn is_heap_allocated(&self) -> bool {
+        matches!(
+            self,
+            Self::Box | Self::Rc | Self::Arc | Self::Weak | Self::RcWeak | Self::ArcWeak
+        )
+    }
+}
+
 impl Display for SpecialRustType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let special_type = match self {
             SpecialRustType::Vec(rust_type) => format!("Vec<{rust_type}>"),
-            SpecialRustType::Array(rust_type, _) => format!("[{rust_type}]"),
+            SpecialRustType::Array(rust_type, _) | SpecialRustType::ConstLenArray(rust_type, _) => {
+                format!("[{rust_type}]")
+            }
             SpecialRustType::Slice(rust_type) => format!("&[{rust_type}]"),
             SpecialRustType::HashMap(rust_type, rust_type1) => {
                 format!("HashMap<{rust_type},{rust_type1}>")
@@ -307,6 +464,7 @@ impl Display for SpecialRustType {
             SpecialRustType::Option(rust_type) => {
                 format!("Option<{}>", rust_type.id())
             }
+            SpecialRustType::Pointer { kind, inner } => format!("{}<{inner}>", kind.id()),
             _ => self.id().to_string(),
         };
         write!(f, "{special_type}")
@@ -352,6 +510,15 @@ impl TryFrom<&syn::Type> for RustType {
                     }
                     _ => Vec::default(),
                 };
+                let full_path = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect::<Vec<_>>();
+                if let Some(special) = SpecialRustType::well_known(&full_path) {
+                    return Ok(Self::Special(special));
+                }
                 match id.as_str() {
                     "Vec" => Self::Special(SpecialRustType::Vec(
                         parameters.into_iter().next().unwrap().into(),
@@ -366,12 +533,16 @@ impl TryFrom<&syn::Type> for RustType {
                             params.next().unwrap().into(),
                         ))
                     }
-                    "OffsetDateTime" => Self::Special(SpecialRustType::DateTime),
                     "str" | "String" => Self::Special(SpecialRustType::String),
-                    // These smart pointers can be treated as their inner type since serde can handle it
+                    // Serde serializes `PhantomData` as unit
+                    "PhantomData" => Self::Special(SpecialRustType::Unit),
+                    // These smart pointers serialize as their inner type since serde can handle it
                     // See impls of serde::Deserialize
                     "Box" | "Weak" | "Arc" | "Rc" | "Cow" | "ArcWeak" | "RcWeak" | "Cell"
-                    | "Mutex" | "RefCell" | "RwLock" => parameters.into_iter().next().unwrap(),
+                    | "Mutex" | "RefCell" | "RwLock" => Self::Special(SpecialRustType::Pointer {
+                        kind: PointerKind::from_id(&id).unwrap(),
+                        inner: parameters.into_iter().next().unwrap().into(),
+                    }),
                     "bool" => Self::Special(SpecialRustType::Bool),
                     "char" => Self::Special(SpecialRustType::Char),
                     "u8" => Self::Special(SpecialRustType::U8),
@@ -413,6 +584,14 @@ impl TryFrom<&syn::Type> for RustType {
                     RustTypeParseError::NumericLiteral(err).with_span(span)
                 })?,
             )),
+            syn::Type::Array(TypeArray {
+                elem,
+                len: Expr::Path(len),
+                ..
+            }) if len.qself.is_none() => Self::Special(SpecialRustType::ConstLenArray(
+                Self::try_from(elem.as_ref())?.into(),
+                len.path.segments.last().unwrap().ident.to_string(),
+            )),
             syn::Type::Slice(TypeSlice {
                 bracket_token: _,
                 elem,
@@ -431,7 +610,7 @@ impl TryFrom<&syn::Type> for RustType {
 
 impl RustType {
     /// Check if a type contains a type with an ID that matches `ty`.
-    /// For example, `Box<String>` contains the types `Box` and `String`. Similarly,
+    /// Smart pointers are transparent, so `Box<String>` only contains `String`. Similarly,
     /// `Vec<Option<HashMap<String, Url>>>` contains the types `Vec`, `Option`, `HashMap`,
     /// `String`, and `Url`.
     pub fn contains_type(&self, ty: &str) -> bool {
@@ -444,6 +623,22 @@ impl RustType {
         }
     }
 
+    /// Whether a value of this type holds a `name` directly rather than behind a
+    /// collection: as itself, an `Option` or smart pointer of it, or a parameter
+    /// of a generic type.
+    pub fn stores_inline(&self, name: &str) -> bool {
+        match self {
+            Self::Simple { id } => id == name,
+            Self::Generic { id, parameters } => {
+                id == name || parameters.iter().any(|p| p.stores_inline(name))
+            }
+            Self::Special(
+                SpecialRustType::Option(inner) | SpecialRustType::Pointer { inner, .. },
+            ) => inner.stores_inline(name),
+            Self::Special(_) => false,
+        }
+    }
+
     /// Get the ID (AKA name) of the type.
     pub fn id(&self) -> &str {
         match &self {
@@ -451,27 +646,42 @@ impl RustType {
             Self::Special(special) => special.id(),
         }
     }
+    /// Strip any smart pointers wrapping this type, e.g. `Arc<Box<T>>` becomes `T`.
+    pub fn unwrap_pointers(&self) -> &Self {
+        match self {
+            Self::Special(SpecialRustType::Pointer { inner, .. }) => inner.unwrap_pointers(),
+            ty => ty,
+        }
+    }
+
     /// Check if the type is `Option<T>`
     pub fn is_optional(&self) -> bool {
-        matches!(self, Self::Special(SpecialRustType::Option(_)))
+        matches!(
+            self.unwrap_pointers(),
+            Self::Special(SpecialRustType::Option(_))
+        )
     }
 
     /// Check if the type is `Option<Option<T>>`
     pub fn is_double_optional(&self) -> bool {
-        match &self {
-            RustType::Special(SpecialRustType::Option(t)) => {
-                matches!(t.as_ref(), RustType::Special(SpecialRustType::Option(_)))
-            }
+        match self.unwrap_pointers() {
+            RustType::Special(SpecialRustType::Option(t)) => t.is_optional(),
             _ => false,
         }
     }
     /// Check if the type is `Vec<T>`
     pub fn is_vec(&self) -> bool {
-        matches!(self, Self::Special(SpecialRustType::Vec(_)))
+        matches!(
+            self.unwrap_pointers(),
+            Self::Special(SpecialRustType::Vec(_))
+        )
     }
     /// Check if the type is `HashMap<K, V>`
     pub fn is_hash_map(&self) -> bool {
-        matches!(self, Self::Special(SpecialRustType::HashMap(_, _)))
+        matches!(
+            self.unwrap_pointers(),
+            Self::Special(SpecialRustType::HashMap(_, _))
+        )
     }
     /// Get the generic parameters for this type. Returns an empty iterator if there are none.
     /// For example, `Vec<String>`'s generic parameters would be `[String]`.
@@ -485,6 +695,58 @@ impl RustType {
         }
     }
 
+    /// Mutable access to the generic parameters of this type, like [`RustType::parameters`].
+    pub(crate) fn parameters_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self> + '_> {
+        match self {
+            Self::Simple { .. } => Box::new(std::iter::empty()),
+            Self::Generic { parameters, .. } => Box::new(parameters.iter_mut()),
+            Self::Special(
+                SpecialRustType::Vec(ty)
+                | SpecialRustType::Array(ty, _)
+                | SpecialRustType::ConstLenArray(ty, _)
+                | SpecialRustType::Slice(ty)
+                | SpecialRustType::Option(ty)
+                | SpecialRustType::Pointer { inner: ty, .. },
+            ) => Box::new(std::iter::once(ty.as_mut())),
+            Self::Special(SpecialRustType::HashMap(key, value)) => {
+                Box::new([key.as_mut(), value.as_mut()].into_iter())
+            }
+            Self::Special(_) => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Turn well-known types such as `Uuid` back into plain references when a type
+    /// with the same name is in `local_types`, because it is defined in the crate or
+    /// imported from somewhere else, since that type is what the source refers to.
+    pub fn resolve_well_known_types(&mut self, local_types: &HashSet<String>) {
+        match self {
+            Self::Simple { .. } => (),
+            Self::Generic { parameters, .. } => parameters
+                .iter_mut()
+                .for_each(|ty| ty.resolve_well_known_types(local_types)),
+            Self::Special(special) if special.is_well_known() => {
+                if local_types.contains(special.id()) {
+                    *self = Self::Simple {
+                        id: special.id().to_owned(),
+                    };
+                }
+            }
+            Self::Special(
+                SpecialRustType::Vec(ty)
+                | SpecialRustType::Array(ty, _)
+                | SpecialRustType::ConstLenArray(ty, _)
+                | SpecialRustType::Slice(ty)
+                | SpecialRustType::Option(ty)
+                | SpecialRustType::Pointer { inner: ty, .. },
+            ) => ty.resolve_well_known_types(local_types),
+            Self::Special(SpecialRustType::HashMap(key, value)) => {
+                key.resolve_well_known_types(local_types);
+                value.resolve_well_known_types(local_types);
+            }
+            Self::Special(_) => (),
+        }
+    }
+
     /// Yield all the type names including nested generic types.
     pub fn all_reference_type_names(&self) -> impl Iterator<Item = &'_ str> + '_ {
         RustRefTypeIter {
@@ -546,13 +808,23 @@ impl SpecialRustType {
     /// Check if this type is equivalent to or contains `ty` in one of its generic parameters.
     pub fn contains_type(&self, ty: &str) -> bool {
         match &self {
-            Self::Vec(rty) | Self::Array(rty, _) | Self::Slice(rty) | Self::Option(rty) => {
-                rty.contains_type(ty)
-            }
+            Self::Vec(rty)
+            | Self::Array(rty, _)
+            | Self::ConstLenArray(rty, _)
+            | Self::Slice(rty)
+            | Self::Option(rty) => rty.contains_type(ty),
             Self::HashMap(rty1, rty2) => rty1.contains_type(ty) || rty2.contains_type(ty),
+            Self::Pointer { inner, .. } => inner.contains_type(ty),
             Self::Unit
             | Self::String
             | Self::DateTime
+            | Self::ChronoDateTime
+            | Self::NaiveDate
+            | Self::Duration
+            | Self::Uuid
+            | Self::Url
+            | Self::Decimal
+            | Self::Bytes
             | Self::Char
             | Self::I8
             | Self::I16
@@ -579,11 +851,19 @@ impl SpecialRustType {
             Self::F64 => "f64",
             Self::F32 => "f32",
             Self::Vec(_) => "Vec",
-            Self::Array(_, _) => "[]",
+            Self::Array(_, _) | Self::ConstLenArray(_, _) => "[]",
             Self::Slice(_) => "&[]",
             Self::Option(_) => "Option",
             Self::HashMap(_, _) => "HashMap",
+            Self::Pointer { kind, .. } => kind.id(),
             Self::DateTime => "OffsetDateTime",
+            Self::ChronoDateTime => "DateTime",
+            Self::NaiveDate => "NaiveDate",
+            Self::Duration => "Duration",
+            Self::Uuid => "Uuid",
+            Self::Url => "Url",
+            Self::Decimal => "Decimal",
+            Self::Bytes => "Bytes",
             Self::String => "String",
             Self::Char => "char",
             Self::Bool => "bool",
@@ -602,19 +882,61 @@ impl SpecialRustType {
         }
     }
 
+    /// Look up the well-known type a path refers to. A bare name like `Uuid` is taken to be
+    /// the well-known type of that name, since the parser cannot see where it was imported
+    /// from; [`RustType::resolve_well_known_types`] undoes that for names that turn out to
+    /// be defined or imported from somewhere else. Longer paths must match exactly, so
+    /// `time::Duration` is not taken for `std::time::Duration`.
+    pub fn well_known(path: &[String]) -> Option<Self> {
+        let full_path = path.join("::");
+        WELL_KNOWN_TYPES
+            .iter()
+            .find(|(well_known_path, _)| match path {
+                [name] => well_known_path.rsplit("::").next() == Some(name.as_str()),
+                _ => *well_known_path == full_path,
+            })
+            .map(|(_, special)| special.clone())
+    }
+
+    /// True for types from outside the standard library that get built-in defaults,
+    /// which the `type_mappings` of each language can still override.
+    pub fn is_well_known(&self) -> bool {
+        matches!(
+            self,
+            Self::DateTime
+                | Self::ChronoDateTime
+                | Self::NaiveDate
+                | Self::Duration
+                | Self::Uuid
+                | Self::Url
+                | Self::Decimal
+                | Self::Bytes
+        )
+    }
+
     /// Iterate over the generic parameters for this type. Returns an empty iterator
     /// if there are none.
     pub fn parameters(&self) -> Box<dyn Iterator<Item = &RustType> + '_> {
         match &self {
-            Self::Vec(rtype) | Self::Array(rtype, _) | Self::Slice(rtype) | Self::Option(rtype) => {
-                Box::new(std::iter::once(rtype.as_ref()))
-            }
+            Self::Vec(rtype)
+            | Self::Array(rtype, _)
+            | Self::ConstLenArray(rtype, _)
+            | Self::Slice(rtype)
+            | Self::Option(rtype)
+            | Self::Pointer { inner: rtype, .. } => Box::new(std::iter::once(rtype.as_ref())),
             Self::HashMap(rtype1, rtype2) => {
                 Box::new([rtype1.as_ref(), rtype2.as_ref()].into_iter())
             }
             Self::Unit
             | Self::String
             | Self::DateTime
+            | Self::ChronoDateTime
+            | Self::NaiveDate
+            | Self::Duration
+            | Self::Uuid
+            | Self::Url
+            | Self::Decimal
+            | Self::Bytes
             | Self::Char
             | Self::I8
             | Self::I16
@@ -637,6 +959,7 @@ impl SpecialRustType {
 
 /// Parsed information about a Rust enum definition
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustEnum {
     /// A unit enum
     ///
@@ -703,10 +1026,18 @@ impl RustEnum {
             Self::Unit(shared) | Self::Algebraic { shared, .. } => shared,
         }
     }
+
+    /// Get a mutable reference to the inner shared content
+    pub(crate) fn shared_mut(&mut self) -> &mut RustEnumShared {
+        match self {
+            Self::Unit(shared) | Self::Algebraic { shared, .. } => shared,
+        }
+    }
 }
 
 /// Enum information shared among different enum types
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustEnumShared {
     /// The enum's ident
     pub id: Id,
@@ -725,10 +1056,29 @@ pub struct RustEnumShared {
     pub is_recursive: bool,
     /// True if this enum contains data that needs to be redacted
     pub is_redacted: bool,
+    /// The integer type from `#[repr(...)]` when the enum is serialized as its
+    /// discriminant with `serde_repr` rather than as a string.
+    pub repr: Option<SpecialRustType>,
+}
+
+impl RustEnumShared {
+    /// True if the enum is serialized as its integer discriminant.
+    pub fn is_numeric(&self) -> bool {
+        self.repr.is_some()
+    }
+
+    /// The `#[serde(other)]` variant that unrecognized tags are decoded as, if any.
+    pub fn catch_all_variant(&self) -> Option<&RustEnumVariantShared> {
+        self.variants
+            .iter()
+            .map(RustEnumVariant::shared)
+            .find(|v| v.is_catch_all)
+    }
 }
 
 /// Parsed information about a Rust enum variant
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustEnumVariant {
     /// A unit variant
     Unit(RustEnumVariantShared),
@@ -761,11 +1111,19 @@ impl RustEnumVariant {
 
 /// Variant information shared among different variant types
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RustEnumVariantShared {
     /// The variant's ident
     pub id: Id,
     /// Comments applied to the variant
     pub comments: Vec<String>,
+    /// Additional names accepted when deserializing, from `#[serde(alias = "...")]`.
+    pub aliases: Vec<String>,
+    /// The integer discriminant of a unit variant, e.g. `3` for `Variant = 3`.
+    /// Variants without an explicit value follow Rust's rules and are one more than the previous.
+    pub discriminant: Option<i64>,
+    /// True for the `#[serde(other)]` variant that unrecognized tags are decoded as.
+    pub is_catch_all: bool,
 }
 
 /// An enum that encapsulates units of code generation for Typeshare.
@@ -0,0 +1,196 @@
+//! Finding and parsing the Rust source files to generate types from.
+use crate::{
+    context::{ParseContext, ParseFileContext},
+    crate_names::CrateNames,
+    error::GeneratorError,
+    language::{CrateName, SINGLE_FILE_CRATE_NAME},
+    parser::{self, ErrorInfo, ParsedData},
+    reconcile::reconcile_aliases,
+};
+use ignore::{overrides::OverrideBuilder, types::TypesBuilder, WalkBuilder, WalkState};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+};
+
+/// A walker over the Rust source files in the directories, in a stable order.
+/// Files ignored by `.gitignore` and other ignore files are skipped, along
+/// with hidden files and anything inside of `tools/typeshare/`.
+pub fn source_walker(
+    directories: &[PathBuf],
+    follow_links: bool,
+) -> Result<WalkBuilder, GeneratorError> {
+    let mut types = TypesBuilder::new();
+    types.add("rust", "*.rs")?;
+    types.select("rust");
+
+    let first_root = directories.first().ok_or(GeneratorError::NoInput)?;
+    let overrides = OverrideBuilder::new(first_root)
+        // Don't process files inside of tools/typeshare/
+        .add("!**/tools/typeshare/**")?
+        .build()?;
+    let mut walker_builder = WalkBuilder::new(first_root);
+    walker_builder
+        .sort_by_file_path(Path::cmp)
+        .types(types.build()?)
+        .overrides(overrides)
+        .follow_links(follow_links);
+    for root in directories.iter().skip(1) {
+        walker_builder.add(root);
+    }
+    Ok(walker_builder)
+}
+
+/// Parse every source file, merge them into their crates and reconcile the
+/// types of all crates.
+///
+/// Fails with every error in the sources when any of them can't be parsed.
+pub fn parse_sources(
+    parse_context: &ParseContext,
+    crate_names: &CrateNames,
+    walker_builder: WalkBuilder,
+) -> Result<BTreeMap<CrateName, ParsedData>, GeneratorError> {
+    let file_parsed_data = parallel_parse_files(parse_context, crate_names, walker_builder)?;
+    let mut crate_parsed_data = merge_crates(file_parsed_data.into_values());
+
+    reconcile_aliases(&mut crate_parsed_data);
+
+    check_parse_errors(&crate_parsed_data)?;
+    Ok(crate_parsed_data)
+}
+
+/// Fail with the errors of every crate, sorted by where they are.
+pub fn check_parse_errors(
+    crate_parsed_data: &BTreeMap<CrateName, ParsedData>,
+) -> Result<(), GeneratorError> {
+    let mut errors = crate_parsed_data
+        .values()
+        .flat_map(|parsed_data| &parsed_data.errors)
+        .cloned()
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    errors.sort_by(|a, b| {
+        (&a.file_name, a.start.line, a.start.column).cmp(&(
+            &b.file_name,
+            b.start.line,
+            b.start.column,
+        ))
+    });
+    Err(GeneratorError::Parse(errors))
+}
+
+/// Parse a single source file.
+pub fn parse_file(
+    parse_context: &ParseContext,
+    crate_names: &CrateNames,
+    path: &Path,
+) -> Result<Option<ParsedData>, GeneratorError> {
+    if path.is_dir() {
+        return Ok(None);
+    }
+
+    let (crate_name, crate_info) = if parse_context.multi_file {
+        let Some(crate_info) = crate_names.crate_of(path)? else {
+            return Ok(None);
+        };
+        let crate_name = if parse_context.per_module {
+            crate_info.name.module(crate_info.module_path(path))
+        } else {
+            crate_info.name.clone()
+        };
+        (crate_name, Some(crate_info))
+    } else {
+        (SINGLE_FILE_CRATE_NAME, None)
+    };
+
+    let parse_file_context = ParseFileContext {
+        source_code: std::fs::read_to_string(path).map_err(|source| GeneratorError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?,
+        crate_name: crate_name.clone(),
+        // The same parsed data is written for every target, which each
+        // assign their own file names.
+        file_name: String::new(),
+        file_path: path.to_path_buf(),
+    };
+
+    let file_name = path.to_string_lossy().into_owned();
+    match parser::parse(parse_context, parse_file_context) {
+        Ok(mut parsed_data) => {
+            if let (Some(parsed_data), Some(crate_info)) = (&mut parsed_data, crate_info) {
+                crate_info.resolve_imports(parsed_data);
+            }
+            Ok(parsed_data)
+        }
+        // Report files that can't be parsed along with the errors in every other file.
+        Err(error) => Ok(Some(ParsedData {
+            errors: vec![ErrorInfo::new(file_name, &error)],
+            ..ParsedData::new(crate_name, String::new(), parse_context.multi_file)
+        })),
+    }
+}
+
+/// Merge the parsed data of each file into the parsed data of its crate.
+pub fn merge_crates(
+    file_parsed_data: impl IntoIterator<Item = ParsedData>,
+) -> BTreeMap<CrateName, ParsedData> {
+    let mut crate_parsed_data: BTreeMap<CrateName, ParsedData> = BTreeMap::new();
+    for parsed_data in file_parsed_data {
+        let crate_name = parsed_data.crate_name.clone();
+        // Append each yielded parsed data by its respective crate.
+        *crate_parsed_data.entry(crate_name).or_default() += parsed_data;
+    }
+    crate_parsed_data
+}
+
+/// Use parallel builder to walk all source directories concurrently, keeping the
+/// parsed data of each file separate.
+pub fn parallel_parse_files(
+    parse_context: &ParseContext,
+    crate_names: &CrateNames,
+    walker_builder: WalkBuilder,
+) -> Result<BTreeMap<PathBuf, ParsedData>, GeneratorError> {
+    let (tx, rx) = mpsc::sync_channel::<Result<(PathBuf, ParsedData), GeneratorError>>(100);
+
+    let collector_thread = thread::spawn(move || {
+        let mut file_parsed_data = BTreeMap::new();
+
+        for result in rx {
+            let (path, parsed_data) = result?;
+            file_parsed_data.insert(path, parsed_data);
+        }
+
+        Ok(file_parsed_data)
+    });
+
+    walker_builder.build_parallel().run(|| {
+        let tx = tx.clone();
+
+        Box::new(move |result| {
+            let result = result.map_err(GeneratorError::from).and_then(|dir_entry| {
+                parse_file(parse_context, crate_names, dir_entry.path())
+                    .map(|parsed_data| parsed_data.map(|data| (dir_entry.into_path(), data)))
+            });
+            match result {
+                Ok(Some(parsed_data)) => {
+                    tx.send(Ok(parsed_data)).unwrap();
+                    WalkState::Continue
+                }
+                Ok(None) => WalkState::Continue,
+                Err(err) => {
+                    tx.send(Err(err)).unwrap();
+                    WalkState::Quit
+                }
+            }
+        })
+    });
+
+    drop(tx);
+    collector_thread.join().unwrap()
+}
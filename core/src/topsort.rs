@@ -51,6 +51,9 @@ fn get_dependencies_from_type(
             SpecialRustType::Vec(inner) => {
                 get_dependencies_from_type(inner, types, res, seen);
             }
+            SpecialRustType::Pointer { inner, .. } => {
+                get_dependencies_from_type(inner, types, res, seen);
+            }
             _ => {}
         },
     };
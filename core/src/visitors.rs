@@ -1,18 +1,24 @@
 //! Visitors to collect various items from the AST.
 use crate::{
+    const_generics::const_generic_kinds,
     context::ParseContext,
+    default_values::DefaultValues,
     error::ParseErrorWithSpan,
-    language::CrateName,
+    language::{CrateName, MODULE_SEPARATOR},
     parser::{
         has_typeshare_annotation, parse_const, parse_enum, parse_struct, parse_type_alias,
         ErrorInfo, ParsedData,
     },
-    rust_types::{RustEnumVariant, RustItem},
+    rust_types::{RustEnumVariant, RustItem, SpecialRustType},
     target_os_check::accept_target_os,
 };
 use log::debug;
-use std::{collections::HashSet, ops::Not, path::PathBuf};
-use syn::{visit::Visit, Attribute, ItemUse, UseTree};
+use std::{borrow::Cow, collections::HashSet, ops::Not, path::PathBuf};
+use syn::{
+    visit::Visit,
+    visit_mut::{self, VisitMut},
+    Attribute, ItemUse, UseTree,
+};
 
 /// List of some popular crate names that we can ignore
 /// during import parsing.
@@ -44,7 +50,10 @@ const IGNORED_BASE_CRATES: &[&str] = &[
 ];
 
 /// List of reference types or imported types we can ignore during import parsing.
-const IGNORED_TYPES: &[&str] = &["Option", "String", "Vec", "HashMap", "T", "I54", "U53"];
+const IGNORED_TYPES: &[&str] = &[
+    "Option", "String", "Vec", "HashMap", "T", "I54", "U53", "Box", "Rc", "Arc", "Weak", "RcWeak",
+    "ArcWeak", "Cow", "Cell", "RefCell", "Mutex", "RwLock",
+];
 
 /// An import visitor that collects all use or
 /// qualified referenced items.
@@ -52,6 +61,12 @@ pub struct TypeShareVisitor<'a> {
     parsed_data: ParsedData,
     file_path: PathBuf,
     parse_context: &'a ParseContext<'a>,
+    default_values: DefaultValues,
+    /// The modules declared in the file.
+    child_modules: HashSet<String>,
+    /// Names in the file that refer to something other than the well-known type of
+    /// that name.
+    shadowing_names: HashSet<String>,
 }
 
 impl<'a> TypeShareVisitor<'a> {
@@ -66,9 +81,18 @@ impl<'a> TypeShareVisitor<'a> {
             parsed_data: ParsedData::new(crate_name, file_name, parse_context.multi_file),
             file_path,
             parse_context,
+            default_values: DefaultValues::default(),
+            child_modules: HashSet::new(),
+            shadowing_names: HashSet::new(),
         }
     }
 
+    /// Resolve `serde(default)` values using the functions and impls in `default_values`.
+    pub(crate) fn with_default_values(mut self, default_values: DefaultValues) -> Self {
+        self.default_values = default_values;
+        self
+    }
+
     #[inline]
     /// Consume the visitor and return parsed data.
     pub fn parsed_data(self) -> Option<ParsedData> {
@@ -83,14 +107,39 @@ impl<'a> TypeShareVisitor<'a> {
         })
     }
 
+    /// Qualify the names in an item that shadow well-known types, so they are parsed
+    /// as the types the file refers to.
+    fn qualify_shadowing_names<'i, T: Clone>(
+        &self,
+        item: &'i T,
+        visit: impl FnOnce(&mut QualifyShadowingNames<'_>, &mut T),
+    ) -> Cow<'i, T> {
+        if self.shadowing_names.is_empty() {
+            return Cow::Borrowed(item);
+        }
+        let mut item = item.clone();
+        visit(&mut QualifyShadowingNames(&self.shadowing_names), &mut item);
+        Cow::Owned(item)
+    }
+
+    /// Remember which generic parameters of a type are const, to drop their arguments
+    /// wherever the type is referenced.
+    fn record_const_generics(&mut self, ident: &syn::Ident, generics: &syn::Generics) {
+        if let Some(kinds) = const_generic_kinds(generics) {
+            self.parsed_data
+                .const_generics
+                .insert(ident.to_string(), kinds);
+        }
+    }
+
     #[inline]
     fn collect_result(&mut self, result: Result<RustItem, ParseErrorWithSpan>) {
         match result {
             Ok(data) => self.parsed_data.push(data),
-            Err(error) => self.parsed_data.errors.push(ErrorInfo {
-                file_name: self.file_path.to_string_lossy().into_owned(),
-                error: error.to_string(),
-            }),
+            Err(error) => self.parsed_data.errors.push(ErrorInfo::new(
+                self.file_path.to_string_lossy().into_owned(),
+                &error,
+            )),
         }
     }
 
@@ -200,6 +249,14 @@ impl<'a> TypeShareVisitor<'a> {
     fn target_os_accepted(&self, attrs: &[Attribute]) -> bool {
         accept_target_os(attrs, &self.parse_context.target_os)
     }
+
+    fn path_resolver(&self) -> PathResolver<'_> {
+        PathResolver {
+            crate_name: &self.parsed_data.crate_name,
+            per_module: self.parse_context.per_module,
+            child_modules: &self.child_modules,
+        }
+    }
 }
 
 impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
@@ -226,6 +283,12 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
             //
             let crate_candidate = p.segments.first()?.ident.to_string();
             let type_candidate = p.segments.last()?.ident.to_string();
+            let path = p
+                .segments
+                .iter()
+                .take(p.segments.len() - 1)
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>();
 
             (accept_crate(&crate_candidate)
                 && accept_type(&type_candidate)
@@ -234,20 +297,9 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
                     .ignored_types
                     .contains(&type_candidate.as_str())
                 && crate_candidate != type_candidate)
-                .then(|| {
-                    // resolve crate and super aliases into the crate name.
-                    let base_crate = if crate_candidate == "crate"
-                        || crate_candidate == "super"
-                        || crate_candidate == "self"
-                    {
-                        self.parsed_data.crate_name.to_string()
-                    } else {
-                        crate_candidate
-                    };
-                    ImportedType {
-                        base_crate: CrateName::from(base_crate),
-                        type_name: type_candidate,
-                    }
+                .then(|| ImportedType {
+                    base_crate: self.path_resolver().resolve(&path),
+                    type_name: type_candidate,
                 })
         };
 
@@ -262,14 +314,15 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
         if !self.parsed_data.multi_file {
             return;
         }
-        self.parsed_data
-            .import_types
-            .extend(parse_import(i, &self.parsed_data.crate_name).filter(|imp| {
+        let import_types = parse_import(i, self.path_resolver())
+            .filter(|imp| {
                 !self
                     .parse_context
                     .ignored_types
                     .contains(&imp.type_name.as_str())
-            }));
+            })
+            .collect::<Vec<_>>();
+        self.parsed_data.import_types.extend(import_types);
         syn::visit::visit_item_use(self, i);
     }
 
@@ -278,7 +331,13 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_struct(i, &self.parse_context.target_os));
+            self.record_const_generics(&i.ident, &i.generics);
+            let i = self.qualify_shadowing_names(i, |v, i| v.visit_item_struct_mut(i));
+            self.collect_result(parse_struct(
+                &i,
+                &self.parse_context.target_os,
+                &self.default_values,
+            ));
         }
 
         syn::visit::visit_item_struct(self, i);
@@ -289,7 +348,9 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_enum(i, &self.parse_context.target_os));
+            self.record_const_generics(&i.ident, &i.generics);
+            let i = self.qualify_shadowing_names(i, |v, i| v.visit_item_enum_mut(i));
+            self.collect_result(parse_enum(&i, &self.parse_context.target_os));
         }
 
         syn::visit::visit_item_enum(self, i);
@@ -300,7 +361,9 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_type_alias(i));
+            self.record_const_generics(&i.ident, &i.generics);
+            let i = self.qualify_shadowing_names(i, |v, i| v.visit_item_type_mut(i));
+            self.collect_result(parse_type_alias(&i));
         }
 
         syn::visit::visit_item_type(self, i);
@@ -329,6 +392,17 @@ impl<'ast> Visit<'ast> for TypeShareVisitor<'_> {
     // }
 
     fn visit_file(&mut self, i: &'ast syn::File) {
+        let mut shadowing_names = ShadowingNames::default();
+        shadowing_names.visit_file(i);
+        self.shadowing_names = shadowing_names.0;
+        self.child_modules = i
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Mod(item_mod) => Some(item_mod.ident.to_string()),
+                _ => None,
+            })
+            .collect();
         if self.target_os_accepted(&i.attrs) {
             syn::visit::visit_file(self, i);
         }
@@ -358,6 +432,7 @@ pub(crate) fn accept_type(type_name: &str) -> bool {
 /// An imported type reference.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(test, derive(Ord, PartialOrd))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImportedType {
     /// Crate this type belongs to.
     pub base_crate: CrateName,
@@ -365,41 +440,75 @@ pub struct ImportedType {
     pub type_name: String,
 }
 
-struct ItemUseIter<'a> {
-    use_tree: Vec<&'a UseTree>,
+/// Resolves the paths used in a file to the crates they lead to, or with per
+/// module output to the modules.
+#[derive(Clone, Copy)]
+struct PathResolver<'a> {
+    /// The crate, or with per module output the module, of the file.
     crate_name: &'a CrateName,
-    base_name: Option<String>,
+    per_module: bool,
+    child_modules: &'a HashSet<String>,
 }
 
-impl<'a> ItemUseIter<'a> {
-    pub fn new(use_tree: &'a UseTree, crate_name: &'a CrateName) -> Self {
-        Self {
-            use_tree: vec![use_tree],
-            crate_name,
-            base_name: None,
+impl PathResolver<'_> {
+    /// Resolve a path leading up to an item, not including the item itself.
+    fn resolve(&self, path: &[String]) -> CrateName {
+        let Some(first) = path.first() else {
+            return self.crate_name.clone();
+        };
+        if !self.per_module {
+            return if first == "crate" || first == "super" || first == "self" {
+                self.crate_name.clone()
+            } else {
+                CrateName::from(first.as_str())
+            };
         }
-    }
 
-    fn resolve_crate_name(&self) -> CrateName {
-        let crate_name = self.base_name();
-        if crate_name == "crate" || crate_name == "super" || crate_name == "self" {
-            self.crate_name.clone()
-        } else {
-            CrateName::from(crate_name)
-        }
+        let mut module = self
+            .crate_name
+            .segments()
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        let rest = match first.as_str() {
+            "crate" => {
+                module.truncate(1);
+                &path[1..]
+            }
+            "self" => &path[1..],
+            "super" => {
+                let supers = path
+                    .iter()
+                    .take_while(|segment| *segment == "super")
+                    .count();
+                // The crate root is as far up as `super` goes.
+                module.truncate(module.len().saturating_sub(supers).max(1));
+                &path[supers..]
+            }
+            // A module declared in this file, which needs no `self::` prefix.
+            name if self.child_modules.contains(name) => path,
+            // Another crate.
+            _ => {
+                module.clear();
+                path
+            }
+        };
+        module.extend(rest.iter().cloned());
+        CrateName::from(module.join(MODULE_SEPARATOR))
     }
+}
 
-    fn add_name(&mut self, ident: &syn::Ident) {
-        if self.base_name.is_none() {
-            self.base_name = Some(ident.to_string());
-        }
-    }
+struct ItemUseIter<'a> {
+    /// The use trees left to visit, with the path leading up to each of them.
+    use_tree: Vec<(&'a UseTree, Vec<String>)>,
+    resolver: PathResolver<'a>,
+}
 
-    fn base_name(&self) -> String {
-        self.base_name
-            .as_ref()
-            .cloned()
-            .expect("base name not in use statement?")
+impl<'a> ItemUseIter<'a> {
+    pub fn new(use_tree: &'a UseTree, resolver: PathResolver<'a>) -> Self {
+        Self {
+            use_tree: vec![(use_tree, Vec::new())],
+            resolver,
+        }
     }
 }
 
@@ -407,18 +516,19 @@ impl Iterator for ItemUseIter<'_> {
     type Item = ImportedType;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(use_tree) = self.use_tree.pop() {
+        while let Some((use_tree, mut path)) = self.use_tree.pop() {
             match use_tree {
-                syn::UseTree::Path(path) => {
-                    self.add_name(&path.ident);
-                    self.use_tree.push(&path.tree);
+                syn::UseTree::Path(use_path) => {
+                    path.push(use_path.ident.to_string());
+                    self.use_tree.push((&use_path.tree, path));
                 }
                 syn::UseTree::Name(name) => {
                     let type_name = name.ident.to_string();
-                    let base_crate = self.resolve_crate_name();
-                    if accept_crate(base_crate.as_str()) && accept_type(&type_name) {
+                    if path.first().is_some_and(|base| accept_crate(base))
+                        && accept_type(&type_name)
+                    {
                         return Some(ImportedType {
-                            base_crate,
+                            base_crate: self.resolver.resolve(&path),
                             type_name,
                         });
                     }
@@ -427,16 +537,16 @@ impl Iterator for ItemUseIter<'_> {
                     // TODO: I need to do something here.
                 }
                 syn::UseTree::Glob(_) => {
-                    let base_crate = self.resolve_crate_name();
-                    if accept_crate(base_crate.as_str()) {
+                    if path.first().is_some_and(|base| accept_crate(base)) {
                         return Some(ImportedType {
-                            base_crate,
+                            base_crate: self.resolver.resolve(&path),
                             type_name: "*".into(),
                         });
                     }
                 }
                 syn::UseTree::Group(g) => {
-                    self.use_tree.extend(g.items.iter());
+                    self.use_tree
+                        .extend(g.items.iter().map(|item| (item, path.clone())));
                 }
             }
         }
@@ -445,19 +555,106 @@ impl Iterator for ItemUseIter<'_> {
     }
 }
 
+/// Collects the names in a file that shadow well-known types: the types defined in it
+/// and the types imported from somewhere other than the well-known path.
+#[derive(Default)]
+struct ShadowingNames(HashSet<String>);
+
+impl ShadowingNames {
+    fn insert_local(&mut self, ident: &syn::Ident) {
+        let name = ident.to_string();
+        if SpecialRustType::well_known(std::slice::from_ref(&name)).is_some() {
+            self.0.insert(name);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ShadowingNames {
+    fn visit_item_use(&mut self, i: &'ast ItemUse) {
+        let mut imported_names = Vec::new();
+        collect_imported_names(&i.tree, Vec::new(), &mut imported_names);
+        self.0
+            .extend(imported_names.into_iter().filter_map(|(name, path)| {
+                let well_known = SpecialRustType::well_known(std::slice::from_ref(&name));
+                (well_known.is_some() && well_known != SpecialRustType::well_known(&path))
+                    .then_some(name)
+            }));
+    }
+
+    fn visit_item_struct(&mut self, i: &'ast syn::ItemStruct) {
+        self.insert_local(&i.ident);
+    }
+
+    fn visit_item_enum(&mut self, i: &'ast syn::ItemEnum) {
+        self.insert_local(&i.ident);
+    }
+
+    fn visit_item_type(&mut self, i: &'ast syn::ItemType) {
+        self.insert_local(&i.ident);
+    }
+}
+
+/// Prefixes references to shadowing names with `self::`, which the parser does not
+/// take for a well-known type.
+struct QualifyShadowingNames<'a>(&'a HashSet<String>);
+
+impl VisitMut for QualifyShadowingNames<'_> {
+    fn visit_type_path_mut(&mut self, i: &mut syn::TypePath) {
+        if i.qself.is_none()
+            && i.path.leading_colon.is_none()
+            && i.path.segments.len() == 1
+            && self.0.contains(&i.path.segments[0].ident.to_string())
+        {
+            i.path.segments.insert(
+                0,
+                syn::PathSegment::from(syn::Ident::new("self", i.path.segments[0].ident.span())),
+            );
+        }
+        visit_mut::visit_type_path_mut(self, i);
+    }
+}
+
+/// Collect the names a use tree brings into scope, with the full paths they refer to.
+fn collect_imported_names(
+    use_tree: &UseTree,
+    mut path: Vec<String>,
+    names: &mut Vec<(String, Vec<String>)>,
+) {
+    match use_tree {
+        UseTree::Path(use_path) => {
+            path.push(use_path.ident.to_string());
+            collect_imported_names(&use_path.tree, path, names);
+        }
+        UseTree::Name(name) => {
+            path.push(name.ident.to_string());
+            names.push((name.ident.to_string(), path));
+        }
+        UseTree::Rename(rename) => {
+            path.push(rename.ident.to_string());
+            names.push((rename.rename.to_string(), path));
+        }
+        UseTree::Glob(_) => (),
+        UseTree::Group(group) => group
+            .items
+            .iter()
+            .for_each(|item| collect_imported_names(item, path.clone(), names)),
+    }
+}
+
 fn parse_import<'a>(
     item_use: &'a ItemUse,
-    crate_name: &'a CrateName,
+    resolver: PathResolver<'a>,
 ) -> impl Iterator<Item = ImportedType> + 'a {
-    ItemUseIter::new(&item_use.tree, crate_name)
+    ItemUseIter::new(&item_use.tree, resolver)
 }
 
 #[cfg(test)]
 mod test {
-    use super::{parse_import, TypeShareVisitor};
+    use super::{parse_import, PathResolver, TypeShareVisitor};
     use crate::{context::ParseContext, visitors::ImportedType};
     use cool_asserts::assert_matches;
     use itertools::Itertools;
+    use std::collections::HashSet;
     use syn::{visit::Visit, File};
 
     #[test]
@@ -476,7 +673,15 @@ mod test {
             .iter()
             .flat_map(|item| {
                 if let syn::Item::Use(use_item) = item {
-                    parse_import(use_item, &"my_crate".into()).collect()
+                    parse_import(
+                        use_item,
+                        PathResolver {
+                            crate_name: &"my_crate".into(),
+                            per_module: false,
+                            child_modules: &HashSet::new(),
+                        },
+                    )
+                    .collect()
                 } else {
                     Vec::new()
                 }
@@ -544,7 +749,15 @@ mod test {
             .iter()
             .flat_map(|item| {
                 if let syn::Item::Use(use_item) = item {
-                    parse_import(use_item, &"my_crate".into()).collect()
+                    parse_import(
+                        use_item,
+                        PathResolver {
+                            crate_name: &"my_crate".into(),
+                            per_module: false,
+                            child_modules: &HashSet::new(),
+                        },
+                    )
+                    .collect()
                 } else {
                     Vec::new()
                 }
@@ -629,6 +842,7 @@ mod test {
         let parse_context = ParseContext {
             ignored_types: Vec::new(),
             multi_file: true,
+            per_module: false,
             target_os: Vec::new(),
         };
 
@@ -699,4 +913,60 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_per_module_paths() {
+        let rust_code = "
+            mod nested;
+
+            use crate::types::{MyType, MyEnum};
+            use super::sibling::SiblingType;
+            use super::super::RootType;
+            use self::nested::NestedType;
+            use nested::deeper::DeepType;
+            use other_crate::module::*;
+
+            struct S {
+                f: other_crate::Qualified
+            }
+            ";
+
+        let parse_context = ParseContext {
+            multi_file: true,
+            per_module: true,
+            ..Default::default()
+        };
+
+        let file: File = syn::parse_str(rust_code).unwrap();
+        let mut visitor = TypeShareVisitor::new(
+            &parse_context,
+            "my_crate::a::b".into(),
+            "my_file".into(),
+            "file_path".into(),
+        );
+        visitor.visit_file(&file);
+
+        let sorted_imports = visitor
+            .parsed_data
+            .import_types
+            .into_iter()
+            .map(|import| (import.type_name, import.base_crate.to_string()))
+            .sorted()
+            .collect_vec();
+
+        assert_eq!(
+            sorted_imports,
+            [
+                ("*", "other_crate::module"),
+                ("DeepType", "my_crate::a::b::nested::deeper"),
+                ("MyEnum", "my_crate::types"),
+                ("MyType", "my_crate::types"),
+                ("NestedType", "my_crate::a::b::nested"),
+                ("Qualified", "other_crate"),
+                ("RootType", "my_crate"),
+                ("SiblingType", "my_crate::a::sibling"),
+            ]
+            .map(|(type_name, base_crate)| (type_name.to_owned(), base_crate.to_owned()))
+        );
+    }
 }
@@ -0,0 +1,563 @@
+//! Writing generated files, along with the manifest of the files written to
+//! an output folder.
+use crate::{
+    error::GeneratorError,
+    language::{
+        all_types, CrateName, CrateTypes, Language, SupportedLanguage, SINGLE_FILE_CRATE_NAME,
+    },
+    parser::ParsedData,
+};
+use log::info;
+use similar::TextDiff;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where generated types are written.
+#[derive(Debug, Clone, Copy)]
+pub enum Output<'a> {
+    File(&'a Path),
+    Folder(&'a Path),
+}
+
+/// What to do with generated files whose contents differ from the files on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Write the new contents.
+    Write,
+    /// Leave the files alone and print a diff of the changes instead.
+    Check,
+}
+
+/// Options for writing generated files.
+#[derive(Debug, Clone, Copy)]
+pub struct WriteOptions<'a> {
+    pub mode: WriteMode,
+    /// Delete files in the output folder that were generated by an earlier run
+    /// but aren't generated anymore.
+    pub prune: bool,
+    /// Tells the files of this target apart from the files of other targets
+    /// writing to the same folder in its manifest.
+    pub owner: &'a str,
+    /// Only generate the files of these crates, leaving the others as they are.
+    pub crates: Option<&'a HashSet<CrateName>>,
+}
+
+/// The file in an output folder listing the files typeshare generated there,
+/// one per line after the owner of the file and a tab.
+const MANIFEST_FILE_NAME: &str = ".typeshare-manifest";
+
+/// Write the parsed data to the one or more files depending on the destination.
+///
+/// Returns the files that were out of date.
+pub fn write_generated(
+    destination: Output<'_>,
+    lang: &mut (impl Language + ?Sized),
+    language_type: SupportedLanguage,
+    mut crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    options: WriteOptions<'_>,
+) -> Result<Vec<PathBuf>, GeneratorError> {
+    let ignored_types = lang.ignored_reference_types();
+    for parsed_data in crate_parsed_data.values_mut() {
+        parsed_data
+            .import_types
+            .retain(|import| !ignored_types.contains(&import.type_name.as_str()));
+    }
+    for (crate_name, parsed_data) in &mut crate_parsed_data {
+        parsed_data.file_name = language_type.output_file_name(crate_name);
+    }
+
+    info!(
+        "typeshare started writing {} generated types for {:?}",
+        crate_parsed_data.len(),
+        language_type
+    );
+
+    lang.pre_generation(&crate_parsed_data);
+    match destination {
+        Output::File(path) => write_single_file(lang, path, crate_parsed_data, options.mode),
+        Output::Folder(path) => {
+            // Collect all the types into a map of the file name they
+            // belong too and the list of type names. Used for generating
+            // imports in generated files.
+            let import_candidates = all_types(&mut crate_parsed_data);
+            write_multiple_files(lang, path, crate_parsed_data, import_candidates, options)
+        }
+    }
+}
+
+/// Write files generated outside of typeshare, such as by a plugin. Their paths
+/// are relative to the output folder, and a single output file takes the one
+/// file generated for it whatever its path.
+///
+/// Returns the files that were out of date.
+pub fn write_files(
+    destination: Output<'_>,
+    files: Vec<(PathBuf, Vec<u8>)>,
+    options: WriteOptions<'_>,
+) -> Result<Vec<PathBuf>, GeneratorError> {
+    match destination {
+        Output::File(path) => {
+            let mut files = files.into_iter();
+            let (Some((_, output)), None) = (files.next(), files.next()) else {
+                return Err(GeneratorError::FileCount(path.to_path_buf()));
+            };
+            let out_of_date = check_write_file(path, output, options.mode)?;
+            Ok(out_of_date
+                .then(|| path.to_path_buf())
+                .into_iter()
+                .collect())
+        }
+        Output::Folder(output_folder) => {
+            let mut out_of_date = Vec::new();
+            let mut generated_files = BTreeSet::new();
+            for (file_name, output) in files {
+                let outfile = output_folder.join(&file_name);
+                if !output.is_empty() {
+                    generated_files.insert(file_name.to_string_lossy().into_owned());
+                }
+                if check_write_file(&outfile, output, options.mode)? {
+                    out_of_date.push(outfile);
+                }
+            }
+            out_of_date.extend(update_manifest(output_folder, generated_files, options)?);
+            Ok(out_of_date)
+        }
+    }
+}
+
+/// Write multiple module files.
+fn write_multiple_files(
+    lang: &mut (impl Language + ?Sized),
+    output_folder: &Path,
+    crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    import_candidates: CrateTypes,
+    options: WriteOptions<'_>,
+) -> Result<Vec<PathBuf>, GeneratorError> {
+    let mut out_of_date = Vec::new();
+    let mut generated_files = BTreeSet::new();
+    for (crate_name, parsed_data) in crate_parsed_data {
+        let outfile = Path::new(output_folder).join(&parsed_data.file_name);
+        if options
+            .crates
+            .is_some_and(|crates| !crates.contains(&crate_name))
+        {
+            if outfile.exists() {
+                generated_files.insert(parsed_data.file_name);
+            }
+            continue;
+        }
+
+        let file_name = parsed_data.file_name.clone();
+        let mut generated_contents = Vec::new();
+        lang.generate_types(&mut generated_contents, &import_candidates, parsed_data)
+            .map_err(|source| GeneratorError::Io {
+                path: outfile.clone(),
+                source,
+            })?;
+        if !generated_contents.is_empty() {
+            generated_files.insert(file_name);
+        }
+        if check_write_file(&outfile, generated_contents, options.mode)? {
+            out_of_date.push(outfile);
+        }
+    }
+
+    out_of_date.extend(update_manifest(output_folder, generated_files, options)?);
+
+    // Support files are written straight to disk by the language, so they
+    // can't be checked.
+    if options.mode == WriteMode::Write {
+        lang.post_generation(&output_folder.as_os_str().to_string_lossy())?;
+    }
+
+    Ok(out_of_date)
+}
+
+/// Record the generated files in the manifest of the output folder, deleting the
+/// files it listed before that weren't generated this time unless pruning is off.
+///
+/// Returns the stale files.
+fn update_manifest(
+    output_folder: &Path,
+    generated_files: BTreeSet<String>,
+    options: WriteOptions<'_>,
+) -> Result<Vec<PathBuf>, GeneratorError> {
+    let manifest_path = output_folder.join(MANIFEST_FILE_NAME);
+    let manifest = match fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(source) => {
+            return Err(GeneratorError::Io {
+                path: manifest_path,
+                source,
+            })
+        }
+    };
+
+    // Files of other targets are kept as they are.
+    let (listed_files, mut entries): (BTreeSet<_>, BTreeSet<_>) = manifest
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(owner, file_name)| (owner.to_owned(), file_name.to_owned()))
+        .partition(|(owner, _)| owner == options.owner);
+    let listed_files = listed_files
+        .into_iter()
+        .map(|(_, file_name)| file_name)
+        .collect::<BTreeSet<_>>();
+
+    let mut stale_files = Vec::new();
+    for file_name in listed_files.difference(&generated_files) {
+        let stale_file = output_folder.join(file_name);
+        if !stale_file.exists() {
+            continue;
+        }
+        if !options.prune {
+            // Keep it listed so that a later run can still delete it.
+            entries.insert((options.owner.to_owned(), file_name.clone()));
+            continue;
+        }
+
+        match options.mode {
+            WriteMode::Write => {
+                info!("Removing stale file {stale_file:?}");
+                fs::remove_file(&stale_file).map_err(io_error(&stale_file))?;
+            }
+            WriteMode::Check => {
+                let existing = fs::read(&stale_file).map_err(io_error(&stale_file))?;
+                print_diff(&stale_file, &existing, &[]);
+            }
+        }
+        stale_files.push(stale_file);
+    }
+
+    if options.mode == WriteMode::Check {
+        return Ok(stale_files);
+    }
+
+    entries.extend(
+        generated_files
+            .into_iter()
+            .map(|file_name| (options.owner.to_owned(), file_name)),
+    );
+    let mut contents = entries
+        .into_iter()
+        .map(|(owner, file_name)| format!("{owner}\t{file_name}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if contents.is_empty() {
+        if manifest_path.exists() {
+            fs::remove_file(&manifest_path).map_err(io_error(&manifest_path))?;
+        }
+    } else if contents != manifest.trim_end() {
+        contents.push('\n');
+        fs::write(&manifest_path, contents).map_err(io_error(&manifest_path))?;
+    }
+
+    Ok(stale_files)
+}
+
+/// Write the file if the contents have changed, or print the changes in check mode.
+///
+/// Returns whether the file was out of date.
+fn check_write_file(
+    outfile: &Path,
+    output: Vec<u8>,
+    mode: WriteMode,
+) -> Result<bool, GeneratorError> {
+    let existing = fs::read(outfile).ok();
+    match &existing {
+        Some(buf) if *buf == output => {
+            // avoid writing the file to leave the mtime intact
+            // for tools which might use it to know when to
+            // rebuild.
+            info!("Skipping writing to {outfile:?} no changes");
+            return Ok(false);
+        }
+        _ => {}
+    }
+
+    if output.is_empty() {
+        return Ok(false);
+    }
+
+    if mode == WriteMode::Check {
+        print_diff(outfile, existing.as_deref().unwrap_or_default(), &output);
+        return Ok(true);
+    }
+
+    // If the output directory doesn't already exist, create it.
+    if let Some(out_dir) = outfile
+        .parent()
+        .filter(|out_dir| !out_dir.as_os_str().is_empty() && !out_dir.exists())
+    {
+        fs::create_dir_all(out_dir).map_err(io_error(out_dir))?;
+    }
+
+    fs::write(outfile, output).map_err(io_error(outfile))?;
+    Ok(true)
+}
+
+/// Attach the path of a file to an error accessing it.
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> GeneratorError + '_ {
+    |source| GeneratorError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// Print a unified diff from the existing contents of a file to the generated ones.
+fn print_diff(outfile: &Path, existing: &[u8], output: &[u8]) {
+    let existing = String::from_utf8_lossy(existing);
+    let output = String::from_utf8_lossy(output);
+    let path = outfile.to_string_lossy();
+
+    print!(
+        "{}",
+        TextDiff::from_lines(&existing, &output)
+            .unified_diff()
+            .header(&format!("a/{path}"), &format!("b/{path}"))
+    );
+}
+
+/// Write all types to a single file.
+fn write_single_file(
+    lang: &mut (impl Language + ?Sized),
+    file_name: &Path,
+    mut crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    mode: WriteMode,
+) -> Result<Vec<PathBuf>, GeneratorError> {
+    let outfile = Path::new(file_name).to_path_buf();
+    let parsed_data = crate_parsed_data
+        .remove(&SINGLE_FILE_CRATE_NAME)
+        .ok_or_else(|| GeneratorError::NoTypes(outfile.clone()))?;
+
+    let mut output = Vec::new();
+    lang.generate_types(&mut output, &HashMap::new(), parsed_data)
+        .map_err(io_error(&outfile))?;
+
+    let out_of_date = check_write_file(&outfile, output, mode)?;
+    Ok(out_of_date.then_some(outfile).into_iter().collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::language::TypeScript;
+
+    fn output_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("typeshare_writer_{name}"));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    fn options(mode: WriteMode) -> WriteOptions<'static> {
+        WriteOptions {
+            mode,
+            prune: true,
+            owner: "test",
+            crates: None,
+        }
+    }
+
+    fn write(path: &Path, contents: &str, mode: WriteMode) -> Vec<PathBuf> {
+        write_files(
+            Output::File(path),
+            vec![(PathBuf::new(), contents.into())],
+            options(mode),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn up_to_date_file() {
+        let dir = output_dir("up_to_date");
+        let path = dir.join("types.ts");
+        fs::write(&path, "current").unwrap();
+
+        assert!(write(&path, "current", WriteMode::Check).is_empty());
+        assert!(write(&path, "current", WriteMode::Write).is_empty());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "current");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn out_of_date_file() {
+        let dir = output_dir("out_of_date");
+        let path = dir.join("types.ts");
+        fs::write(&path, "old").unwrap();
+
+        assert_eq!(write(&path, "new", WriteMode::Check), vec![path.clone()]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+
+        assert_eq!(write(&path, "new", WriteMode::Write), vec![path.clone()]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(write(&path, "new", WriteMode::Check).is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_file() {
+        let dir = output_dir("missing");
+        let path = dir.join("generated").join("types.ts");
+
+        assert_eq!(write(&path, "new", WriteMode::Check), vec![path.clone()]);
+        assert!(!path.exists());
+
+        assert_eq!(write(&path, "new", WriteMode::Write), vec![path.clone()]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn out_of_date_files_in_folder() {
+        let dir = output_dir("folder");
+        fs::write(dir.join("current.ts"), "current").unwrap();
+        fs::write(dir.join("old.ts"), "old").unwrap();
+        let files = || {
+            vec![
+                (PathBuf::from("current.ts"), b"current".to_vec()),
+                (PathBuf::from("old.ts"), b"new".to_vec()),
+                (PathBuf::from("missing.ts"), b"new".to_vec()),
+            ]
+        };
+
+        let out_of_date = write_files(Output::Folder(&dir), files(), options(WriteMode::Check));
+        assert_eq!(
+            out_of_date.unwrap(),
+            [dir.join("old.ts"), dir.join("missing.ts")]
+        );
+        assert!(!dir.join("missing.ts").exists());
+
+        write_files(Output::Folder(&dir), files(), options(WriteMode::Write)).unwrap();
+        let out_of_date = write_files(Output::Folder(&dir), files(), options(WriteMode::Check));
+        assert!(out_of_date.unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn folder_files(names: &[&str]) -> Vec<(PathBuf, Vec<u8>)> {
+        names
+            .iter()
+            .map(|name| (PathBuf::from(name), b"types".to_vec()))
+            .collect()
+    }
+
+    fn manifest(dir: &Path) -> String {
+        fs::read_to_string(dir.join(MANIFEST_FILE_NAME)).unwrap_or_default()
+    }
+
+    #[test]
+    fn stale_files_are_removed() {
+        let dir = output_dir("stale");
+        let options = options(WriteMode::Write);
+        write_files(
+            Output::Folder(&dir),
+            folder_files(&["a.ts", "b.ts"]),
+            options,
+        )
+        .unwrap();
+        assert_eq!(manifest(&dir), "test\ta.ts\ntest\tb.ts\n");
+
+        let out_of_date = write_files(Output::Folder(&dir), folder_files(&["a.ts"]), options);
+        assert_eq!(out_of_date.unwrap(), vec![dir.join("b.ts")]);
+        assert!(!dir.join("b.ts").exists());
+        assert_eq!(manifest(&dir), "test\ta.ts\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stale_files_are_kept_without_pruning() {
+        let dir = output_dir("no_prune");
+        let options = WriteOptions {
+            prune: false,
+            ..options(WriteMode::Write)
+        };
+        write_files(
+            Output::Folder(&dir),
+            folder_files(&["a.ts", "b.ts"]),
+            options,
+        )
+        .unwrap();
+
+        let out_of_date = write_files(Output::Folder(&dir), folder_files(&["a.ts"]), options);
+        assert!(out_of_date.unwrap().is_empty());
+        assert!(dir.join("b.ts").exists());
+        // A later run that prunes still knows to remove it.
+        assert_eq!(manifest(&dir), "test\ta.ts\ntest\tb.ts\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stale_files_are_reported_in_check_mode() {
+        let dir = output_dir("check_stale");
+        let files = folder_files(&["a.ts", "b.ts"]);
+        write_files(Output::Folder(&dir), files, options(WriteMode::Write)).unwrap();
+
+        let out_of_date = write_files(
+            Output::Folder(&dir),
+            folder_files(&["a.ts"]),
+            options(WriteMode::Check),
+        );
+        assert_eq!(out_of_date.unwrap(), vec![dir.join("b.ts")]);
+        assert!(dir.join("b.ts").exists());
+        assert_eq!(manifest(&dir), "test\ta.ts\ntest\tb.ts\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn other_owners_are_preserved() {
+        let dir = output_dir("owners");
+        fs::write(dir.join("c.kt"), "types").unwrap();
+        fs::write(dir.join(MANIFEST_FILE_NAME), "other\tc.kt\n").unwrap();
+
+        let files = folder_files(&["a.ts"]);
+        write_files(Output::Folder(&dir), files, options(WriteMode::Write)).unwrap();
+        assert_eq!(manifest(&dir), "other\tc.kt\ntest\ta.ts\n");
+
+        write_files(Output::Folder(&dir), Vec::new(), options(WriteMode::Write)).unwrap();
+        assert!(!dir.join("a.ts").exists());
+        assert!(dir.join("c.kt").exists());
+        assert_eq!(manifest(&dir), "other\tc.kt\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn restricted_runs_keep_unchanged_crates() {
+        let dir = output_dir("restricted");
+        let parsed_data = || {
+            ["a", "b"]
+                .into_iter()
+                .map(|name| {
+                    let crate_name = CrateName::from(name);
+                    let parsed_data = ParsedData::new(crate_name.clone(), String::new(), true);
+                    (crate_name, parsed_data)
+                })
+                .collect::<BTreeMap<_, _>>()
+        };
+        let generate = |options| {
+            write_generated(
+                Output::Folder(&dir),
+                &mut TypeScript::default(),
+                SupportedLanguage::TypeScript,
+                parsed_data(),
+                options,
+            )
+            .unwrap()
+        };
+        generate(options(WriteMode::Write));
+        fs::write(dir.join("b.ts"), "edited").unwrap();
+
+        let crates = HashSet::from([CrateName::from("a")]);
+        let out_of_date = generate(WriteOptions {
+            crates: Some(&crates),
+            ..options(WriteMode::Write)
+        });
+        assert!(out_of_date.is_empty());
+        assert_eq!(fs::read_to_string(dir.join("b.ts")).unwrap(), "edited");
+        assert_eq!(manifest(&dir), "test\ta.ts\ntest\tb.ts\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -203,4 +203,78 @@ mod serde_attributes_on_enums {
             "The serde flatten attribute is not currently supported, on line 4 and column 12"
         );
     }
+
+    #[test]
+    fn asymmetric_rename() {
+        let source = r##"
+        #[typeshare]
+        pub struct Foo {
+            #[serde(rename(serialize = "fieldOne"))]
+            pub field1: String
+        }
+        "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        let err = process_input(
+            source,
+            &mut TypeScript::default(),
+            &HashMap::new(),
+            &mut out,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`field1` is serialized as \"fieldOne\" but deserialized as \"field1\", which cannot be expressed in the generated types, on line 5 and column 16"
+        );
+    }
+
+    #[test]
+    fn mismatched_conversion_types() {
+        let source = r##"
+        #[typeshare]
+        #[serde(into = "String", from = "u32")]
+        pub struct Foo {
+            pub field1: String
+        }
+        "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        let err = process_input(
+            source,
+            &mut TypeScript::default(),
+            &HashMap::new(),
+            &mut out,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The serde into type `String` differs from the from type `u32`, which cannot be expressed in the generated types, on line 2 and column 8"
+        );
+    }
+
+    #[test]
+    fn non_literal_repr_discriminant() {
+        let source = r##"
+        #[typeshare]
+        #[derive(Serialize_repr, Deserialize_repr)]
+        #[repr(u8)]
+        pub enum Foo {
+            Bar = BASE,
+            Baz,
+        }
+        "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        let err = process_input(
+            source,
+            &mut TypeScript::default(),
+            &HashMap::new(),
+            &mut out,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The discriminant of enum variant `Bar` must be an integer literal, on line 6 and column 12"
+        );
+    }
 }
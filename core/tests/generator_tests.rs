@@ -0,0 +1,144 @@
+use std::{fs, path::PathBuf};
+use typeshare_core::{
+    error::GeneratorError,
+    language::{Kotlin, TypeScript},
+    Generator,
+};
+
+const CURRENT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn input_dir() -> PathBuf {
+    [CURRENT_DIR, "data", "generator"].iter().collect()
+}
+
+fn output_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("typeshare_generator_{name}"));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn write_to_file() {
+    let path = output_path("file.ts");
+
+    Generator::new()
+        .input_dir(input_dir())
+        .target(TypeScript::default())
+        .write_to(&path)
+        .unwrap();
+
+    let output = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(output.contains("export interface UserId {"), "{output}");
+    assert!(output.contains("export interface Session {"), "{output}");
+}
+
+#[test]
+fn write_to_folder() {
+    let path = output_path("folder");
+
+    Generator::new()
+        .input_dir(input_dir())
+        .target(TypeScript::default())
+        .write_to_folder(&path)
+        .unwrap();
+
+    let app = fs::read_to_string(path.join("app.ts")).unwrap();
+    let shared = fs::read_to_string(path.join("shared.ts")).unwrap();
+    fs::remove_dir_all(&path).unwrap();
+    assert!(
+        app.contains(r#"import { Platform, Tagged, UserId } from "./shared";"#),
+        "{app}"
+    );
+    assert!(shared.contains("export interface UserId {"), "{shared}");
+    // The only use of `T` is a `PhantomData` field, so it is dropped across crates too
+    assert!(app.contains("tagged: Tagged;"), "{app}");
+    assert!(shared.contains("export interface Tagged {"), "{shared}");
+}
+
+#[test]
+fn names_crates_like_the_cli() {
+    let path = output_path("workspace");
+
+    Generator::new()
+        .input_dir(
+            [CURRENT_DIR, "data", "workspace"]
+                .iter()
+                .collect::<PathBuf>(),
+        )
+        .target(TypeScript::default())
+        .write_to_folder(&path)
+        .unwrap();
+
+    // Crates are named after their manifests rather than their directories,
+    // and renamed dependencies resolve to the crates they name.
+    let app = fs::read_to_string(path.join("app_server.ts")).unwrap();
+    let shared = fs::read_to_string(path.join("shared.ts")).unwrap();
+    fs::remove_dir_all(&path).unwrap();
+    assert!(
+        app.contains(r#"import { Account, User } from "./shared";"#),
+        "{app}"
+    );
+    // Files matched by ignore files are skipped.
+    assert!(!shared.contains("interface Generated"), "{shared}");
+}
+
+#[test]
+fn catch_all_variants_across_crates() {
+    let path = output_path("catch_all");
+
+    Generator::new()
+        .input_dir(input_dir())
+        .target(Kotlin {
+            package: "com.example".into(),
+            ..Default::default()
+        })
+        .write_to_folder(&path)
+        .unwrap();
+
+    // `app` is generated before `shared`, which defines the enum.
+    let app = fs::read_to_string(path.join("app.kt")).unwrap();
+    fs::remove_dir_all(&path).unwrap();
+    assert!(
+        app.contains("val platform: Platform = Platform.Unknown"),
+        "{app}"
+    );
+}
+
+#[test]
+fn consts_across_crates() {
+    let path = output_path("consts");
+
+    Generator::new()
+        .input_dir(
+            [CURRENT_DIR, "data", "generator_consts"]
+                .iter()
+                .collect::<PathBuf>(),
+        )
+        .target(TypeScript::default())
+        .write_to_folder(&path)
+        .unwrap();
+
+    let app = fs::read_to_string(path.join("app.ts")).unwrap();
+    fs::remove_dir_all(&path).unwrap();
+    assert!(
+        app.contains(r#"import { Buffer } from "./lengths";"#),
+        "{app}"
+    );
+    assert!(
+        app.contains("bytes: [number, number, number, number];"),
+        "{app}"
+    );
+    assert!(app.contains("buffer: Buffer;"), "{app}");
+}
+
+#[test]
+fn missing_target() {
+    let err = Generator::new()
+        .input_dir(input_dir())
+        .write_to(output_path("missing_target.ts"))
+        .unwrap_err();
+
+    assert!(matches!(err, GeneratorError::NoTarget), "{err}");
+}
@@ -376,6 +376,7 @@ macro_rules! target_os {
 ///     }
 /// }
 /// ```
+#[allow(clippy::test_attr_in_doctest)]
 macro_rules! tests {
     // The initial `$(#[$outer:meta])*` here captures comments so they can be used
     // inside the macro; we don't do anything with them, though
@@ -492,7 +493,8 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        typescript,
+        python
     ];
     can_generate_generic_struct: [
         swift {
@@ -502,6 +504,7 @@ tests! {
         kotlin,
         scala,
         typescript,
+        python,
     ];
     can_generate_generic_type_alias: [
         swift {
@@ -509,7 +512,8 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        typescript,
+        python
     ];
     can_generate_const: [typescript, go, python];
     can_generate_slice_of_user_type: [swift, kotlin, scala, typescript, go, python];
@@ -568,6 +572,17 @@ tests! {
         go,
         python
     ];
+    serde_rename_directions: [swift, kotlin, scala, typescript, go, python];
+    serde_alias: [swift, kotlin, typescript, go, python];
+    skip_serializing_if: [swift, kotlin, typescript, go, python];
+    serde_serialized_type: [swift, kotlin, scala, typescript, go, python];
+    serde_repr_enum: [swift, kotlin, typescript, go, python];
+    serde_other_variant: [swift, kotlin, typescript, python];
+    well_known_types: [swift, kotlin, scala, typescript, go, python];
+    well_known_time_types: [swift, kotlin, scala, typescript, go, python];
+    const_generics: [swift, kotlin, scala, typescript, go, python];
+    phantom_data: [swift, kotlin, typescript, go, python];
+    serde_default_values: [swift, kotlin, typescript, python];
     can_override_types: [swift, kotlin, scala, typescript, go];
 
     /// Structs
@@ -680,6 +695,7 @@ tests! {
     serialize_anonymous_field_as: [kotlin, swift, typescript, scala, go, python];
     smart_pointers: [kotlin, swift, typescript, scala, go, python];
     recursive_enum_decorator: [kotlin, swift, typescript, scala, go, python];
+    recursive_struct: [swift, typescript];
 
     uppercase_go_acronyms: [
         go {
@@ -715,5 +731,11 @@ tests! {
     }
     ];
     no_mangle: [swift, kotlin, scala, typescript, go];
+    scala3_dialect: [scala { package: "com.agilebits.onepassword".to_string(), dialect: typeshare_core::language::ScalaDialect::Scala3 }];
+    scala_circe_codecs: [scala { package: "com.agilebits.onepassword".to_string(), circe: true }];
+    scala3_circe_codecs: [scala { package: "com.agilebits.onepassword".to_string(), dialect: typeshare_core::language::ScalaDialect::Scala3, circe: true }];
+    python_dataclass_style: [python { style: typeshare_core::language::PythonStyle::Dataclass }];
+    python_typeddict_style: [python { style: typeshare_core::language::PythonStyle::TypedDict }];
+    python_msgspec_style: [python { style: typeshare_core::language::PythonStyle::Msgspec }];
     cfg_if_attribute_typeshare: [swift, kotlin, typescript];
 }